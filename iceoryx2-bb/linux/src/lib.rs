@@ -15,5 +15,8 @@
 #[cfg(target_os = "linux")]
 pub mod epoll;
 
+#[cfg(target_os = "linux")]
+pub mod numa;
+
 #[cfg(target_os = "linux")]
 pub mod signalfd;
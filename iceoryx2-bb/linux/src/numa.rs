@@ -0,0 +1,216 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`NumaNode`] represents a memory node of a NUMA (non-uniform memory access) system. A
+//! memory region can be bound to a [`NumaNode`] so that its pages are allocated from the
+//! memory that is attached to this node. Pages that were already allocated elsewhere are
+//! moved to the node.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_linux::numa::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NumaNode::new(0)?;
+//!
+//! # let memory_start: *const core::ffi::c_void = core::ptr::null();
+//! # let memory_len = 0;
+//! // the memory range must stay valid while it is bound
+//! unsafe { node.bind_memory(memory_start, memory_len)? };
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate alloc;
+
+use alloc::format;
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_posix::{directory::Directory, system_configuration::SystemInfo};
+use iceoryx2_bb_system_types::path::Path;
+use iceoryx2_log::fail;
+use iceoryx2_pal_os_api::linux;
+use iceoryx2_pal_posix::posix::{self};
+
+const NUMA_NODE_DIRECTORY: &str = "/sys/devices/system/node";
+
+/// The maximum number of [`NumaNode`]s that are supported.
+pub const MAX_NUMBER_OF_NUMA_NODES: usize = 1024;
+
+const BITS_PER_MASK_ENTRY: usize = posix::ulong::BITS as usize;
+const NODE_MASK_LEN: usize = MAX_NUMBER_OF_NUMA_NODES / BITS_PER_MASK_ENTRY;
+
+/// Errors that can occur when a [`NumaNode`] is created with [`NumaNode::new()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum NumaNodeCreationError {
+    /// The system does not support NUMA.
+    NotSupported,
+    /// A [`NumaNode`] with the provided id does not exist.
+    DoesNotExist,
+    /// The node information of the system could not be acquired.
+    UnableToAcquireNodeInformation,
+}
+
+impl core::fmt::Display for NumaNodeCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NumaNodeCreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for NumaNodeCreationError {}
+
+/// Errors that can occur when a memory region is bound to a [`NumaNode`] with
+/// [`NumaNode::bind_memory()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum NumaBindError {
+    /// The memory region is not completely mapped into the address space of the process.
+    InvalidAddressRange,
+    /// The system has not enough memory to bind the memory region.
+    InsufficientMemory,
+    /// The process is not allowed to bind memory to the [`NumaNode`].
+    InsufficientPermissions,
+    /// Some of the already allocated pages could not be moved to the [`NumaNode`].
+    UnableToMovePages,
+    /// The system does not support NUMA.
+    NotSupported,
+    /// An error occurred that was not described in the linux man-page.
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for NumaBindError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NumaBindError::{self:?}")
+    }
+}
+
+impl core::error::Error for NumaBindError {}
+
+/// Represents an existing memory node of a NUMA system.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct NumaNode {
+    id: u32,
+}
+
+impl NumaNode {
+    /// Creates a new [`NumaNode`] if a node with the provided id exists on the system.
+    pub fn new(id: u32) -> Result<Self, NumaNodeCreationError> {
+        let msg = format!("Unable to create NUMA node {id}");
+        let origin = "NumaNode::new()";
+
+        if MAX_NUMBER_OF_NUMA_NODES <= id as usize {
+            fail!(from origin, with NumaNodeCreationError::DoesNotExist,
+                "{msg} since the id exceeds the maximum supported number of nodes ({MAX_NUMBER_OF_NUMA_NODES}).");
+        }
+
+        if !Self::does_node_directory_exist(NUMA_NODE_DIRECTORY, &msg)? {
+            fail!(from origin, with NumaNodeCreationError::NotSupported,
+                "{msg} since the system does not support NUMA.");
+        }
+
+        if !Self::does_node_directory_exist(&format!("{NUMA_NODE_DIRECTORY}/node{id}"), &msg)? {
+            fail!(from origin, with NumaNodeCreationError::DoesNotExist,
+                "{msg} since the node does not exist.");
+        }
+
+        Ok(Self { id })
+    }
+
+    fn does_node_directory_exist(
+        directory: &str,
+        msg: &str,
+    ) -> Result<bool, NumaNodeCreationError> {
+        let origin = "NumaNode::new()";
+        let path = fail!(from origin, when Path::new(directory.as_bytes()),
+                with NumaNodeCreationError::UnableToAcquireNodeInformation,
+                "{msg} since \"{directory}\" is not a valid path.");
+
+        Ok(fail!(from origin, when Directory::does_exist(&path),
+                with NumaNodeCreationError::UnableToAcquireNodeInformation,
+                "{msg} since the existence of \"{directory}\" could not be verified."))
+    }
+
+    /// Returns the id of the [`NumaNode`].
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Binds the provided memory region to the [`NumaNode`]. All pages of the memory region
+    /// are allocated from the memory of the [`NumaNode`] and already allocated pages are
+    /// moved. The memory region is extended to page boundaries.
+    ///
+    /// # Safety
+    ///
+    ///   * the memory range [address, len] must be mapped into the address space of the process
+    ///
+    pub unsafe fn bind_memory(
+        &self,
+        address: *const posix::void,
+        len: usize,
+    ) -> Result<(), NumaBindError> {
+        let page_size = SystemInfo::PageSize.value();
+        let aligned_address = address as usize - (address as usize % page_size);
+        let aligned_len = len + (address as usize - aligned_address);
+
+        let mut node_mask: [posix::ulong; NODE_MASK_LEN] = [0; NODE_MASK_LEN];
+        node_mask[self.id as usize / BITS_PER_MASK_ENTRY] |=
+            1 << (self.id as usize % BITS_PER_MASK_ENTRY);
+
+        if unsafe {
+            linux::mbind(
+                aligned_address as *mut posix::void,
+                aligned_len,
+                linux::MPOL_BIND,
+                node_mask.as_ptr(),
+                // the kernel expects the number of nodes plus one
+                MAX_NUMBER_OF_NUMA_NODES as posix::ulong + 1,
+                linux::MPOL_MF_MOVE | linux::MPOL_MF_STRICT,
+            )
+        } == 0
+        {
+            return Ok(());
+        }
+
+        let msg = format!(
+            "Unable to bind the memory {aligned_address:#16X} with a length of {aligned_len} to the NUMA node {}",
+            self.id
+        );
+        match posix::Errno::get() {
+            posix::Errno::EFAULT => {
+                fail!(from self, with NumaBindError::InvalidAddressRange,
+                    "{msg} since the memory range is not completely mapped.");
+            }
+            posix::Errno::ENOMEM => {
+                fail!(from self, with NumaBindError::InsufficientMemory,
+                    "{msg} due to insufficient memory.");
+            }
+            posix::Errno::EPERM => {
+                fail!(from self, with NumaBindError::InsufficientPermissions,
+                    "{msg} due to insufficient permissions.");
+            }
+            posix::Errno::EIO => {
+                fail!(from self, with NumaBindError::UnableToMovePages,
+                    "{msg} since some already allocated pages could not be moved.");
+            }
+            posix::Errno::ENOSYS => {
+                fail!(from self, with NumaBindError::NotSupported,
+                    "{msg} since the system does not support NUMA.");
+            }
+            e => {
+                fail!(from self, with NumaBindError::UnknownError(e as i32),
+                    "{msg} since an unknown error occurred ({e:?}).");
+            }
+        }
+    }
+}
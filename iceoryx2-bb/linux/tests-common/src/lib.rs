@@ -18,4 +18,6 @@ extern crate iceoryx2_bb_loggers;
 #[cfg(target_os = "linux")]
 pub mod epoll_tests;
 #[cfg(target_os = "linux")]
+pub mod numa_tests;
+#[cfg(target_os = "linux")]
 pub mod signal_fd_tests;
@@ -0,0 +1,40 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_linux::numa::*;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+#[test]
+pub fn creating_numa_node_with_id_exceeding_max_fails() {
+    let sut = NumaNode::new(MAX_NUMBER_OF_NUMA_NODES as u32);
+    assert_that!(sut.err(), eq Some(NumaNodeCreationError::DoesNotExist));
+}
+
+#[test]
+pub fn memory_can_be_bound_to_existing_numa_node() {
+    let sut = match NumaNode::new(0) {
+        Ok(sut) => sut,
+        Err(NumaNodeCreationError::NotSupported) => return,
+        Err(e) => panic!("unable to create numa node ({e:?})"),
+    };
+    assert_that!(sut.id(), eq 0);
+
+    let memory = alloc::vec![0u8; SystemInfo::PageSize.value() * 2];
+    match unsafe { sut.bind_memory(memory.as_ptr().cast(), memory.len()) } {
+        Ok(()) | Err(NumaBindError::NotSupported) | Err(NumaBindError::InsufficientPermissions) => {
+            ()
+        }
+        Err(e) => panic!("unable to bind memory to numa node ({e:?})"),
+    }
+}
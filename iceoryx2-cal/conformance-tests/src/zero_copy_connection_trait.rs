@@ -852,6 +852,106 @@ pub mod zero_copy_connection_trait {
         .unwrap();
     }
 
+    #[conformance_test]
+    pub fn would_block_on_send_is_true_when_buffer_is_full<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 3;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.would_block_on_send(id), eq false);
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE, id),
+                is_ok
+            );
+        }
+
+        assert_that!(sut_sender.would_block_on_send(id), eq true);
+    }
+
+    #[conformance_test]
+    pub fn number_of_pending_samples_is_reduced_by_receive<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 3;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.number_of_pending_samples(id), eq i);
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE, id),
+                is_ok
+            );
+        }
+        assert_that!(sut_sender.number_of_pending_samples(id), eq BUFFER_SIZE);
+
+        assert_that!(sut_receiver.receive(id).unwrap(), is_some);
+        assert_that!(sut_sender.number_of_pending_samples(id), eq BUFFER_SIZE - 1);
+    }
+
+    #[conformance_test]
+    pub fn blocking_send_while_returns_when_condition_no_longer_holds<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let _watchdog = Watchdog::new();
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(1)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        let _sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(1)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE), SAMPLE_SIZE, id),
+            is_ok
+        );
+
+        let mut number_of_calls = 0;
+        let result = sut_sender.blocking_send_while(
+            PointerOffset::new(SAMPLE_SIZE * 2),
+            SAMPLE_SIZE,
+            id,
+            || {
+                number_of_calls += 1;
+                number_of_calls < 5
+            },
+        );
+
+        assert_that!(result.err().unwrap(), eq ZeroCopySendError::ReceiveBufferFull);
+        assert_that!(number_of_calls, eq 5);
+    }
+
     #[conformance_test]
     pub fn blocking_send_returns_when_connection_to_receiver_is_lost<Sut: ZeroCopyConnection>() {
         const TIMEOUT: Duration = Duration::from_millis(25);
@@ -2061,4 +2161,79 @@ pub mod zero_copy_connection_trait {
 
         assert_that!(sut_receiver.__internal_get_channel_state(CHANNEL_ID).load(Ordering::Relaxed), eq 789);
     }
+
+    #[conformance_test]
+    pub fn resync_request_is_visible_to_sender_until_taken<Sut: ZeroCopyConnection>() {
+        const CHANNEL_ID: ChannelId = ChannelId::new(0);
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        let sut_sender = Sut::Builder::new(&name)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        assert_that!(sut_sender.take_resync_request(CHANNEL_ID), eq false);
+
+        sut_receiver.request_resync(CHANNEL_ID);
+        assert_that!(sut_sender.take_resync_request(CHANNEL_ID), eq true);
+        assert_that!(sut_sender.take_resync_request(CHANNEL_ID), eq false);
+    }
+
+    #[conformance_test]
+    pub fn number_of_resync_samples_is_visible_to_receiver<Sut: ZeroCopyConnection>() {
+        const CHANNEL_ID: ChannelId = ChannelId::new(0);
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        let sut_sender = Sut::Builder::new(&name)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        assert_that!(sut_receiver.number_of_resync_samples(CHANNEL_ID), eq 0);
+
+        sut_sender.set_number_of_resync_samples(CHANNEL_ID, 7);
+        assert_that!(sut_receiver.number_of_resync_samples(CHANNEL_ID), eq 7);
+    }
+
+    #[conformance_test]
+    pub fn reconnect_request_is_visible_to_receiver_until_connection_is_recreated<
+        Sut: ZeroCopyConnection,
+    >() {
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_receiver.has_reconnect_request(), eq false);
+
+        sut_sender.request_reconnect();
+        assert_that!(sut_receiver.has_reconnect_request(), eq true);
+        assert_that!(sut_receiver.has_reconnect_request(), eq true);
+
+        drop(sut_receiver);
+        drop(sut_sender);
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        assert_that!(sut_receiver.has_reconnect_request(), eq false);
+    }
 }
@@ -19,7 +19,7 @@ pub mod details {
     use alloc::vec;
     use alloc::vec::Vec;
 
-    use iceoryx2_bb_concurrency::atomic::{AtomicU8, AtomicU64, AtomicUsize};
+    use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU8, AtomicU64, AtomicUsize};
     use iceoryx2_bb_concurrency::cell::UnsafeCell;
     use iceoryx2_bb_container::vector::relocatable_vec::*;
     use iceoryx2_bb_elementary_traits::allocator::{AllocationError, BaseAllocator};
@@ -162,6 +162,8 @@ pub mod details {
     #[repr(C)]
     struct Channel {
         state: AtomicU64,
+        resync_requested: AtomicBool,
+        number_of_resync_samples: AtomicU64,
         completion_queue: RelocatableIndexQueue,
         submission_queue: RelocatableSafelyOverflowingIndexQueue,
     }
@@ -176,6 +178,8 @@ pub mod details {
                     RelocatableIndexQueue::new_uninit(completion_queue_capacity)
                 },
                 state: AtomicU64::new(CHANNEL_STATE_OPEN.0),
+                resync_requested: AtomicBool::new(false),
+                number_of_resync_samples: AtomicU64::new(0),
             }
         }
 
@@ -204,6 +208,7 @@ pub mod details {
         channels: RelocatableVec<Channel>,
         segment_details: RelocatableVec<SegmentDetails>,
         state: AtomicU8,
+        reconnect_requested: AtomicBool,
         max_borrowed_samples: usize,
         number_of_samples_per_segment: usize,
         number_of_segments: u8,
@@ -228,6 +233,7 @@ pub mod details {
                 number_of_samples_per_segment,
                 number_of_segments,
                 state: AtomicU8::new(State::None.value()),
+                reconnect_requested: AtomicBool::new(false),
             }
         }
 
@@ -658,6 +664,20 @@ pub mod details {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
             &self.storage.get().channels[channel_id.value()].state
         }
+
+        fn __internal_get_resync_request(&self, channel_id: ChannelId) -> &AtomicBool {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].resync_requested
+        }
+
+        fn __internal_get_number_of_resync_samples(&self, channel_id: ChannelId) -> &AtomicU64 {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].number_of_resync_samples
+        }
+
+        fn __internal_get_reconnect_request(&self) -> &AtomicBool {
+            &self.storage.get().reconnect_requested
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
@@ -729,6 +749,16 @@ pub mod details {
             ptr: PointerOffset,
             sample_size: usize,
             channel_id: ChannelId,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            self.blocking_send_while(ptr, sample_size, channel_id, || true)
+        }
+
+        fn blocking_send_while<F: FnMut() -> bool>(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+            channel_id: ChannelId,
+            mut keep_blocking: F,
         ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
             let msg = "Unable to blocking send the offset";
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
@@ -747,6 +777,7 @@ pub mod details {
                     mgmt.channels[channel_id.value()].submission_queue.is_full()
                         && is_connected
                         && has_valid_channel_state
+                        && keep_blocking()
                 }) {
                     fail!(from self, with ZeroCopySendError::InternalError,
                         "{msg} {ptr:?} via channel {channel_id:?} since the adaptive wait failed. [{e:?}]");
@@ -766,6 +797,24 @@ pub mod details {
             self.try_send(ptr, sample_size, channel_id)
         }
 
+        fn would_block_on_send(&self, channel_id: ChannelId) -> bool {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+
+            let storage = self.storage.get();
+            !storage.enable_safe_overflow
+                && storage.channels[channel_id.value()]
+                    .submission_queue
+                    .is_full()
+        }
+
+        fn number_of_pending_samples(&self, channel_id: ChannelId) -> usize {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+
+            self.storage.get().channels[channel_id.value()]
+                .submission_queue
+                .len()
+        }
+
         fn reclaim(
             &self,
             channel_id: ChannelId,
@@ -913,6 +962,20 @@ pub mod details {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
             &self.storage.get().channels[channel_id.value()].state
         }
+
+        fn __internal_get_resync_request(&self, channel_id: ChannelId) -> &AtomicBool {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].resync_requested
+        }
+
+        fn __internal_get_number_of_resync_samples(&self, channel_id: ChannelId) -> &AtomicU64 {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].number_of_resync_samples
+        }
+
+        fn __internal_get_reconnect_request(&self) -> &AtomicBool {
+            &self.storage.get().reconnect_requested
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyReceiver for Receiver<Storage> {
@@ -26,7 +26,7 @@ pub use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_log::fail;
 
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
-use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64, Ordering};
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 
@@ -220,6 +220,55 @@ pub trait ZeroCopyPortDetails {
     fn is_connected(&self) -> bool;
     #[doc(hidden)]
     fn __internal_get_channel_state(&self, channel_id: ChannelId) -> &AtomicU64;
+    #[doc(hidden)]
+    fn __internal_get_resync_request(&self, channel_id: ChannelId) -> &AtomicBool;
+    #[doc(hidden)]
+    fn __internal_get_number_of_resync_samples(&self, channel_id: ChannelId) -> &AtomicU64;
+    #[doc(hidden)]
+    fn __internal_get_reconnect_request(&self) -> &AtomicBool;
+
+    /// Signals the other side of the channel that a re-delivery of its history was
+    /// requested.
+    fn request_resync(&self, channel_id: ChannelId) {
+        self.__internal_get_resync_request(channel_id)
+            .store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true when a re-delivery was requested via
+    /// [`ZeroCopyPortDetails::request_resync()`] and resets the request.
+    fn take_resync_request(&self, channel_id: ChannelId) -> bool {
+        self.__internal_get_resync_request(channel_id)
+            .swap(false, Ordering::Relaxed)
+    }
+
+    /// Announces to the other side of the channel how many samples are re-delivered when it
+    /// requests a resync via [`ZeroCopyPortDetails::request_resync()`].
+    fn set_number_of_resync_samples(&self, channel_id: ChannelId, value: u64) {
+        self.__internal_get_number_of_resync_samples(channel_id)
+            .store(value, Ordering::Relaxed);
+    }
+
+    /// Returns the number of samples the other side announced via
+    /// [`ZeroCopyPortDetails::set_number_of_resync_samples()`].
+    fn number_of_resync_samples(&self, channel_id: ChannelId) -> u64 {
+        self.__internal_get_number_of_resync_samples(channel_id)
+            .load(Ordering::Relaxed)
+    }
+
+    /// Signals the other side that the connection is no longer used and shall be released
+    /// so that it can be re-established. The request stays set for the lifetime of the
+    /// connection.
+    fn request_reconnect(&self) {
+        self.__internal_get_reconnect_request()
+            .store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true when the other side requested via
+    /// [`ZeroCopyPortDetails::request_reconnect()`] to release the connection.
+    fn has_reconnect_request(&self) -> bool {
+        self.__internal_get_reconnect_request()
+            .load(Ordering::Relaxed)
+    }
 
     fn set_channel_state(&self, channel_id: ChannelId, state: ChannelState) -> bool {
         self.__internal_get_channel_state(channel_id)
@@ -304,9 +353,26 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept + Send {
         channel_id: ChannelId,
     ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
+    /// Blocks like [`ZeroCopySender::blocking_send()`] as long as `keep_blocking` returns
+    /// `true`. As soon as it returns `false` it behaves like [`ZeroCopySender::try_send()`].
+    fn blocking_send_while<F: FnMut() -> bool>(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        keep_blocking: F,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
     fn reclaim(&self, channel_id: ChannelId)
     -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
 
+    /// Returns `true` when the receive buffer of the channel is full and safe overflow is
+    /// disabled, meaning that [`ZeroCopySender::blocking_send()`] would currently block.
+    fn would_block_on_send(&self, channel_id: ChannelId) -> bool;
+
+    /// Returns the number of samples that were sent via the channel but not yet received.
+    fn number_of_pending_samples(&self, channel_id: ChannelId) -> usize;
+
     /// # Safety
     ///
     /// * must ensure that no receiver is still holding data, otherwise data races may occur on
@@ -105,6 +105,18 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                     default_value: format!("\"{}\"", config.global.node.service_tag_suffix),
                     description: "Suffix added to the service tag of the node.",
                 },
+                Field {
+                    key: "global.node.heartbeat-suffix",
+                    value_type: "string",
+                    default_value: format!("\"{}\"", config.global.node.heartbeat_suffix),
+                    description: "Suffix added to the heartbeat of the node.",
+                },
+                Field {
+                    key: "global.node.enable-heartbeat",
+                    value_type: "`true`|`false`",
+                    default_value: config.global.node.enable_heartbeat.to_string(),
+                    description: "Defines if every node creates a heartbeat that other nodes can use to check its liveliness.",
+                },
                 Field {
                     key: "global.node.cleanup-dead-nodes-on-creation",
                     value_type: "`true`|`false`",
@@ -184,6 +196,12 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                     default_value: format!("\"{}\"", config.global.service.blackboard_data_suffix),
                     description: "The suffix of the blackboard payload data segment.",
                 },
+                Field {
+                    key: "global.service.creation-epoch-suffix",
+                    value_type: "string",
+                    default_value: format!("\"{}\"", config.global.service.creation_epoch_suffix),
+                    description: "The suffix of the file that stores the creation epoch of a service.",
+                },
             ],
         },
         Section {
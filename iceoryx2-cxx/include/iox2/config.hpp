@@ -48,6 +48,15 @@ class Node {
     auto service_tag_suffix() && -> const char*;
     /// Set the suffix of the service tags.
     void set_service_tag_suffix(const iox2::bb::FileName& value) &&;
+    /// The suffix of the node heartbeat.
+    auto heartbeat_suffix() && -> const char*;
+    /// Set the suffix of the node heartbeat.
+    void set_heartbeat_suffix(const iox2::bb::FileName& value) &&;
+    /// When true, every [`Node`](Node) creates a heartbeat that other nodes can use to check
+    /// its liveliness.
+    auto enable_heartbeat() && -> bool;
+    /// Enable/disable the node heartbeat
+    void set_enable_heartbeat(bool value) &&;
     /// When true, the [`NodeBuilder`](NodeBuilder) checks for dead nodes and
     /// cleans up all their stale resources whenever a new [`Node`](Node) is
     /// created.
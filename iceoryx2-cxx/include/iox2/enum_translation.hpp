@@ -117,6 +117,8 @@ constexpr auto from<int, iox2::NodeCreationFailure>(const int value) noexcept ->
         return iox2::NodeCreationFailure::InsufficientPermissions;
     case iox2_node_creation_failure_e_INTERNAL_ERROR:
         return iox2::NodeCreationFailure::InternalError;
+    case iox2_node_creation_failure_e_INVALID_SHM_NAME_PREFIX:
+        return iox2::NodeCreationFailure::InvalidShmNamePrefix;
     }
 
     IOX2_UNREACHABLE();
@@ -131,6 +133,8 @@ from<iox2::NodeCreationFailure, iox2_node_creation_failure_e>(const iox2::NodeCr
         return iox2_node_creation_failure_e_INSUFFICIENT_PERMISSIONS;
     case iox2::NodeCreationFailure::InternalError:
         return iox2_node_creation_failure_e_INTERNAL_ERROR;
+    case iox2::NodeCreationFailure::InvalidShmNamePrefix:
+        return iox2_node_creation_failure_e_INVALID_SHM_NAME_PREFIX;
     }
 
     IOX2_UNREACHABLE();
@@ -1588,6 +1592,8 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::UnableToCreateDataSegment;
     case iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_publisher_create_error_e_UNABLE_TO_BIND_TO_NUMA_NODE:
+        return iox2::PublisherCreateError::UnableToBindToNumaNode;
     }
 
     IOX2_UNREACHABLE();
@@ -1604,6 +1610,8 @@ from<iox2::PublisherCreateError, iox2_publisher_create_error_e>(const iox2::Publ
         return iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::PublisherCreateError::UnableToBindToNumaNode:
+        return iox2_publisher_create_error_e_UNABLE_TO_BIND_TO_NUMA_NODE;
     }
 
     IOX2_UNREACHABLE();
@@ -1672,6 +1680,16 @@ constexpr auto from<int, iox2::SendError>(const int value) noexcept -> iox2::Sen
         return iox2::SendError::ConnectionError;
     case iox2_send_error_e_INTERNAL_ERROR:
         return iox2::SendError::InternalError;
+    case iox2_send_error_e_RATE_LIMITED:
+        return iox2::SendError::RateLimited;
+    case iox2_send_error_e_TIMEOUT:
+        return iox2::SendError::Timeout;
+    case iox2_send_error_e_UNINITIALIZED_PAYLOAD:
+        return iox2::SendError::UninitializedPayload;
+    case iox2_send_error_e_SAMPLE_FROM_OTHER_PUBLISHER:
+        return iox2::SendError::SampleFromOtherPublisher;
+    case iox2_send_error_e_LOAN_ERROR_SEGMENT_FULL:
+        return iox2::SendError::LoanErrorSegmentFull;
     }
 
     IOX2_UNREACHABLE();
@@ -1696,6 +1714,16 @@ constexpr auto from<iox2::SendError, iox2_send_error_e>(const iox2::SendError va
         return iox2_send_error_e_CONNECTION_ERROR;
     case iox2::SendError::InternalError:
         return iox2_send_error_e_INTERNAL_ERROR;
+    case iox2::SendError::RateLimited:
+        return iox2_send_error_e_RATE_LIMITED;
+    case iox2::SendError::Timeout:
+        return iox2_send_error_e_TIMEOUT;
+    case iox2::SendError::UninitializedPayload:
+        return iox2_send_error_e_UNINITIALIZED_PAYLOAD;
+    case iox2::SendError::SampleFromOtherPublisher:
+        return iox2_send_error_e_SAMPLE_FROM_OTHER_PUBLISHER;
+    case iox2::SendError::LoanErrorSegmentFull:
+        return iox2_send_error_e_LOAN_ERROR_SEGMENT_FULL;
     }
 
     IOX2_UNREACHABLE();
@@ -1716,6 +1744,14 @@ constexpr auto from<int, iox2::ReceiveError>(const int value) noexcept -> iox2::
         return iox2::ReceiveError::UnableToMapSendersDataSegment;
     case iox2_receive_error_e_EXCEEDS_MAX_BORROWS:
         return iox2::ReceiveError::ExceedsMaxBorrows;
+    case iox2_receive_error_e_FOREIGN_ENDIANNESS:
+        return iox2::ReceiveError::ForeignEndianness;
+    case iox2_receive_error_e_MISALIGNED_PAYLOAD:
+        return iox2::ReceiveError::MisalignedPayload;
+    case iox2_receive_error_e_POOL_EXHAUSTED:
+        return iox2::ReceiveError::PoolExhausted;
+    case iox2_receive_error_e_EXCEEDS_POOL_BUFFER_SIZE:
+        return iox2::ReceiveError::ExceedsPoolBufferSize;
     }
 
     IOX2_UNREACHABLE();
@@ -1731,6 +1767,14 @@ constexpr auto from<iox2::ReceiveError, iox2_receive_error_e>(const iox2::Receiv
         return iox2_receive_error_e_UNABLE_TO_MAP_SENDERS_DATA_SEGMENT;
     case iox2::ReceiveError::ExceedsMaxBorrows:
         return iox2_receive_error_e_EXCEEDS_MAX_BORROWS;
+    case iox2::ReceiveError::ForeignEndianness:
+        return iox2_receive_error_e_FOREIGN_ENDIANNESS;
+    case iox2::ReceiveError::MisalignedPayload:
+        return iox2_receive_error_e_MISALIGNED_PAYLOAD;
+    case iox2::ReceiveError::PoolExhausted:
+        return iox2_receive_error_e_POOL_EXHAUSTED;
+    case iox2::ReceiveError::ExceedsPoolBufferSize:
+        return iox2_receive_error_e_EXCEEDS_POOL_BUFFER_SIZE;
     }
 
     IOX2_UNREACHABLE();
@@ -1753,6 +1797,8 @@ constexpr auto from<int, iox2::LoanError>(const int value) noexcept -> iox2::Loa
         return iox2::LoanError::ExceedsMaxLoanSize;
     case iox2_loan_error_e_INTERNAL_FAILURE:
         return iox2::LoanError::InternalFailure;
+    case iox2_loan_error_e_SEGMENT_FULL:
+        return iox2::LoanError::SegmentFull;
     }
 
     IOX2_UNREACHABLE();
@@ -1769,6 +1815,8 @@ constexpr auto from<iox2::LoanError, iox2_loan_error_e>(const iox2::LoanError va
         return iox2_loan_error_e_EXCEEDS_MAX_LOAN_SIZE;
     case iox2::LoanError::InternalFailure:
         return iox2_loan_error_e_INTERNAL_FAILURE;
+    case iox2::LoanError::SegmentFull:
+        return iox2_loan_error_e_SEGMENT_FULL;
     }
 
     IOX2_UNREACHABLE();
@@ -1801,6 +1849,8 @@ constexpr auto from<int, iox2::RequestSendError>(const int value) noexcept -> io
         return iox2::RequestSendError::ConnectionError;
     case iox2_request_send_error_e_INTERNAL_ERROR:
         return iox2::RequestSendError::InternalError;
+    case iox2_request_send_error_e_LOAN_ERROR_SEGMENT_FULL:
+        return iox2::RequestSendError::LoanErrorSegmentFull;
     }
 
     IOX2_UNREACHABLE();
@@ -1828,6 +1878,8 @@ constexpr auto from<iox2::RequestSendError, iox2_request_send_error_e>(const iox
         return iox2_request_send_error_e_CONNECTION_ERROR;
     case iox2::RequestSendError::InternalError:
         return iox2_request_send_error_e_INTERNAL_ERROR;
+    case iox2::RequestSendError::LoanErrorSegmentFull:
+        return iox2_request_send_error_e_LOAN_ERROR_SEGMENT_FULL;
     }
 
     IOX2_UNREACHABLE();
@@ -33,7 +33,10 @@ enum class NodeCreationFailure : uint8_t {
     InsufficientPermissions,
     /// Maybe the configuration/system is broken since someone has
     /// removed/modified internal resources
-    InternalError
+    InternalError,
+    /// The provided shm name prefix contains characters that are not allowed in the names of
+    /// system resources or is too long.
+    InvalidShmNamePrefix
 };
 
 /// Failures of [`DeadNodeView::remove_stale_resources()`] that occur when the stale resources of
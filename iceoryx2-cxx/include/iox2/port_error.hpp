@@ -33,6 +33,10 @@ enum class LoanError : uint8_t {
     ExceedsMaxLoanSize,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalFailure,
+    /// Every slot of the static data segment is in use, for instance because slow
+    /// receivers still hold the samples. Becomes available again as soon as samples
+    /// are released.
+    SegmentFull,
 };
 
 /// Failure that can be emitted when data is sent.
@@ -56,7 +60,21 @@ enum class SendError : uint8_t {
     /// A failure occurred while establishing a connection to the ports counterpart port.
     ConnectionError,
     /// An internal mechanisms failed and the data could not be delivered to all receivers.
-    InternalError
+    InternalError,
+    /// The maximum send rate of the [`Publisher`] is exceeded and the data was discarded.
+    RateLimited,
+    /// The receive buffer of at least one receiver was still full when the timeout of a
+    /// blocking send expired.
+    Timeout,
+    /// The payload was not completely written before it was sent. Only detected when the
+    /// [`Publisher`] validates the initialization of its samples.
+    UninitializedPayload,
+    /// The sample was loaned from another [`Publisher`] than the one that shall send it.
+    SampleFromOtherPublisher,
+    /// Every slot of the static data segment is in use, for instance because slow
+    /// receivers still hold the samples. Becomes available again as soon as samples
+    /// are released.
+    LoanErrorSegmentFull
 };
 
 /// Defines the failure that can occur when receiving data with
@@ -71,7 +89,19 @@ enum class ReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapSendersDataSegment
+    UnableToMapSendersDataSegment,
+
+    /// The received data was published by a process with a different byte order and the
+    /// receiver was configured to reject it.
+    ForeignEndianness,
+    /// The payload of the received data does not satisfy the alignment the receiver was
+    /// configured to assert.
+    MisalignedPayload,
+    /// All buffers of the provided buffer pool are in use. The received data remains queued.
+    PoolExhausted,
+    /// The payload of the received data is larger than the buffers of the provided buffer
+    /// pool. The received data was discarded.
+    ExceedsPoolBufferSize
 };
 
 /// Failure that can be emitted when a [`RequestMut`] is sent.
@@ -100,6 +130,10 @@ enum class RequestSendError : uint8_t {
     ConnectionError,
     /// An internal mechanisms failed and the data could not be delivered to all receivers.
     InternalError,
+    /// Every slot of the static data segment is in use, for instance because slow
+    /// receivers still hold the samples. Becomes available again as soon as samples
+    /// are released.
+    LoanErrorSegmentFull,
 };
 } // namespace iox2
 
@@ -41,6 +41,10 @@ class PublisherDetailsView {
     /// The current maximum length of a slice.
     auto max_slice_len() const -> uint64_t;
 
+    /// The maximum number of data segments the [`Publisher`] can have. Is only greater than
+    /// one when the [`Publisher`] uses a dynamic data segment.
+    auto max_number_of_segments() const -> uint8_t;
+
   private:
     template <typename T, typename>
     friend auto internal::list_ports_callback(void* context, T port_details_view) -> iox2_callback_progression_e;
@@ -32,6 +32,9 @@ enum class PublisherCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The datasegment of the [`Publisher`] could not be bound to the requested
+    /// NUMA node.
+    UnableToBindToNumaNode,
 };
 } // namespace iox2
 
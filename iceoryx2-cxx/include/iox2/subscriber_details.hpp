@@ -38,6 +38,10 @@ class SubscriberDetailsView {
     /// The receive buffer size for incoming samples.
     auto buffer_size() const -> uint64_t;
 
+    /// The number of samples that were evicted from the receive buffer before they were
+    /// consumed.
+    auto overflowed_samples() const -> uint64_t;
+
   private:
     template <typename T, typename>
     friend auto internal::list_ports_callback(void* context, T port_details_view) -> iox2_callback_progression_e;
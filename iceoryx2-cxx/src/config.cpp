@@ -498,6 +498,22 @@ void Node::set_service_tag_suffix(const iox2::bb::FileName& value) && {
     iox2_config_global_node_set_service_tag_suffix(m_config, value.as_string().unchecked_access().c_str());
 }
 
+auto Node::heartbeat_suffix() && -> const char* {
+    return iox2_config_global_node_heartbeat_suffix(m_config);
+}
+
+void Node::set_heartbeat_suffix(const iox2::bb::FileName& value) && {
+    iox2_config_global_node_set_heartbeat_suffix(m_config, value.as_string().unchecked_access().c_str());
+}
+
+auto Node::enable_heartbeat() && -> bool {
+    return iox2_config_global_node_enable_heartbeat(m_config);
+}
+
+void Node::set_enable_heartbeat(bool value) && {
+    iox2_config_global_node_set_enable_heartbeat(m_config, value);
+}
+
 auto Node::cleanup_dead_nodes_on_creation() && -> bool {
     return iox2_config_global_node_cleanup_dead_nodes_on_creation(m_config);
 }
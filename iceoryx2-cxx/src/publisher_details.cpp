@@ -51,4 +51,8 @@ auto PublisherDetailsView::number_of_samples() const -> uint64_t {
 auto PublisherDetailsView::max_slice_len() const -> uint64_t {
     return iox2_publisher_details_max_slice_len(m_handle);
 }
+
+auto PublisherDetailsView::max_number_of_segments() const -> uint8_t {
+    return iox2_publisher_details_max_number_of_segments(m_handle);
+}
 } // namespace iox2
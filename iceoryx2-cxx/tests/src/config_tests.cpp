@@ -276,6 +276,23 @@ TEST(Config, global_node_service_tag_suffix) {
     ASSERT_THAT(config.global().node().service_tag_suffix(), StrEq(test_value.as_string().unchecked_access().c_str()));
 }
 
+TEST(Config, global_node_heartbeat_suffix) {
+    const auto test_value = iox2::bb::FileName::create("who_is_thumping").value();
+    auto config = Config();
+
+    config.global().node().set_heartbeat_suffix(test_value);
+    ASSERT_THAT(config.global().node().heartbeat_suffix(), StrEq(test_value.as_string().unchecked_access().c_str()));
+}
+
+TEST(Config, global_node_enable_heartbeat) {
+    auto config = Config();
+
+    config.global().node().set_enable_heartbeat(true);
+    ASSERT_THAT(config.global().node().enable_heartbeat(), Eq(true));
+    config.global().node().set_enable_heartbeat(false);
+    ASSERT_THAT(config.global().node().enable_heartbeat(), Eq(false));
+}
+
 TEST(Config, global_node_cleanup_dead_nodes_on_creation) {
     auto config = Config();
 
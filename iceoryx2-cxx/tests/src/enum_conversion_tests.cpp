@@ -66,6 +66,7 @@ TEST(EnumConversionTest, node_creation_failure_into_c_str) {
     using Sut = iox2::NodeCreationFailure;
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InternalError)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InvalidShmNamePrefix)), 1U);
 }
 
 TEST(EnumConversionTest, node_wait_failure_into_c_str) {
@@ -88,6 +89,7 @@ TEST(EnumConversionTest, publisher_create_into_c_str) {
     using Sut = iox2::PublisherCreateError;
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxSupportedPublishers)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::UnableToCreateDataSegment)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::UnableToBindToNumaNode)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_loan_into_c_str) {
@@ -96,6 +98,7 @@ TEST(EnumConversionTest, publisher_loan_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxLoanedSamples)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxLoanSize)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InternalFailure)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::SegmentFull)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_send_into_c_str) {
@@ -107,6 +110,11 @@ TEST(EnumConversionTest, publisher_send_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::LoanErrorExceedsMaxLoanSize)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::LoanErrorInternalFailure)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ConnectionError)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::RateLimited)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::Timeout)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::UninitializedPayload)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::SampleFromOtherPublisher)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::LoanErrorSegmentFull)), 1U);
 }
 
 TEST(EnumConversionTest, event_open_into_c_str) {
@@ -244,6 +252,10 @@ TEST(EnumConversionTest, subscriber_receive_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxBorrows)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::FailedToEstablishConnection)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::UnableToMapSendersDataSegment)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ForeignEndianness)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::MisalignedPayload)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::PoolExhausted)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsPoolBufferSize)), 1U);
 }
 
 TEST(EnumConversionTest, subscriber_create_into_c_str) {
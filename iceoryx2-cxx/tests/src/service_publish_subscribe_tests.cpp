@@ -311,7 +311,7 @@ TYPED_TEST(ServicePublishSubscribeTest, override_preallocated_samples_to_one_wor
     auto sample_1 = sut_publisher.loan().value();
     auto sample_2 = sut_publisher.loan();
     ASSERT_THAT(sample_2.has_value(), Eq(false));
-    ASSERT_THAT(sample_2.error(), Eq(LoanError::OutOfMemory));
+    ASSERT_THAT(sample_2.error(), Eq(LoanError::SegmentFull));
 }
 
 struct DummyData {
@@ -1674,6 +1674,37 @@ TYPED_TEST(ServicePublishSubscribeTest, publisher_details_are_correct) {
     ASSERT_THAT(counter, Eq(1));
 }
 
+TYPED_TEST(ServicePublishSubscribeTest, listing_two_publishers_provides_their_counts) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+    constexpr uint64_t NUMBER_OF_PUBLISHERS = 2;
+
+    const auto service_name = iox2_testing::generate_service_name();
+    auto node = NodeBuilder().create<SERVICE_TYPE>().value();
+    auto sut = node.service_builder(service_name).template publish_subscribe<uint64_t>().create().value();
+
+    auto publisher_1 = sut.publisher_builder().create().value();
+    auto publisher_2 = sut.publisher_builder().create().value();
+    auto subscriber = sut.subscriber_builder().create().value();
+
+    auto counter = 0U;
+    sut.dynamic_config().list_publishers([&](auto publisher_details_view) -> auto {
+        counter++;
+        EXPECT_TRUE(publisher_details_view.node_id() == node.id());
+        EXPECT_THAT(publisher_details_view.max_number_of_segments(), Eq(1));
+        return CallbackProgression::Continue;
+    });
+    ASSERT_THAT(counter, Eq(NUMBER_OF_PUBLISHERS));
+
+    counter = 0;
+    sut.dynamic_config().list_subscribers([&](auto subscriber_details_view) -> auto {
+        counter++;
+        EXPECT_TRUE(subscriber_details_view.subscriber_id() == subscriber.id());
+        EXPECT_THAT(subscriber_details_view.overflowed_samples(), Eq(0));
+        return CallbackProgression::Continue;
+    });
+    ASSERT_THAT(counter, Eq(1));
+}
+
 TYPED_TEST(ServicePublishSubscribeTest, only_max_publishers_can_be_created) {
     constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
 
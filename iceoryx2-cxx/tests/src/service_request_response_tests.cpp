@@ -581,7 +581,7 @@ TYPED_TEST(ServiceRequestResponseTest, override_preallocated_requests_to_one_wor
     auto request_1 = sut_client.loan().value();
     auto request_2 = sut_client.loan();
     ASSERT_THAT(request_2.has_value(), Eq(false));
-    ASSERT_THAT(request_2.error(), Eq(LoanError::OutOfMemory));
+    ASSERT_THAT(request_2.error(), Eq(LoanError::SegmentFull));
 }
 
 TYPED_TEST(ServiceRequestResponseTest, override_preallocated_responses_to_one_works) {
@@ -604,7 +604,7 @@ TYPED_TEST(ServiceRequestResponseTest, override_preallocated_responses_to_one_wo
     auto response_1 = active_request.loan().value();
     auto response_2 = active_request.loan();
     ASSERT_THAT(response_2.has_value(), Eq(false));
-    ASSERT_THAT(response_2.error(), Eq(LoanError::OutOfMemory));
+    ASSERT_THAT(response_2.error(), Eq(LoanError::SegmentFull));
 }
 
 struct DummyData {
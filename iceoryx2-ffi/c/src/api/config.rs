@@ -677,6 +677,90 @@ pub unsafe extern "C" fn iox2_config_global_node_set_service_tag_suffix(
     }
 }
 
+/// Returns the suffix of the node heartbeat.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_config_global_node_heartbeat_suffix(
+    handle: iox2_config_h_ref,
+) -> *const c_char {
+    handle.assert_non_null();
+    unsafe {
+        let config = &*handle.as_type();
+        config
+            .value
+            .as_ref()
+            .value
+            .global
+            .node
+            .heartbeat_suffix
+            .as_c_str()
+    }
+}
+
+/// Sets the suffix of the node heartbeat.
+///
+/// Returns: [`iox2_semantic_string_error_e`](crate::api::iox2_semantic_string_error_e) when an
+/// invalid file name was provided
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `value` - A valid file name containing the suffix
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_config_global_node_set_heartbeat_suffix(
+    handle: iox2_config_h_ref,
+    value: *const c_char,
+) -> c_int {
+    handle.assert_non_null();
+    unsafe {
+        let config = &mut *handle.as_type();
+        match FileName::from_c_str(value) {
+            Ok(n) => {
+                config.value.as_mut().value.global.node.heartbeat_suffix = n;
+                IOX2_OK as _
+            }
+            Err(e) => e as c_int,
+        }
+    }
+}
+
+/// When true, every [`iox2_node_h`](crate::api::iox2_node_h) creates a heartbeat that other
+/// nodes can use to check its liveliness.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_config_global_node_enable_heartbeat(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+    unsafe {
+        let config = &*handle.as_type();
+        config.value.as_ref().value.global.node.enable_heartbeat
+    }
+}
+
+/// Enable/disable the node heartbeat
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_config_global_node_set_enable_heartbeat(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+    unsafe {
+        let config = &mut *handle.as_type();
+        config.value.as_mut().value.global.node.enable_heartbeat = value;
+    }
+}
+
 /// When true, [`iox2_node_builder_create()`](crate::api::iox2_node_builder_create) checks for dead
 /// nodes and cleans up all their stale resources whenever a new
 /// [`iox2_node_h`](crate::api::iox2_node_h) is created.
@@ -1264,6 +1348,62 @@ pub unsafe extern "C" fn iox2_config_global_service_set_blackboard_data_suffix(
         }
     }
 }
+
+/// Returns the suffix of the file that stores the creation epoch of a service
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_config_global_service_creation_epoch_suffix(
+    handle: iox2_config_h_ref,
+) -> *const c_char {
+    handle.assert_non_null();
+    unsafe {
+        let config = &*handle.as_type();
+        config
+            .value
+            .as_ref()
+            .value
+            .global
+            .service
+            .creation_epoch_suffix
+            .as_c_str()
+    }
+}
+
+/// Sets the suffix of the file that stores the creation epoch of a service
+///
+/// Returns: [`iox2_semantic_string_error_e`](crate::api::iox2_semantic_string_error_e) when an
+/// invalid file name was provided
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `value` - A valid file name containing the suffix
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_config_global_service_set_creation_epoch_suffix(
+    handle: iox2_config_h_ref,
+    value: *const c_char,
+) -> c_int {
+    handle.assert_non_null();
+    unsafe {
+        let config = &mut *handle.as_type();
+        match FileName::from_c_str(value) {
+            Ok(n) => {
+                config
+                    .value
+                    .as_mut()
+                    .value
+                    .global
+                    .service
+                    .creation_epoch_suffix = n;
+                IOX2_OK as _
+            }
+            Err(e) => e as c_int,
+        }
+    }
+}
 /////////////////
 // END: service
 /////////////////
@@ -97,6 +97,7 @@ pub enum iox2_publisher_create_error_e {
     EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    UNABLE_TO_BIND_TO_NUMA_NODE,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -111,6 +112,9 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_publisher_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            PublisherCreateError::UnableToBindToNumaNode => {
+                iox2_publisher_create_error_e::UNABLE_TO_BIND_TO_NUMA_NODE
+            }
         }) as c_int
     }
 }
@@ -27,7 +27,7 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<Header>>()
 pub struct iox2_publish_subscribe_header_storage_t {
-    internal: [u8; 48], // core::mem::size_of::<Option<Header>>()
+    internal: [u8; 64], // core::mem::size_of::<Option<Header>>()
 }
 
 #[repr(C)]
@@ -182,4 +182,31 @@ pub unsafe extern "C" fn iox2_publish_subscribe_header_number_of_elements(
         header.value.as_ref().number_of_elements()
     }
 }
+/// Writes the sequence number the publisher stamped on the sample into `sequence_number_ptr`.
+/// The sequence number is monotonically increasing for every sent sample of a publisher
+/// and can be used to detect lost samples.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///   [`iox2_sample_header()`](crate::iox2_sample_header)
+/// * `sequence_number_ptr` valid pointer to a `uint64_t` that receives the sequence number
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+/// * `sequence_number_ptr` is valid and non-null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_sequence_number(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+    sequence_number_ptr: *mut u64,
+) {
+    header_handle.assert_non_null();
+    debug_assert!(!sequence_number_ptr.is_null());
+    unsafe {
+        let header = &mut *header_handle.as_type();
+
+        *sequence_number_ptr = header.value.as_ref().sequence_number();
+    }
+}
 // END C API
@@ -20,7 +20,7 @@ use crate::api::{
 
 use iceoryx2::port::LoanError;
 use iceoryx2::port::SendError;
-use iceoryx2::port::publisher::Publisher;
+use iceoryx2::port::publisher::{Publisher, PublisherShutdownError};
 use iceoryx2::port::update_connections::UpdateConnections;
 use iceoryx2::prelude::*;
 use iceoryx2_bb_elementary::static_assert::*;
@@ -33,6 +33,7 @@ use super::{IntoCInt, iox2_sample_mut_h, iox2_sample_mut_t};
 use crate::c_size_t;
 use core::ffi::{c_char, c_int, c_void};
 use core::mem::ManuallyDrop;
+use core::time::Duration;
 
 // BEGIN types definition
 
@@ -47,6 +48,11 @@ pub enum iox2_send_error_e {
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
     INTERNAL_ERROR,
+    RATE_LIMITED,
+    TIMEOUT,
+    UNINITIALIZED_PAYLOAD,
+    SAMPLE_FROM_OTHER_PUBLISHER,
+    LOAN_ERROR_SEGMENT_FULL,
 }
 
 impl IntoCInt for SendError {
@@ -60,6 +66,9 @@ impl IntoCInt for SendError {
             SendError::LoanError(LoanError::OutOfMemory) => {
                 iox2_send_error_e::LOAN_ERROR_OUT_OF_MEMORY
             }
+            SendError::LoanError(LoanError::SegmentFull) => {
+                iox2_send_error_e::LOAN_ERROR_SEGMENT_FULL
+            }
             SendError::LoanError(LoanError::ExceedsMaxLoans) => {
                 iox2_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOANS
             }
@@ -70,6 +79,10 @@ impl IntoCInt for SendError {
                 iox2_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
             SendError::ConnectionError(_) => iox2_send_error_e::CONNECTION_ERROR,
+            SendError::RateLimited => iox2_send_error_e::RATE_LIMITED,
+            SendError::Timeout => iox2_send_error_e::TIMEOUT,
+            SendError::UninitializedPayload => iox2_send_error_e::UNINITIALIZED_PAYLOAD,
+            SendError::SampleFromOtherPublisher => iox2_send_error_e::SAMPLE_FROM_OTHER_PUBLISHER,
         }) as c_int
     }
 }
@@ -78,6 +91,7 @@ impl IntoCInt for LoanError {
     fn into_c_int(self) -> c_int {
         (match self {
             LoanError::OutOfMemory => iox2_loan_error_e::OUT_OF_MEMORY,
+            LoanError::SegmentFull => iox2_loan_error_e::SEGMENT_FULL,
             LoanError::ExceedsMaxLoans => iox2_loan_error_e::EXCEEDS_MAX_LOANED_SAMPLES,
             LoanError::ExceedsMaxLoanSize => iox2_loan_error_e::EXCEEDS_MAX_LOAN_SIZE,
             LoanError::InternalFailure => iox2_loan_error_e::INTERNAL_FAILURE,
@@ -92,6 +106,25 @@ pub enum iox2_loan_error_e {
     EXCEEDS_MAX_LOANED_SAMPLES,
     EXCEEDS_MAX_LOAN_SIZE,
     INTERNAL_FAILURE,
+    SEGMENT_FULL,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, CStrRepr)]
+pub enum iox2_publisher_shutdown_error_e {
+    TIMEOUT = IOX2_OK as isize + 1,
+    INTERNAL_FAILURE,
+}
+
+impl IntoCInt for PublisherShutdownError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            PublisherShutdownError::Timeout => iox2_publisher_shutdown_error_e::TIMEOUT,
+            PublisherShutdownError::InternalFailure => {
+                iox2_publisher_shutdown_error_e::INTERNAL_FAILURE
+            }
+        }) as c_int
+    }
 }
 
 pub(super) union PublisherUnion {
@@ -215,6 +248,24 @@ unsafe fn send_copy<S: Service>(
     IOX2_OK
 }
 
+unsafe fn send_copy_if_subscribed<S: Service>(
+    publisher: &Publisher<S, PayloadFfi, UserHeaderFfi>,
+    data_ptr: *const c_void,
+    size_of_element: usize,
+    number_of_recipients: *mut usize,
+) -> c_int {
+    unsafe {
+        if publisher.connection_stats().next().is_none() {
+            if !number_of_recipients.is_null() {
+                *number_of_recipients = 0;
+            }
+            return IOX2_OK;
+        }
+
+        send_copy(publisher, data_ptr, size_of_element, number_of_recipients)
+    }
+}
+
 unsafe fn send_slice_copy<S: Service>(
     publisher: &Publisher<S, PayloadFfi, UserHeaderFfi>,
     data_ptr: *const c_void,
@@ -247,6 +298,22 @@ unsafe fn send_slice_copy<S: Service>(
     IOX2_OK
 }
 
+unsafe fn shutdown<S: Service>(
+    publisher: &Publisher<S, PayloadFfi, UserHeaderFfi>,
+    timeout: Duration,
+    remaining_samples: *mut c_size_t,
+) -> c_int {
+    match publisher.shutdown(timeout) {
+        Ok(()) => IOX2_OK,
+        Err(e) => {
+            if !remaining_samples.is_null() {
+                unsafe { *remaining_samples = publisher.number_of_samples_in_use() };
+            }
+            e.into_c_int()
+        }
+    }
+}
+
 // BEGIN C API
 
 /// Returns a string literal describing the provided [`iox2_send_error_e`].
@@ -268,6 +335,27 @@ pub unsafe extern "C" fn iox2_send_error_string(error: iox2_send_error_e) -> *co
     error.as_const_cstr().as_ptr() as *const c_char
 }
 
+/// Returns a string literal describing the provided [`iox2_publisher_shutdown_error_e`].
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// The returned pointer must not be modified or freed and is valid as long as the program runs.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_publisher_shutdown_error_string(
+    error: iox2_publisher_shutdown_error_e,
+) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
 /// Returns a string literal describing the provided [`iox2_loan_error_e`].
 ///
 /// # Arguments
@@ -498,6 +586,56 @@ pub unsafe extern "C" fn iox2_publisher_send_copy(
     }
 }
 
+/// Sends a copy of the provided data via the publisher but only when at least one subscriber
+/// is connected. Otherwise, no sample is loaned and the send is skipped. The data must be
+/// copyable via `memcpy`.
+///
+/// # Arguments
+///
+/// * `handle` obtained by [`iox2_port_factory_publisher_builder_create`](crate::iox2_port_factory_publisher_builder_create)
+/// * `data_ptr` pointer to the payload that shall be transmitted
+/// * `data_len` the size of the payload in bytes
+/// * `out_recipients` (optional) used to store the number of subscriber that received the data,
+///   it is set to 0 when the send was skipped
+///
+/// Return [`IOX2_OK`] on success, otherwise [`iox2_send_error_e`].
+///
+/// # Safety
+///
+/// * `publisher_handle` is valid and non-null
+/// * `data_ptr` non-null pointer to a valid position in memory
+/// * `data_len` the size of the payload memory
+/// * `out_recipients` can be null, otherwise a valid pointer to a [`c_size_t`]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_publisher_send_copy_if_subscribed(
+    publisher_handle: iox2_publisher_h_ref,
+    data_ptr: *const c_void,
+    data_len: c_size_t,
+    out_recipients: *mut c_size_t,
+) -> c_int {
+    publisher_handle.assert_non_null();
+    debug_assert!(!data_ptr.is_null());
+    debug_assert!(data_len != 0);
+    unsafe {
+        let publisher = &mut *publisher_handle.as_type();
+
+        match publisher.service_type {
+            iox2_service_type_e::IPC => send_copy_if_subscribed(
+                &publisher.value.as_mut().ipc,
+                data_ptr,
+                data_len,
+                out_recipients,
+            ),
+            iox2_service_type_e::LOCAL => send_copy_if_subscribed(
+                &publisher.value.as_mut().local,
+                data_ptr,
+                data_len,
+                out_recipients,
+            ),
+        }
+    }
+}
+
 /// Loans memory from the publishers data segment.
 ///
 /// # Arguments
@@ -614,6 +752,55 @@ pub unsafe extern "C" fn iox2_publisher_update_connections(
     }
 }
 
+/// Waits until all samples that were delivered by the publisher were released by the
+/// subscribers and destroys the publisher afterwards. If the timeout is reached before,
+/// the publisher is not destroyed and the number of samples that are still in use is
+/// written into `out_remaining`.
+///
+/// # Arguments
+///
+/// * `publisher_handle` - A valid [`iox2_publisher_h`]
+/// * `timeout_ns` - The maximum time in nanoseconds to wait for the samples to be released
+/// * `out_remaining` - Either a NULL pointer or a pointer to a `c_size_t` that stores the number
+///   of samples still in use when the timeout was reached
+///
+/// Returns IOX2_OK on success, an [`iox2_publisher_shutdown_error_e`] otherwise.
+///
+/// # Safety
+///
+/// * On success, the `publisher_handle` is invalid after the return of this function and leads
+///   to undefined behavior if used in another function call!
+/// * On failure, the `publisher_handle` is still valid and must be destroyed with
+///   [`iox2_publisher_drop`] or another call to [`iox2_publisher_shutdown`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_publisher_shutdown(
+    publisher_handle: iox2_publisher_h,
+    timeout_ns: u64,
+    out_remaining: *mut c_size_t,
+) -> c_int {
+    publisher_handle.assert_non_null();
+    let timeout = Duration::from_nanos(timeout_ns);
+
+    unsafe {
+        let publisher = &mut *publisher_handle.as_type();
+
+        let result = match publisher.service_type {
+            iox2_service_type_e::IPC => {
+                shutdown(&publisher.value.as_ref().ipc, timeout, out_remaining)
+            }
+            iox2_service_type_e::LOCAL => {
+                shutdown(&publisher.value.as_ref().local, timeout, out_remaining)
+            }
+        };
+
+        if result == IOX2_OK {
+            iox2_publisher_drop(publisher_handle);
+        }
+
+        result
+    }
+}
+
 /// This function needs to be called to destroy the publisher!
 ///
 /// # Arguments
@@ -93,3 +93,17 @@ pub unsafe extern "C" fn iox2_publisher_details_max_slice_len(
     debug_assert!(!handle.is_null());
     unsafe { (*handle).max_slice_len as _ }
 }
+
+/// Returns how many data segments the publisher can have at most. Is only greater than one
+/// when the publisher uses a dynamic data segment.
+///
+/// # Safety
+///
+/// * `handle` valid pointer to the publisher details
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_publisher_details_max_number_of_segments(
+    handle: iox2_publisher_details_ptr,
+) -> u8 {
+    debug_assert!(!handle.is_null());
+    unsafe { (*handle).max_number_of_segments }
+}
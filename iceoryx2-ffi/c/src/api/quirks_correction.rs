@@ -29,7 +29,8 @@ use crate::{
 
 use super::{
     iox2_client_create_error_e, iox2_connection_failure_e, iox2_node_cleanup_failure_e,
-    iox2_request_response_open_or_create_error_e, iox2_request_send_error_e,
+    iox2_publisher_shutdown_error_e, iox2_request_response_open_or_create_error_e,
+    iox2_request_send_error_e,
 };
 
 #[doc(hidden)]
@@ -249,3 +250,11 @@ pub unsafe extern "C" fn __iox2_internal_server_create_error_stub() -> iox2_serv
 pub unsafe extern "C" fn __iox2_internal_request_send_error_stub() -> iox2_request_send_error_e {
     iox2_request_send_error_e::CONNECTION_BROKEN_SINCE_SENDER_NO_LONGER_EXISTS
 }
+
+#[doc(hidden)]
+#[unsafe(no_mangle)]
+// TODO: enums are only exported when they are actually used by some function
+pub unsafe extern "C" fn __iox2_internal_publisher_shutdown_error_stub()
+-> iox2_publisher_shutdown_error_e {
+    iox2_publisher_shutdown_error_e::TIMEOUT
+}
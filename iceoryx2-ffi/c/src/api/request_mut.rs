@@ -46,6 +46,7 @@ pub enum iox2_request_send_error_e {
     CONNECTION_ERROR,
     EXCEEDS_MAX_ACTIVE_REQUESTS,
     INTERNAL_ERROR,
+    LOAN_ERROR_SEGMENT_FULL,
 }
 
 impl IntoCInt for RequestSendError {
@@ -60,6 +61,9 @@ impl IntoCInt for RequestSendError {
             RequestSendError::SendError(SendError::LoanError(LoanError::OutOfMemory)) => {
                 iox2_request_send_error_e::LOAN_ERROR_OUT_OF_MEMORY
             }
+            RequestSendError::SendError(SendError::LoanError(LoanError::SegmentFull)) => {
+                iox2_request_send_error_e::LOAN_ERROR_SEGMENT_FULL
+            }
             RequestSendError::SendError(SendError::LoanError(LoanError::ExceedsMaxLoans)) => {
                 iox2_request_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOANS
             }
@@ -75,7 +79,14 @@ impl IntoCInt for RequestSendError {
             RequestSendError::ExceedsMaxActiveRequests => {
                 iox2_request_send_error_e::EXCEEDS_MAX_ACTIVE_REQUESTS
             }
-            RequestSendError::SendError(SendError::InternalError) => {
+            // clients have neither a send rate limit, a blocking send with timeout, a
+            // validation of the payload initialization nor a batch send, therefore these
+            // cannot occur
+            RequestSendError::SendError(SendError::InternalError)
+            | RequestSendError::SendError(SendError::RateLimited)
+            | RequestSendError::SendError(SendError::Timeout)
+            | RequestSendError::SendError(SendError::UninitializedPayload)
+            | RequestSendError::SendError(SendError::SampleFromOtherPublisher) => {
                 iox2_request_send_error_e::INTERNAL_ERROR
             }
         }) as c_int
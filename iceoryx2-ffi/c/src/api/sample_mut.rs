@@ -19,15 +19,24 @@ use crate::api::{
 
 use iceoryx2::sample_mut_uninit::SampleMutUninit;
 use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_bb_elementary_traits::AsCStr;
+use iceoryx2_ffi_macros::CStrRepr;
 use iceoryx2_ffi_macros::iceoryx2_ffi;
 
-use core::ffi::{c_int, c_void};
+use core::ffi::{c_char, c_int, c_void};
 use core::mem::ManuallyDrop;
 
 use super::UninitPayloadFfi;
 
 // BEGIN types definition
 
+#[repr(C)]
+#[derive(Copy, Clone, CStrRepr)]
+pub enum iox2_payload_element_size_error_e {
+    ELEMENT_SIZE_IS_ZERO = IOX2_OK as isize + 1,
+    PAYLOAD_SIZE_IS_NOT_A_MULTIPLE_OF_ELEMENT_SIZE,
+}
+
 pub(super) union SampleMutUninitUnion {
     ipc: ManuallyDrop<SampleMutUninit<crate::IpcService, UninitPayloadFfi, UserHeaderFfi>>,
     local: ManuallyDrop<SampleMutUninit<crate::LocalService, UninitPayloadFfi, UserHeaderFfi>>,
@@ -118,6 +127,27 @@ impl HandleToType for iox2_sample_mut_h_ref {
 
 // BEGIN C API
 
+/// Returns a string literal describing the provided [`iox2_payload_element_size_error_e`].
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// The returned pointer must not be modified or freed and is valid as long as the program runs.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_payload_element_size_error_string(
+    error: iox2_payload_element_size_error_e,
+) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
 /// cbindgen:ignore
 /// Internal API - do not use
 /// # Safety
@@ -311,6 +341,53 @@ pub unsafe extern "C" fn iox2_sample_mut_payload(
     }
 }
 
+/// Acquires the samples mutable payload as a typed slice whose elements have the size
+/// `element_size`. The number of elements is the payload size in bytes divided by
+/// `element_size`.
+///
+/// Returns [`IOX2_OK`] on success, otherwise [`iox2_payload_element_size_error_e`] when
+/// `element_size` is zero or the payload size is not a multiple of `element_size`. In the
+/// error case `payload_ptr` and `number_of_elements` are not modified.
+///
+/// # Safety
+///
+/// * `handle` obtained by [`iox2_publisher_loan_slice_uninit()`](crate::iox2_publisher_loan_slice_uninit())
+/// * `payload_ptr` a valid, non-null pointer pointing to a [`*mut c_void`] pointer.
+/// * `number_of_elements` a valid, non-null pointer pointing to a [`c_size_t`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_sample_mut_payload_typed(
+    handle: iox2_sample_mut_h_ref,
+    element_size: c_size_t,
+    payload_ptr: *mut *mut c_void,
+    number_of_elements: *mut c_size_t,
+) -> c_int {
+    handle.assert_non_null();
+    debug_assert!(!payload_ptr.is_null());
+    debug_assert!(!number_of_elements.is_null());
+
+    if element_size == 0 {
+        return iox2_payload_element_size_error_e::ELEMENT_SIZE_IS_ZERO as c_int;
+    }
+
+    unsafe {
+        let sample = &mut *handle.as_type();
+        let payload = match sample.service_type {
+            iox2_service_type_e::IPC => sample.value.as_mut().ipc.payload_mut(),
+            iox2_service_type_e::LOCAL => sample.value.as_mut().local.payload_mut(),
+        };
+
+        if payload.len() % element_size != 0 {
+            return iox2_payload_element_size_error_e::PAYLOAD_SIZE_IS_NOT_A_MULTIPLE_OF_ELEMENT_SIZE
+                as c_int;
+        }
+
+        *payload_ptr = payload.as_mut_ptr().cast();
+        *number_of_elements = payload.len() / element_size;
+    }
+
+    IOX2_OK
+}
+
 /// Takes the ownership of the sample and sends it
 ///
 /// # Safety
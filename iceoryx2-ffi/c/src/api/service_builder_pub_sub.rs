@@ -102,7 +102,8 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::InternalFailure => {
              iox2_pub_sub_open_or_create_error_e::O_INTERNAL_FAILURE
          }
-         PublishSubscribeOpenError::IncompatibleTypes => {
+         PublishSubscribeOpenError::IncompatibleTypes
+         | PublishSubscribeOpenError::IncompatibleTypeName => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_TYPES
          }
          PublishSubscribeOpenError::IncompatibleMessagingPattern => {
@@ -147,6 +148,9 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IsMarkedForDestruction => {
              iox2_pub_sub_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
          }
+         PublishSubscribeOpenError::Timeout => {
+             iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_EXIST
+         }
         }) as c_int
     }
 }
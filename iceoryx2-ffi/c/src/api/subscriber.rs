@@ -37,6 +37,10 @@ pub enum iox2_receive_error_e {
     EXCEEDS_MAX_BORROWS = IOX2_OK as isize + 1,
     FAILED_TO_ESTABLISH_CONNECTION,
     UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+    FOREIGN_ENDIANNESS,
+    MISALIGNED_PAYLOAD,
+    POOL_EXHAUSTED,
+    EXCEEDS_POOL_BUFFER_SIZE,
 }
 
 impl IntoCInt for ReceiveError {
@@ -49,6 +53,10 @@ impl IntoCInt for ReceiveError {
             ReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapSendersDataSegment(
                 _,
             )) => iox2_receive_error_e::UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+            ReceiveError::ForeignEndianness => iox2_receive_error_e::FOREIGN_ENDIANNESS,
+            ReceiveError::MisalignedPayload => iox2_receive_error_e::MISALIGNED_PAYLOAD,
+            ReceiveError::PoolExhausted => iox2_receive_error_e::POOL_EXHAUSTED,
+            ReceiveError::ExceedsPoolBufferSize => iox2_receive_error_e::EXCEEDS_POOL_BUFFER_SIZE,
         }) as c_int
     }
 }
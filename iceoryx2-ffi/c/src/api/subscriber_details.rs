@@ -79,3 +79,17 @@ pub unsafe extern "C" fn iox2_subscriber_details_buffer_size(
     debug_assert!(!handle.is_null());
     unsafe { (*handle).buffer_size as _ }
 }
+
+/// Returns how many samples were evicted from the receive buffer of the subscriber before
+/// they were consumed.
+///
+/// # Safety
+///
+/// * `handle` valid pointer to the subscriber details
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_subscriber_details_overflowed_samples(
+    handle: iox2_subscriber_details_ptr,
+) -> u64 {
+    debug_assert!(!handle.is_null());
+    unsafe { (*handle).overflowed_samples() }
+}
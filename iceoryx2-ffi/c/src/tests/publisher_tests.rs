@@ -0,0 +1,396 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod publisher {
+    use crate::api::*;
+    use crate::c_size_t;
+    use crate::tests::{ServiceTypeMapping, create_node};
+    use core::ffi::c_int;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT_NS: u64 = 50_000_000;
+
+    unsafe fn create_pub_sub_service(
+        node_handle: iox2_node_h_ref,
+        service_name: &str,
+    ) -> iox2_port_factory_pub_sub_h {
+        unsafe {
+            let mut service_name_handle: iox2_service_name_h = core::ptr::null_mut();
+            let ret_val = iox2_service_name_new(
+                core::ptr::null_mut(),
+                service_name.as_ptr() as *const _,
+                service_name.len(),
+                &mut service_name_handle,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let service_builder_handle = iox2_node_service_builder(
+                node_handle,
+                core::ptr::null_mut(),
+                iox2_cast_service_name_ptr(service_name_handle),
+            );
+            iox2_service_name_drop(service_name_handle);
+
+            let service_builder_handle = iox2_service_builder_pub_sub(service_builder_handle);
+            let type_name = "u64";
+            let ret_val = iox2_service_builder_pub_sub_set_payload_type_details(
+                &service_builder_handle,
+                iox2_type_variant_e::FIXED_SIZE,
+                type_name.as_ptr() as *const _,
+                type_name.len(),
+                core::mem::size_of::<u64>(),
+                core::mem::align_of::<u64>(),
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let mut pub_sub_factory: iox2_port_factory_pub_sub_h = core::ptr::null_mut();
+            let ret_val = iox2_service_builder_pub_sub_open_or_create(
+                service_builder_handle,
+                core::ptr::null_mut(),
+                &mut pub_sub_factory as *mut _,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            pub_sub_factory
+        }
+    }
+
+    unsafe fn create_ports(
+        pub_sub_factory: iox2_port_factory_pub_sub_h_ref,
+    ) -> (iox2_publisher_h, iox2_subscriber_h) {
+        unsafe {
+            let publisher_builder_handle =
+                iox2_port_factory_pub_sub_publisher_builder(pub_sub_factory, core::ptr::null_mut());
+            let mut publisher_handle: iox2_publisher_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_publisher_builder_create(
+                publisher_builder_handle,
+                core::ptr::null_mut(),
+                &mut publisher_handle,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let subscriber_builder_handle = iox2_port_factory_pub_sub_subscriber_builder(
+                pub_sub_factory,
+                core::ptr::null_mut(),
+            );
+            let mut subscriber_handle: iox2_subscriber_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_subscriber_builder_create(
+                subscriber_builder_handle,
+                core::ptr::null_mut(),
+                &mut subscriber_handle,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            (publisher_handle, subscriber_handle)
+        }
+    }
+
+    unsafe fn send_payload(publisher_handle: iox2_publisher_h_ref) {
+        unsafe {
+            let payload: u64 = 8912;
+            let ret_val = iox2_publisher_send_copy(
+                publisher_handle,
+                (&payload as *const u64).cast(),
+                core::mem::size_of::<u64>(),
+                core::ptr::null_mut(),
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+        }
+    }
+
+    #[test]
+    fn shutdown_succeeds_when_all_samples_are_released<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let node_handle = create_node::<S>("bar");
+            let pub_sub_factory =
+                create_pub_sub_service(&node_handle, "publisher/shutdown/released");
+            let (publisher_handle, subscriber_handle) = create_ports(&pub_sub_factory);
+
+            send_payload(&publisher_handle);
+
+            let mut sample_handle: iox2_sample_h = core::ptr::null_mut();
+            let ret_val = iox2_subscriber_receive(
+                &subscriber_handle,
+                core::ptr::null_mut(),
+                &mut sample_handle,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(sample_handle.is_null(), eq false);
+            iox2_sample_drop(sample_handle);
+
+            let mut remaining: c_size_t = 0;
+            let ret_val = iox2_publisher_shutdown(publisher_handle, TIMEOUT_NS, &mut remaining);
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(remaining, eq 0);
+
+            iox2_subscriber_drop(subscriber_handle);
+            iox2_port_factory_pub_sub_drop(pub_sub_factory);
+            iox2_node_drop(node_handle);
+        }
+    }
+
+    #[test]
+    fn shutdown_times_out_when_samples_are_still_in_use<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let node_handle = create_node::<S>("bar");
+            let pub_sub_factory = create_pub_sub_service(&node_handle, "publisher/shutdown/in/use");
+            let (publisher_handle, subscriber_handle) = create_ports(&pub_sub_factory);
+
+            send_payload(&publisher_handle);
+
+            let mut remaining: c_size_t = 0;
+            let ret_val = iox2_publisher_shutdown(publisher_handle, TIMEOUT_NS, &mut remaining);
+            assert_that!(
+                ret_val,
+                eq(iox2_publisher_shutdown_error_e::TIMEOUT as c_int)
+            );
+            assert_that!(remaining, eq 1);
+
+            iox2_publisher_drop(publisher_handle);
+            iox2_subscriber_drop(subscriber_handle);
+            iox2_port_factory_pub_sub_drop(pub_sub_factory);
+            iox2_node_drop(node_handle);
+        }
+    }
+
+    #[test]
+    fn sample_mut_payload_typed_validates_element_size<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let node_handle = create_node::<S>("bar");
+            let pub_sub_factory = create_pub_sub_service(&node_handle, "sample/mut/payload/typed");
+            let (publisher_handle, subscriber_handle) = create_ports(&pub_sub_factory);
+
+            let mut sample_handle: iox2_sample_mut_h = core::ptr::null_mut();
+            let ret_val = iox2_publisher_loan_slice_uninit(
+                &publisher_handle,
+                core::ptr::null_mut(),
+                &mut sample_handle,
+                1,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let mut payload_ptr: *mut core::ffi::c_void = core::ptr::null_mut();
+            let mut number_of_elements: c_size_t = 0;
+
+            let ret_val = iox2_sample_mut_payload_typed(
+                &sample_handle,
+                core::mem::size_of::<u32>(),
+                &mut payload_ptr,
+                &mut number_of_elements,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(payload_ptr.is_null(), eq false);
+            assert_that!(number_of_elements, eq 2);
+
+            let ret_val = iox2_sample_mut_payload_typed(
+                &sample_handle,
+                core::mem::size_of::<u64>(),
+                &mut payload_ptr,
+                &mut number_of_elements,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(number_of_elements, eq 1);
+
+            let ret_val = iox2_sample_mut_payload_typed(
+                &sample_handle,
+                3,
+                &mut payload_ptr,
+                &mut number_of_elements,
+            );
+            assert_that!(
+                ret_val,
+                eq(iox2_payload_element_size_error_e::PAYLOAD_SIZE_IS_NOT_A_MULTIPLE_OF_ELEMENT_SIZE
+                    as c_int)
+            );
+            assert_that!(number_of_elements, eq 1);
+
+            let ret_val = iox2_sample_mut_payload_typed(
+                &sample_handle,
+                0,
+                &mut payload_ptr,
+                &mut number_of_elements,
+            );
+            assert_that!(
+                ret_val,
+                eq(iox2_payload_element_size_error_e::ELEMENT_SIZE_IS_ZERO as c_int)
+            );
+
+            iox2_sample_mut_drop(sample_handle);
+            iox2_publisher_drop(publisher_handle);
+            iox2_subscriber_drop(subscriber_handle);
+            iox2_port_factory_pub_sub_drop(pub_sub_factory);
+            iox2_node_drop(node_handle);
+        }
+    }
+
+    #[test]
+    fn publishers_have_distinct_unique_ids<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let node_handle = create_node::<S>("bar");
+            let pub_sub_factory = create_pub_sub_service(&node_handle, "publisher/unique/ids");
+            let (publisher_handle, subscriber_handle) = create_ports(&pub_sub_factory);
+
+            let publisher_builder_handle = iox2_port_factory_pub_sub_publisher_builder(
+                &pub_sub_factory,
+                core::ptr::null_mut(),
+            );
+            let mut other_publisher_handle: iox2_publisher_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_publisher_builder_create(
+                publisher_builder_handle,
+                core::ptr::null_mut(),
+                &mut other_publisher_handle,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let mut id_handle: iox2_unique_publisher_id_h = core::ptr::null_mut();
+            iox2_publisher_id(&publisher_handle, core::ptr::null_mut(), &mut id_handle);
+            let mut same_id_handle: iox2_unique_publisher_id_h = core::ptr::null_mut();
+            iox2_publisher_id(
+                &publisher_handle,
+                core::ptr::null_mut(),
+                &mut same_id_handle,
+            );
+            let mut other_id_handle: iox2_unique_publisher_id_h = core::ptr::null_mut();
+            iox2_publisher_id(
+                &other_publisher_handle,
+                core::ptr::null_mut(),
+                &mut other_id_handle,
+            );
+
+            assert_that!(iox2_unique_publisher_id_eq(&id_handle, &same_id_handle), eq true);
+            assert_that!(iox2_unique_publisher_id_eq(&id_handle, &other_id_handle), eq false);
+
+            let mut subscriber_id_handle: iox2_unique_subscriber_id_h = core::ptr::null_mut();
+            iox2_subscriber_id(
+                &subscriber_handle,
+                core::ptr::null_mut(),
+                &mut subscriber_id_handle,
+            );
+            assert_that!(subscriber_id_handle.is_null(), eq false);
+
+            iox2_unique_subscriber_id_drop(subscriber_id_handle);
+            iox2_unique_publisher_id_drop(other_id_handle);
+            iox2_unique_publisher_id_drop(same_id_handle);
+            iox2_unique_publisher_id_drop(id_handle);
+            iox2_publisher_drop(other_publisher_handle);
+            iox2_publisher_drop(publisher_handle);
+            iox2_subscriber_drop(subscriber_handle);
+            iox2_port_factory_pub_sub_drop(pub_sub_factory);
+            iox2_node_drop(node_handle);
+        }
+    }
+
+    #[test]
+    fn received_samples_carry_consecutive_sequence_numbers<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let node_handle = create_node::<S>("bar");
+            let pub_sub_factory =
+                create_pub_sub_service(&node_handle, "publisher/header/sequence/number");
+            let (publisher_handle, subscriber_handle) = create_ports(&pub_sub_factory);
+
+            send_payload(&publisher_handle);
+            send_payload(&publisher_handle);
+
+            let mut sequence_numbers = [0u64; 2];
+            for sequence_number in &mut sequence_numbers {
+                let mut sample_handle: iox2_sample_h = core::ptr::null_mut();
+                let ret_val = iox2_subscriber_receive(
+                    &subscriber_handle,
+                    core::ptr::null_mut(),
+                    &mut sample_handle,
+                );
+                assert_that!(ret_val, eq(IOX2_OK));
+                assert_that!(sample_handle.is_null(), eq false);
+
+                let mut header_handle: iox2_publish_subscribe_header_h = core::ptr::null_mut();
+                iox2_sample_header(&sample_handle, core::ptr::null_mut(), &mut header_handle);
+                iox2_publish_subscribe_header_sequence_number(&header_handle, sequence_number);
+
+                iox2_publish_subscribe_header_drop(header_handle);
+                iox2_sample_drop(sample_handle);
+            }
+
+            assert_that!(sequence_numbers[1], eq sequence_numbers[0] + 1);
+
+            iox2_publisher_drop(publisher_handle);
+            iox2_subscriber_drop(subscriber_handle);
+            iox2_port_factory_pub_sub_drop(pub_sub_factory);
+            iox2_node_drop(node_handle);
+        }
+    }
+
+    #[test]
+    fn send_copy_if_subscribed_skips_send_without_subscriber<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let node_handle = create_node::<S>("bar");
+            let pub_sub_factory =
+                create_pub_sub_service(&node_handle, "publisher/send/copy/if/subscribed");
+            let (publisher_handle, subscriber_handle) = create_ports(&pub_sub_factory);
+            let payload: u64 = 4711;
+
+            let mut recipients: c_size_t = 1;
+            iox2_subscriber_drop(subscriber_handle);
+            let ret_val = iox2_publisher_send_copy_if_subscribed(
+                &publisher_handle,
+                (&payload as *const u64).cast(),
+                core::mem::size_of::<u64>(),
+                &mut recipients,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(recipients, eq 0);
+
+            let subscriber_builder_handle = iox2_port_factory_pub_sub_subscriber_builder(
+                &pub_sub_factory,
+                core::ptr::null_mut(),
+            );
+            let mut subscriber_handle: iox2_subscriber_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_subscriber_builder_create(
+                subscriber_builder_handle,
+                core::ptr::null_mut(),
+                &mut subscriber_handle,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let ret_val = iox2_publisher_send_copy_if_subscribed(
+                &publisher_handle,
+                (&payload as *const u64).cast(),
+                core::mem::size_of::<u64>(),
+                &mut recipients,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(recipients, eq 1);
+
+            let mut sample_handle: iox2_sample_h = core::ptr::null_mut();
+            let ret_val = iox2_subscriber_receive(
+                &subscriber_handle,
+                core::ptr::null_mut(),
+                &mut sample_handle,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(sample_handle.is_null(), eq false);
+            iox2_sample_drop(sample_handle);
+
+            iox2_publisher_drop(publisher_handle);
+            iox2_subscriber_drop(subscriber_handle);
+            iox2_port_factory_pub_sub_drop(pub_sub_factory);
+            iox2_node_drop(node_handle);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -77,6 +77,31 @@ impl Node {
         self.0.lock().global.node.service_tag_suffix = value.0
     }
 
+    #[getter]
+    /// The suffix of the node heartbeat.
+    pub fn heartbeat_suffix(&self) -> FileName {
+        FileName(self.0.lock().global.node.heartbeat_suffix)
+    }
+
+    #[setter]
+    /// Set the suffix of the node heartbeat.
+    pub fn set_heartbeat_suffix(&mut self, value: &FileName) {
+        self.0.lock().global.node.heartbeat_suffix = value.0
+    }
+
+    #[getter]
+    /// When true, every `Node` creates a heartbeat that other nodes can use to check its
+    /// liveliness.
+    pub fn enable_heartbeat(&self) -> bool {
+        self.0.lock().global.node.enable_heartbeat
+    }
+
+    #[setter]
+    /// Enable/disable the node heartbeat
+    pub fn set_enable_heartbeat(&mut self, value: bool) {
+        self.0.lock().global.node.enable_heartbeat = value
+    }
+
     #[getter]
     /// When true, the `NodeBuilder` checks for dead nodes and
     /// cleans up all their stale resources whenever a new [`Node`](Node) is
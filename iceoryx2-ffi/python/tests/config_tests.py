@@ -105,6 +105,21 @@ def test_global_node_service_tag_suffix_can_be_set() -> None:
     assert sut.global_cfg.node.service_tag_suffix == value
 
 
+def test_global_node_heartbeat_suffix_can_be_set() -> None:
+    sut = iox2.config.default()
+    value = iox2.FileName.new(".thump")
+    sut.global_cfg.node.heartbeat_suffix = value
+    assert sut.global_cfg.node.heartbeat_suffix == value
+
+
+def test_global_node_enable_heartbeat_can_be_set() -> None:
+    sut = iox2.config.default()
+    sut.global_cfg.node.enable_heartbeat = True
+    assert sut.global_cfg.node.enable_heartbeat
+    sut.global_cfg.node.enable_heartbeat = False
+    assert not sut.global_cfg.node.enable_heartbeat
+
+
 def test_global_node_cleanup_dead_nodes_on_destruction_can_be_set() -> None:
     sut = iox2.config.default()
     sut.global_cfg.node.cleanup_dead_nodes_on_destruction = True
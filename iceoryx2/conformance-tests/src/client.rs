@@ -163,7 +163,7 @@ pub mod client {
             .unwrap();
 
         let _request = sut.loan().unwrap();
-        assert_that!(sut.loan().err(), eq Some(LoanError::OutOfMemory));
+        assert_that!(sut.loan().err(), eq Some(LoanError::SegmentFull));
     }
 
     #[conformance_test]
@@ -184,7 +184,7 @@ pub mod client {
             .unwrap();
 
         let _request = sut.loan().unwrap();
-        assert_that!(sut.loan().err(), eq Some(LoanError::OutOfMemory));
+        assert_that!(sut.loan().err(), eq Some(LoanError::SegmentFull));
     }
 
     #[conformance_test]
@@ -212,7 +212,7 @@ pub mod client {
             for _ in 0..n {
                 requests.push(sut.loan().unwrap());
             }
-            assert_that!(sut.loan().err(), eq Some(LoanError::OutOfMemory));
+            assert_that!(sut.loan().err(), eq Some(LoanError::SegmentFull));
         }
     }
 
@@ -25,10 +25,12 @@ pub mod node {
     use iceoryx2::node::{
         NodeCleanupFailure, NodeCreationFailure, NodeListFailure, NodeState, NodeView,
     };
+    use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
+    use iceoryx2_bb_posix::clock::nanosleep;
     use iceoryx2_bb_posix::ipc_capable::Handle;
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::thread::thread_scope;
@@ -403,4 +405,284 @@ pub mod node {
 
         assert_that!(node.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
+
+    #[conformance_test]
+    pub fn by_default_no_log_prefix_is_set<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(node.log_prefix(), eq "");
+    }
+
+    #[conformance_test]
+    pub fn log_prefix_can_be_set<S: Service>() {
+        const LOG_PREFIX: &str = "[node-with-prefix] ";
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .log_prefix(LOG_PREFIX)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(node.log_prefix(), eq LOG_PREFIX);
+    }
+
+    #[conformance_test]
+    pub fn paused_publishers_of_a_node_do_not_deliver_until_resumed<S: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(1)
+            .create()
+            .unwrap();
+        let other_service = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let other_publisher = other_service.publisher_builder().create().unwrap();
+        let subscriber = other_service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.are_publishers_paused(), eq false);
+        sut.pause_publishers();
+        assert_that!(sut.are_publishers_paused(), eq true);
+        assert_that!(other_node.are_publishers_paused(), eq false);
+
+        assert_that!(publisher.send_copy(1).unwrap(), eq 0);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(other_publisher.send_copy(2).unwrap(), eq 1);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        drop(sample);
+
+        let late_subscriber = service.subscriber_builder().create().unwrap();
+        publisher.update_connections().unwrap();
+        assert_that!(late_subscriber.receive().unwrap(), is_none);
+
+        sut.resume_publishers();
+        assert_that!(sut.are_publishers_paused(), eq false);
+
+        assert_that!(publisher.send_copy(3).unwrap(), eq 2);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+        assert_that!(sample.was_preceded_by_overflow(), eq false);
+        let sample = late_subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+    }
+
+    #[conformance_test]
+    pub fn is_alive_turns_false_when_heartbeat_is_not_refreshed<S: Service>() {
+        const MAX_AGE: Duration = Duration::from_millis(50);
+        let mut config = generate_isolated_config();
+        config.global.node.enable_heartbeat = true;
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let observer = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        sut.heartbeat();
+        assert_that!(observer.is_alive(sut.id(), Duration::from_secs(60)), eq true);
+
+        nanosleep(MAX_AGE * 2).unwrap();
+        assert_that!(observer.is_alive(sut.id(), MAX_AGE), eq false);
+
+        sut.heartbeat();
+        assert_that!(observer.is_alive(sut.id(), MAX_AGE), eq true);
+
+        let sut_id = *sut.id();
+        drop(sut);
+        assert_that!(observer.is_alive(&sut_id, Duration::from_secs(60)), eq false);
+    }
+
+    #[conformance_test]
+    pub fn is_alive_is_false_when_heartbeat_is_disabled<S: Service>() {
+        let mut config = generate_isolated_config();
+        config.global.node.enable_heartbeat = false;
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let observer = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        sut.heartbeat();
+        assert_that!(observer.is_alive(sut.id(), Duration::from_secs(60)), eq false);
+    }
+
+    #[conformance_test]
+    pub fn list_open_services_lists_only_services_opened_by_this_node<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let list_open_services = |node: &Node<S>| {
+            let mut services = BTreeSet::new();
+            node.list_open_services(|service_name, static_config| {
+                assert_that!(static_config.name(), eq service_name);
+                services.insert(service_name.clone());
+                CallbackProgression::Continue
+            });
+            services
+        };
+
+        assert_that!(list_open_services(&sut), len 0);
+
+        let pubsub_service_name = generate_service_name();
+        let pubsub_service = sut
+            .service_builder(&pubsub_service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let pubsub_service_2 = sut
+            .service_builder(&pubsub_service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let event_service_name = generate_service_name();
+        let event_service = sut
+            .service_builder(&event_service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let other_service_name = generate_service_name();
+        let _other_service = other_node
+            .service_builder(&other_service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let services = list_open_services(&sut);
+        assert_that!(services, len 2);
+        assert_that!(services.contains(&pubsub_service_name), eq true);
+        assert_that!(services.contains(&event_service_name), eq true);
+
+        drop(event_service);
+        drop(pubsub_service);
+
+        let services = list_open_services(&sut);
+        assert_that!(services, len 1);
+        assert_that!(services.contains(&pubsub_service_name), eq true);
+
+        drop(pubsub_service_2);
+        assert_that!(list_open_services(&sut), len 0);
+    }
+
+    #[conformance_test]
+    pub fn reinit_after_fork_provides_node_with_new_identity<S: Service>() {
+        let config = generate_isolated_config();
+        let node_name = generate_node_name(0, "the fork in the road");
+        let mut sut = NodeBuilder::new()
+            .config(&config)
+            .name(&node_name)
+            .create::<S>()
+            .unwrap();
+        let parent_details = Details::from_node(&sut);
+
+        assert_that!(sut.reinit_after_fork(), is_ok);
+        let child_details = Details::from_node(&sut);
+
+        assert_that!(child_details.id, ne parent_details.id);
+        assert_that!(child_details.name, eq parent_details.name);
+        assert_that!(child_details.config, eq parent_details.config);
+
+        // without a real fork the parent identity is still alive in this process
+        let mut node_details = VecDeque::new();
+        node_details.push_back(parent_details);
+        node_details.push_back(child_details);
+        assert_node_presence::<S>(&node_details, &config);
+    }
+
+    #[conformance_test]
+    pub fn reinit_after_fork_node_can_communicate<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let mut sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(sut.reinit_after_fork(), is_ok);
+
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let other_service = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = other_service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(8192), eq Ok(1));
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 8192);
+    }
+
+    #[conformance_test]
+    pub fn node_with_shm_name_prefix_prepends_it_to_all_resource_names<S: Service>() {
+        use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+        use iceoryx2_bb_container::semantic_string::SemanticString;
+
+        const TENANT_PREFIX: &str = "tenant42_";
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .shm_name_prefix(TENANT_PREFIX)
+            .create::<S>()
+            .unwrap();
+
+        let mut expected_prefix = TENANT_PREFIX.as_bytes().to_vec();
+        expected_prefix.extend_from_slice(config.global.prefix.as_bytes());
+        assert_that!(sut.config().global.prefix.as_bytes(), eq expected_prefix.as_slice());
+
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let same_tenant = NodeBuilder::new()
+            .config(&config)
+            .shm_name_prefix(TENANT_PREFIX)
+            .create::<S>()
+            .unwrap();
+        let opened_service = same_tenant
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = opened_service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(31), eq Ok(1));
+        assert_that!(subscriber.receive().unwrap().map(|sample| *sample), eq Some(31));
+
+        let other_tenant = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let result = other_tenant
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::DoesNotExist));
+    }
+
+    #[conformance_test]
+    pub fn node_with_invalid_shm_name_prefix_cannot_be_created<S: Service>() {
+        let config = generate_isolated_config();
+
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .shm_name_prefix("tenant/42")
+            .create::<S>();
+
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::InvalidShmNamePrefix));
+    }
 }
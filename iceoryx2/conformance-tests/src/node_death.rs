@@ -891,4 +891,48 @@ pub mod node_death {
 
         assert_that!(number_of_nodes(), eq 0);
     }
+
+    #[conformance_test]
+    pub fn node_without_automatic_cleanup_keeps_dead_node_ports_until_explicit_cleanup<S: Test>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+
+        let mut dead_node = S::create_test_node(&config).node;
+        let dead_service = dead_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let dead_publisher = dead_service.publisher_builder().create().unwrap();
+
+        S::staged_death(&mut dead_node);
+        core::mem::forget(dead_publisher);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node);
+
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .automatic_dead_node_cleanup(false)
+            .create::<S::Service>()
+            .unwrap();
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 1);
+
+        let other_node = NodeBuilder::new()
+            .config(&config)
+            .automatic_dead_node_cleanup(false)
+            .create::<S::Service>()
+            .unwrap();
+        drop(other_node);
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 1);
+
+        assert_that!(sut.cleanup_stale_resources(), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 0);
+    }
 }
@@ -16,17 +16,20 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[conformance_tests]
 pub mod publisher {
     use alloc::collections::BTreeSet;
+    use alloc::sync::Arc;
     use alloc::{format, vec};
     use core::time::Duration;
+    use iceoryx2::port::multi_publisher::{MultiPublisher, MultiSendError};
+    use iceoryx2::port::publisher::{PublisherCreateError, PublisherShutdownError, SendBatchError};
     use iceoryx2::port::update_connections::UpdateConnections;
-    use iceoryx2::port::{LoanError, publisher::PublisherCreateError};
+    use iceoryx2::port::{DataSegmentType, DegradationAction, LoanError, SendError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::testing;
     use iceoryx2::testing::generate_service_name;
-    use iceoryx2_bb_concurrency::atomic::{AtomicBool, Ordering};
+    use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicUsize, Ordering};
     use iceoryx2_bb_posix::barrier::*;
     use iceoryx2_bb_posix::clock::{Time, nanosleep};
     use iceoryx2_bb_posix::mutex::{MutexBuilder, MutexHandle};
@@ -96,7 +99,7 @@ pub mod publisher {
             .create()?;
 
         let _sample = sut.loan()?;
-        assert_that!(sut.loan().err(), eq Some(LoanError::OutOfMemory));
+        assert_that!(sut.loan().err(), eq Some(LoanError::SegmentFull));
 
         Ok(())
     }
@@ -119,7 +122,7 @@ pub mod publisher {
             .create()?;
 
         let _sample = sut.loan()?;
-        assert_that!(sut.loan().err(), eq Some(LoanError::OutOfMemory));
+        assert_that!(sut.loan().err(), eq Some(LoanError::SegmentFull));
 
         Ok(())
     }
@@ -149,12 +152,43 @@ pub mod publisher {
             for _ in 0..n {
                 samples.push(sut.loan()?)
             }
-            assert_that!(sut.loan().err(), eq Some(LoanError::OutOfMemory));
+            assert_that!(sut.loan().err(), eq Some(LoanError::SegmentFull));
         }
 
         Ok(())
     }
 
+    #[conformance_test]
+    pub fn loan_fails_with_segment_full_when_slow_subscriber_holds_all_samples<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .override_sample_preallocation(|_| 3)
+            .max_loaned_samples(2)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()?;
+        let _subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        sut.send_copy(2)?;
+
+        let _sample = sut.loan()?;
+        assert_that!(sut.loan().err(), eq Some(LoanError::SegmentFull));
+
+        Ok(())
+    }
+
     #[conformance_test]
     pub fn drop_is_not_called_for_underlying_type_of_sample<Sut: Service>()
     -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
@@ -177,6 +211,76 @@ pub mod publisher {
         Ok(())
     }
 
+    #[conformance_test]
+    pub fn drop_is_called_for_discarded_sample_when_run_drop_on_discard_is_enabled<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<LifetimeTracker>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .run_drop_on_discard(true)
+            .create()?;
+
+        let tracker = LifetimeTracker::start_tracking();
+        let sut = publisher.loan()?;
+        assert_that!(tracker.number_of_living_instances(), eq 1);
+        drop(sut);
+        assert_that!(tracker.number_of_living_instances(), eq 0);
+
+        let uninit_sut = publisher.loan_uninit()?;
+        drop(uninit_sut);
+        assert_that!(tracker.number_of_living_instances(), eq 0);
+
+        let sut = publisher.loan()?;
+        assert_that!(tracker.number_of_living_instances(), eq 1);
+        assert_that!(sut.send(), is_ok);
+        assert_that!(tracker.number_of_living_instances(), eq 1);
+
+        Ok(())
+    }
+
+    #[conformance_test]
+    pub fn drop_is_called_for_sample_sent_while_paused_when_run_drop_on_discard_is_enabled<
+        Sut: Service,
+    >() -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<LifetimeTracker>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .run_drop_on_discard(true)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let tracker = LifetimeTracker::start_tracking();
+        node.pause_publishers();
+        let sut = publisher.loan()?;
+        assert_that!(tracker.number_of_living_instances(), eq 1);
+        assert_that!(sut.send(), eq Ok(0));
+        assert_that!(tracker.number_of_living_instances(), eq 0);
+        node.resume_publishers();
+
+        let sut = publisher.loan()?;
+        assert_that!(sut.send(), eq Ok(1));
+        assert_that!(tracker.number_of_living_instances(), eq 1);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.header().sequence_number(), eq 0);
+
+        Ok(())
+    }
+
     #[conformance_test]
     pub fn loan_uninit_does_not_initialize_sample<Sut: Service>()
     -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
@@ -296,6 +400,39 @@ pub mod publisher {
         Ok(())
     }
 
+    #[conformance_test]
+    pub fn publisher_loan_slice_filled_initializes_all_elements<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        const NUMBER_OF_ELEMENTS: usize = 31;
+        const FILL_VALUE: u8 = 0xff;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        publisher
+            .loan_slice_filled(NUMBER_OF_ELEMENTS, FILL_VALUE)?
+            .send()?;
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), len NUMBER_OF_ELEMENTS);
+        assert_that!(sample.payload().iter().all(|e| *e == FILL_VALUE), eq true);
+
+        let sut = publisher.loan_slice_filled(NUMBER_OF_ELEMENTS + 1, FILL_VALUE);
+        assert_that!(sut.err(), eq Some(LoanError::ExceedsMaxLoanSize));
+
+        Ok(())
+    }
+
     #[conformance_test]
     pub fn publisher_loan_unit_and_send_sample_works<Sut: Service>()
     -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
@@ -399,6 +536,82 @@ pub mod publisher {
         Ok(())
     }
 
+    #[conformance_test]
+    pub fn preallocate_more_samples_than_max_loaned_samples_fails<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        let pool = sut.preallocate(3);
+        assert_that!(pool, is_err);
+        assert_that!(pool.err().unwrap(), eq LoanError::ExceedsMaxLoans);
+
+        let pool = sut.preallocate(2)?;
+        assert_that!(pool.len(), eq 2);
+
+        Ok(())
+    }
+
+    #[conformance_test]
+    pub fn sample_pool_can_be_reused_across_many_sends<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        const NUMBER_OF_SENDS: u64 = 1000;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(4).create()?;
+        let subscriber = service.subscriber_builder().create()?;
+        let mut pool = sut.preallocate(4)?;
+
+        for n in 0..NUMBER_OF_SENDS {
+            let sample = pool.acquire()?;
+            assert_that!(sample.write_payload(n).send(), eq Ok(1));
+
+            let received = subscriber.receive()?;
+            assert_that!(received, is_some);
+            assert_that!(*received.unwrap(), eq n);
+        }
+
+        Ok(())
+    }
+
+    #[conformance_test]
+    pub fn sample_pool_acquire_fails_when_no_sample_can_be_loaned<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+        let mut pool = sut.preallocate(2)?;
+
+        let _sample1 = pool.acquire()?;
+        let _sample2 = pool.acquire()?;
+        assert_that!(pool.is_empty(), eq true);
+
+        let sample3 = pool.acquire();
+        assert_that!(sample3, is_err);
+        assert_that!(sample3.err().unwrap(), eq LoanError::ExceedsMaxLoans);
+
+        Ok(())
+    }
+
     #[conformance_test]
     pub fn publisher_dropping_sample_reduces_loan_counter<Sut: Service>()
     -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
@@ -598,112 +811,634 @@ pub mod publisher {
     }
 
     #[conformance_test]
-    pub fn unable_to_deliver_strategy_block_unblock_when_subscriber_disconnects<Sut: Service>()
-    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+    pub fn unable_to_deliver_strategy_can_be_changed_to_discard_sample_after_creation<
+        Sut: Service,
+    >() {
         let _watchdog = Watchdog::new();
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
-        let handle = MutexHandle::new();
-        let node = MutexBuilder::new()
-            .create(
-                NodeBuilder::new().config(&config).create::<Sut>().unwrap(),
-                &handle,
-            )
-            .unwrap();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let service = node
-            .lock()
-            .unwrap()
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
             .subscriber_max_buffer_size(1)
             .enable_safe_overflow(false)
-            .create()?;
-        let subscriber = service.subscriber_builder().create().unwrap();
-
-        let init_handle = BarrierHandle::new();
-        let init_barrier = BarrierBuilder::new(2).create(&init_handle).unwrap();
-        let start_handle = BarrierHandle::new();
-        let start_barrier = BarrierBuilder::new(2).create(&start_handle).unwrap();
-        let has_finished_send_thread = AtomicBool::new(false);
-
-        thread_scope(|s| {
-            s.thread_builder().spawn(|| {
-                let service = node
-                    .lock()
-                    .unwrap()
-                    .service_builder(&service_name)
-                    .publish_subscribe::<u64>()
-                    .open()
-                    .unwrap();
-
-                let sut = service
-                    .publisher_builder()
-                    .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
-                    .create()
-                    .unwrap();
-
-                init_barrier.wait();
-
-                start_barrier.wait();
-
-                sut.send_copy(123).unwrap();
-                sut.send_copy(456).unwrap();
-                has_finished_send_thread.store(true, Ordering::Relaxed);
-            })?;
-
-            init_barrier.wait();
-            subscriber.update_connections().unwrap();
+            .create()
+            .unwrap();
 
-            start_barrier.wait();
-            nanosleep(TIMEOUT * 10).unwrap();
-            assert_that!(has_finished_send_thread.load(Ordering::Relaxed), eq false);
-            drop(subscriber);
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
 
-            Ok(())
-        })
-        .unwrap();
+        sut.set_unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample);
+        assert_that!(sut.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::DiscardSample);
 
-        Ok(())
-    }
+        assert_that!(sut.send_copy(8192).unwrap(), eq 1);
+        assert_that!(sut.send_copy(2).unwrap(), eq 0);
 
-    #[conformance_test]
-    pub fn create_error_display_works<S: Service>() {
-        assert_that!(
-            format!("{}", PublisherCreateError::ExceedsMaxSupportedPublishers), eq "PublisherCreateError::ExceedsMaxSupportedPublishers");
-        assert_that!(
-            format!("{}", PublisherCreateError::UnableToCreateDataSegment), eq "PublisherCreateError::UnableToCreateDataSegment");
-    }
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 8192);
+        drop(sample);
+        assert_that!(subscriber.receive().unwrap(), is_none);
 
-    #[conformance_test]
-    pub fn loan_error_display_works<S: Service>() {
-        assert_that!(
-            format!("{}", LoanError::OutOfMemory), eq "LoanError::OutOfMemory");
-        assert_that!(
-            format!("{}", LoanError::ExceedsMaxLoans), eq "LoanError::ExceedsMaxLoans");
-        assert_that!(
-            format!("{}", LoanError::ExceedsMaxLoanSize), eq "LoanError::ExceedsMaxLoanSize");
-        assert_that!(
-            format!("{}", LoanError::InternalFailure), eq "LoanError::InternalFailure");
+        sut.set_unable_to_deliver_strategy(UnableToDeliverStrategy::Block);
+        assert_that!(sut.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::Block);
     }
 
     #[conformance_test]
-    pub fn id_is_unique<Sut: Service>() {
+    pub fn connection_stats_attribute_dropped_samples_to_the_full_subscriber<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const MAX_PUBLISHERS: usize = 8;
-
-        let sut = node
+        let service = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(MAX_PUBLISHERS)
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(false)
             .create()
             .unwrap();
 
-        let mut publishers = vec![];
-        let mut publisher_id_set = BTreeSet::new();
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let full_subscriber = service
+            .subscriber_builder()
+            .buffer_size(1)
+            .create()
+            .unwrap();
+        let draining_subscriber = service.subscriber_builder().create().unwrap();
 
-        for _ in 0..MAX_PUBLISHERS {
+        for n in 0..3 {
+            assert_that!(sut.send_copy(n), is_ok);
+            assert_that!(draining_subscriber.receive().unwrap(), is_some);
+        }
+
+        let stats: vec::Vec<_> = sut.connection_stats().collect();
+        assert_that!(stats, len 2);
+
+        for stat in stats {
+            if stat.subscriber_id == full_subscriber.id() {
+                assert_that!(stat.delivered, eq 1);
+                assert_that!(stat.dropped, eq 2);
+            } else {
+                assert_that!(stat.subscriber_id, eq draining_subscriber.id());
+                assert_that!(stat.delivered, eq 3);
+                assert_that!(stat.dropped, eq 0);
+            }
+        }
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_coalesce_duplicates_skips_consecutive_equal_values<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .coalesce_duplicates(true)
+            .create()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .buffer_size(4)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.send_copy(42), eq Ok(1));
+        assert_that!(sut.send_copy(42), eq Ok(0));
+        assert_that!(sut.send_copy(73), eq Ok(1));
+        assert_that!(sut.send_copy(42), eq Ok(1));
+
+        for expected in [42, 73, 42] {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq expected);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_coalesce_duplicates_does_not_record_undelivered_values<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .coalesce_duplicates(true)
+            .create()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .buffer_size(4)
+            .create()
+            .unwrap();
+
+        node.pause_publishers();
+        assert_that!(sut.send_copy(42), eq Ok(0));
+        node.resume_publishers();
+
+        assert_that!(sut.send_copy(42), eq Ok(1));
+        assert_that!(sut.send_copy(42), eq Ok(0));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 42);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_adaptive_drop_skips_sends_above_threshold<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .adaptive_drop(50)
+            .create()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .buffer_size(4)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.send_copy(1), eq Ok(1));
+        assert_that!(sut.send_copy(2), eq Ok(1));
+        assert_that!(sut.send_copy(3), eq Ok(1));
+        assert_that!(sut.send_copy(4), eq Ok(0));
+        assert_that!(sut.send_copy(5), eq Ok(0));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        assert_that!(sut.send_copy(6), eq Ok(1));
+
+        for expected in [2, 3, 6] {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq expected);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn send_copy_tracked_returns_id_of_received_sample<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let (number_of_recipients, first_id) = sut.send_copy_tracked(1).unwrap();
+        assert_that!(number_of_recipients, eq 1);
+        let (_, second_id) = sut.send_copy_tracked(2).unwrap();
+
+        assert_that!(first_id.publisher_id(), eq sut.id());
+        assert_that!(first_id, ne second_id);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.id(), eq first_id);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.id(), eq second_id);
+    }
+
+    #[conformance_test]
+    pub fn send_batch_delivers_all_samples_in_order<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 5;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .buffer_size(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let mut samples = vec![];
+        for n in 0..NUMBER_OF_SAMPLES {
+            samples.push(sut.loan_uninit().unwrap().write_payload(n as u64));
+        }
+
+        assert_that!(sut.send_batch(samples), eq Ok(NUMBER_OF_SAMPLES));
+        assert_that!(sut.loaned_samples(), eq 0);
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n as u64);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn send_batch_fails_for_sample_from_other_publisher<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(2)
+            .create()
+            .unwrap();
+        let other_publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let samples = vec![
+            sut.loan_uninit().unwrap().write_payload(1),
+            other_publisher.loan_uninit().unwrap().write_payload(2),
+            sut.loan_uninit().unwrap().write_payload(3),
+        ];
+
+        assert_that!(sut.send_batch(samples), eq Err(SendBatchError::PartialDelivery {
+            index: 1,
+            number_of_deliveries: 1,
+            error: SendError::SampleFromOtherPublisher
+        }));
+        assert_that!(sut.loaned_samples(), eq 0);
+        assert_that!(other_publisher.loaned_samples(), eq 0);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn send_batch_reports_delivered_samples_on_partial_failure<Sut: Service>() {
+        const MAX_SEND_RATE: u64 = 2;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(3)
+            .max_send_rate(MAX_SEND_RATE)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .buffer_size(5)
+            .create()
+            .unwrap();
+
+        let mut samples = vec![];
+        for n in 0..3 {
+            samples.push(sut.loan_uninit().unwrap().write_payload(n));
+        }
+
+        assert_that!(sut.send_batch(samples), eq Err(SendBatchError::PartialDelivery {
+            index: 2,
+            number_of_deliveries: 2,
+            error: SendError::RateLimited
+        }));
+        assert_that!(sut.loaned_samples(), eq 0);
+
+        for n in 0..MAX_SEND_RATE {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn blocking_subscribers_reports_subscribers_with_full_buffer<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(false)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let full_subscriber = service
+            .subscriber_builder()
+            .buffer_size(1)
+            .create()
+            .unwrap();
+        let draining_subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.blocking_subscribers(), len 0);
+
+        assert_that!(sut.send_copy(123), is_ok);
+        assert_that!(draining_subscriber.receive().unwrap(), is_some);
+
+        let blocking_subscribers = sut.blocking_subscribers();
+        assert_that!(blocking_subscribers, len 1);
+        assert_that!(blocking_subscribers[0], eq full_subscriber.id());
+
+        assert_that!(full_subscriber.receive().unwrap(), is_some);
+        assert_that!(sut.blocking_subscribers(), len 0);
+    }
+
+    #[conformance_test]
+    pub fn unable_to_deliver_strategy_can_be_changed_to_block_after_creation<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let handle = MutexHandle::new();
+        let node = MutexBuilder::new()
+            .create(
+                NodeBuilder::new().config(&config).create::<Sut>().unwrap(),
+                &handle,
+            )
+            .unwrap();
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()?;
+        sut.set_unable_to_deliver_strategy(UnableToDeliverStrategy::Block);
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        thread_scope(|s| {
+            s.thread_builder().spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .subscriber_max_buffer_size(1)
+                    .open()
+                    .unwrap();
+
+                let subscriber = service.subscriber_builder().create().unwrap();
+                let receive_sample = || loop {
+                    if let Some(sample) = subscriber.receive().unwrap() {
+                        return sample;
+                    }
+                };
+
+                barrier.wait();
+                nanosleep(TIMEOUT).unwrap();
+                let sample_1 = receive_sample();
+                nanosleep(TIMEOUT).unwrap();
+                let sample_2 = receive_sample();
+
+                assert_that!(*sample_1, eq 8192);
+                assert_that!(*sample_2, eq 2);
+            })?;
+
+            barrier.wait();
+            let now = Time::now().unwrap();
+            sut.send_copy(8192).unwrap();
+            sut.send_copy(2).unwrap();
+            assert_that!(now.elapsed().unwrap(), time_at_least TIMEOUT);
+
+            Ok(())
+        })
+        .unwrap();
+
+        Ok(())
+    }
+
+    #[conformance_test]
+    pub fn send_beyond_max_send_rate_is_rate_limited<Sut: Service>() {
+        const MAX_SEND_RATE: u64 = 2;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .max_send_rate(MAX_SEND_RATE)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        for n in 0..MAX_SEND_RATE {
+            assert_that!(sut.send_copy(n), eq Ok(1));
+        }
+
+        for n in 0..3 {
+            assert_that!(sut.send_copy(n), eq Err(SendError::RateLimited));
+        }
+
+        for n in 0..MAX_SEND_RATE {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn send_beyond_max_send_rate_blocks_with_block_strategy<Sut: Service>() {
+        const MAX_SEND_RATE: u64 = 50;
+        let _watchdog = Watchdog::new();
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(MAX_SEND_RATE as usize + 1)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .max_send_rate(MAX_SEND_RATE)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let _subscriber = service.subscriber_builder().create().unwrap();
+
+        let start = Time::now().unwrap();
+        for n in 0..MAX_SEND_RATE + 1 {
+            assert_that!(sut.send_copy(n), eq Ok(1));
+        }
+
+        // the burst is exhausted, the last send has to wait for a refilled token
+        assert_that!(start.elapsed().unwrap(), ge Duration::from_millis(1000 / MAX_SEND_RATE));
+    }
+
+    #[conformance_test]
+    pub fn unable_to_deliver_strategy_block_unblock_when_subscriber_disconnects<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let handle = MutexHandle::new();
+        let node = MutexBuilder::new()
+            .create(
+                NodeBuilder::new().config(&config).create::<Sut>().unwrap(),
+                &handle,
+            )
+            .unwrap();
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let init_handle = BarrierHandle::new();
+        let init_barrier = BarrierBuilder::new(2).create(&init_handle).unwrap();
+        let start_handle = BarrierHandle::new();
+        let start_barrier = BarrierBuilder::new(2).create(&start_handle).unwrap();
+        let has_finished_send_thread = AtomicBool::new(false);
+
+        thread_scope(|s| {
+            s.thread_builder().spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+
+                let sut = service
+                    .publisher_builder()
+                    .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+                    .create()
+                    .unwrap();
+
+                init_barrier.wait();
+
+                start_barrier.wait();
+
+                sut.send_copy(123).unwrap();
+                sut.send_copy(456).unwrap();
+                has_finished_send_thread.store(true, Ordering::Relaxed);
+            })?;
+
+            init_barrier.wait();
+            subscriber.update_connections().unwrap();
+
+            start_barrier.wait();
+            nanosleep(TIMEOUT * 10).unwrap();
+            assert_that!(has_finished_send_thread.load(Ordering::Relaxed), eq false);
+            drop(subscriber);
+
+            Ok(())
+        })
+        .unwrap();
+
+        Ok(())
+    }
+
+    #[conformance_test]
+    pub fn create_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", PublisherCreateError::ExceedsMaxSupportedPublishers), eq "PublisherCreateError::ExceedsMaxSupportedPublishers");
+        assert_that!(
+            format!("{}", PublisherCreateError::UnableToCreateDataSegment), eq "PublisherCreateError::UnableToCreateDataSegment");
+    }
+
+    #[conformance_test]
+    pub fn loan_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", LoanError::OutOfMemory), eq "LoanError::OutOfMemory");
+        assert_that!(
+            format!("{}", LoanError::SegmentFull), eq "LoanError::SegmentFull");
+        assert_that!(
+            format!("{}", LoanError::ExceedsMaxLoans), eq "LoanError::ExceedsMaxLoans");
+        assert_that!(
+            format!("{}", LoanError::ExceedsMaxLoanSize), eq "LoanError::ExceedsMaxLoanSize");
+        assert_that!(
+            format!("{}", LoanError::InternalFailure), eq "LoanError::InternalFailure");
+    }
+
+    #[conformance_test]
+    pub fn id_is_unique<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_PUBLISHERS: usize = 8;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUBLISHERS)
+            .create()
+            .unwrap();
+
+        let mut publishers = vec![];
+        let mut publisher_id_set = BTreeSet::new();
+
+        for _ in 0..MAX_PUBLISHERS {
             let publisher = sut.publisher_builder().create().unwrap();
             assert_that!(publisher_id_set.insert(publisher.id()), eq true);
             publishers.push(publisher);
@@ -711,123 +1446,824 @@ pub mod publisher {
     }
 
     #[conformance_test]
-    pub fn publisher_with_custom_payload_details_adjusts_slice_len<Sut: Service>()
-    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
-        const TYPE_SIZE_OVERRIDE: usize = 128;
+    pub fn publisher_with_custom_payload_details_adjusts_slice_len<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        const TYPE_SIZE_OVERRIDE: usize = 128;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut type_detail = TypeDetail::new::<u8>(TypeVariant::FixedSize);
+        testing::type_detail_set_size(&mut type_detail, TYPE_SIZE_OVERRIDE);
+
+        let service = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_payload_type_details(&type_detail)
+                .create()?
+        };
+
+        let sut = service.publisher_builder().create()?;
+
+        let sample = unsafe { sut.loan_custom_payload(1)? };
+
+        assert_that!(sample.payload(), len TYPE_SIZE_OVERRIDE);
+
+        Ok(())
+    }
+
+    #[conformance_test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    pub fn publisher_with_custom_payload_details_panics_when_calling_loan_slice_uninit<
+        Sut: Service,
+    >() {
+        const TYPE_SIZE_OVERRIDE: usize = 128;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut type_detail = TypeDetail::new::<u8>(TypeVariant::FixedSize);
+        testing::type_detail_set_size(&mut type_detail, TYPE_SIZE_OVERRIDE);
+
+        let service = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_detail)
+                .create()
+                .unwrap()
+        };
+
+        let sut = service.publisher_builder().create().unwrap();
+
+        // panics here
+        let _sample = sut.loan_slice_uninit(1);
+    }
+
+    #[conformance_test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    pub fn custom_fixed_size_payload_panics_when_loaning_more_than_one_element<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let type_details = TypeDetail::new::<u8>(TypeVariant::FixedSize);
+
+        let service = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_payload_type_details(&type_details)
+                .create()
+                .unwrap()
+        };
+
+        let sut = service.publisher_builder().create().unwrap();
+
+        let _sample = unsafe { sut.loan_custom_payload(2) };
+    }
+
+    #[conformance_test]
+    pub fn validate_initialization_accepts_fully_written_samples<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .validate_initialization(true)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sample = sut.loan_slice_uninit(4).unwrap();
+        let sample = sample.write_from_fn(|n| n as u64);
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = sut.loan_slice(4).unwrap();
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [0, 1, 2, 3]);
+    }
+
+    #[conformance_test]
+    #[cfg(debug_assertions)]
+    pub fn validate_initialization_fails_when_partially_written_sample_is_sent<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .validate_initialization(true)
+            .create()
+            .unwrap();
+
+        let mut sample = sut.loan_slice_uninit(4).unwrap();
+        sample.write_element(0, 1);
+        sample.write_element(1, 2);
+        let sample = unsafe { sample.assume_init() };
+
+        assert_that!(sample.send(), eq Err(SendError::UninitializedPayload));
+        assert_that!(sut.loan_slice(4), is_ok);
+    }
+
+    #[conformance_test]
+    #[cfg(debug_assertions)]
+    pub fn validate_initialization_does_not_track_writes_via_payload_mut<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .validate_initialization(true)
+            .create()
+            .unwrap();
+
+        let mut sample = sut.loan_slice_uninit(4).unwrap();
+        sample.payload_mut()[0].write(1);
+        sample.payload_mut()[1].write(2);
+        let mut sample = unsafe { sample.assume_init() };
+        sample.payload_mut()[2] = 3;
+
+        assert_that!(sample.send(), eq Err(SendError::UninitializedPayload));
+
+        let sample = sut.loan_slice_uninit(4).unwrap();
+        let sample = sample.write_from_slice(&[1, 2, 3, 4]);
+        assert_that!(sample.send(), eq Ok(0));
+    }
+
+    #[conformance_test]
+    pub fn reclaims_all_samples_when_subscriber_is_disconnected_and_never_received_them<
+        Sut: Service,
+    >() -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        const MAX_SUBSCRIBERS: usize = 4;
+        const ITERATIONS: usize = 20;
+        const SUBSCRIBER_BUFFER_SIZE: usize = 7;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .subscriber_max_buffer_size(SUBSCRIBER_BUFFER_SIZE)
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        for n in 0..MAX_SUBSCRIBERS {
+            for _ in 0..ITERATIONS {
+                let mut subscribers = vec![];
+
+                for _ in 0..n {
+                    subscribers.push(service.subscriber_builder().create()?);
+                }
+
+                for _ in 0..SUBSCRIBER_BUFFER_SIZE {
+                    sut.send_copy(1293)?;
+                }
+
+                // disconnect all subscribers
+                drop(subscribers);
+            }
+        }
+
+        let sample = sut.loan()?;
+
+        assert_that!(sample.send(), is_ok);
+
+        Ok(())
+    }
+
+    #[conformance_test]
+    pub fn on_sample_reclaimed_reports_samples_of_disconnected_subscriber<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 3;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let reclaimed_samples = Arc::new(AtomicUsize::new(0));
+        let reclaimed_samples_in_callback = reclaimed_samples.clone();
+        let sut = service
+            .publisher_builder()
+            .on_sample_reclaimed(move |n| {
+                reclaimed_samples_in_callback.fetch_add(n, Ordering::Relaxed);
+            })
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(sut.send_copy(n as u64), eq Ok(1));
+        }
+        assert_that!(reclaimed_samples.load(Ordering::Relaxed), eq 0);
+
+        drop(subscriber);
+        assert_that!(sut.update_connections(), is_ok);
+
+        assert_that!(reclaimed_samples.load(Ordering::Relaxed), eq NUMBER_OF_SAMPLES);
+        assert_that!(sut.number_of_samples_in_use(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn shutdown_succeeds_when_all_samples_are_released<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(1)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(4)?;
+        assert_that!(sut.number_of_samples_in_use(), eq 1);
+
+        let sample = subscriber.receive()?;
+        assert_that!(sample, is_some);
+        drop(sample);
+
+        assert_that!(sut.number_of_samples_in_use(), eq 0);
+        assert_that!(sut.shutdown(TIMEOUT), is_ok);
+
+        Ok(())
+    }
+
+    #[conformance_test]
+    pub fn shutdown_times_out_when_samples_are_still_in_use<Sut: Service>()
+    -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(4)?;
+        sut.send_copy(5)?;
+        let sample = subscriber.receive()?;
+
+        let start = Time::now().unwrap();
+        assert_that!(sut.shutdown(TIMEOUT).err(), eq Some(PublisherShutdownError::Timeout));
+        assert_that!(start.elapsed().unwrap(), ge TIMEOUT);
+        assert_that!(sut.number_of_samples_in_use(), eq 2);
+
+        drop(sample);
+        drop(subscriber);
+        assert_that!(sut.shutdown(TIMEOUT), is_ok);
+
+        Ok(())
+    }
+
+    #[conformance_test]
+    pub fn corrupted_connection_is_reestablished_with_reconnect_degradation_action<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(1)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .set_degradation_callback(Some(|_, _, _| DegradationAction::Reconnect))
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.send_copy(1), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        drop(sample);
+
+        let result = unsafe {
+            iceoryx2::port::testing::__internal_publisher_corrupt_connection(&sut, subscriber.id())
+        };
+        assert_that!(result, is_ok);
+
+        // the corrupted connection is kept until the subscriber has released it
+        assert_that!(sut.send_copy(2), eq Ok(0));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        // the connection is re-established and the history is replayed
+        assert_that!(sut.update_connections(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(sut.send_copy(3), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+    }
+
+    #[conformance_test]
+    pub fn reconnect_degradation_action_reclaims_samples_when_subscriber_released_connection<
+        Sut: Service,
+    >() {
+        const NUMBER_OF_SAMPLES: usize = 3;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let reclaimed_samples = Arc::new(AtomicUsize::new(0));
+        let reclaimed_samples_in_callback = reclaimed_samples.clone();
+        let sut = service
+            .publisher_builder()
+            .set_degradation_callback(Some(|_, _, _| DegradationAction::Reconnect))
+            .on_sample_reclaimed(move |n| {
+                reclaimed_samples_in_callback.fetch_add(n, Ordering::Relaxed);
+            })
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(sut.send_copy(n as u64), eq Ok(1));
+        }
+        assert_that!(sut.number_of_samples_in_use(), eq NUMBER_OF_SAMPLES);
+
+        let result = unsafe {
+            iceoryx2::port::testing::__internal_publisher_corrupt_connection(&sut, subscriber.id())
+        };
+        assert_that!(result, is_ok);
+
+        // the subscriber may still read the delivered samples
+        assert_that!(reclaimed_samples.load(Ordering::Relaxed), eq 0);
+        assert_that!(sut.number_of_samples_in_use(), eq NUMBER_OF_SAMPLES);
+
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(sut.update_connections(), is_ok);
+
+        assert_that!(reclaimed_samples.load(Ordering::Relaxed), eq NUMBER_OF_SAMPLES);
+        assert_that!(sut.number_of_samples_in_use(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn reconnect_degradation_action_keeps_samples_held_by_subscriber<Sut: Service>() {
+        const PAYLOAD: u64 = 8129347812;
+        const NUMBER_OF_SENDS: u64 = 128;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .set_degradation_callback(Some(|_, _, _| DegradationAction::Reconnect))
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.send_copy(PAYLOAD), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+
+        let result = unsafe {
+            iceoryx2::port::testing::__internal_publisher_corrupt_connection(&sut, subscriber.id())
+        };
+        assert_that!(result, is_ok);
+
+        for n in 0..NUMBER_OF_SENDS {
+            assert_that!(sut.send_copy(n), eq Ok(0));
+        }
+        assert_that!(*sample, eq PAYLOAD);
+        assert_that!(sut.number_of_samples_in_use(), eq 1);
+
+        // the connection is not released while the sample is held
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(sut.update_connections(), is_ok);
+        for n in 0..NUMBER_OF_SENDS {
+            assert_that!(sut.send_copy(n), eq Ok(0));
+        }
+        assert_that!(*sample, eq PAYLOAD);
+        assert_that!(sut.number_of_samples_in_use(), eq 1);
+
+        drop(sample);
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(sut.update_connections(), is_ok);
+        assert_that!(sut.number_of_samples_in_use(), eq 0);
+
+        assert_that!(sut.send_copy(PAYLOAD + 1), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq PAYLOAD + 1);
+    }
+
+    #[conformance_test]
+    pub fn corrupted_connection_fails_send_with_fail_degradation_action<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut type_detail = TypeDetail::new::<u8>(TypeVariant::FixedSize);
-        testing::type_detail_set_size(&mut type_detail, TYPE_SIZE_OVERRIDE);
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-        let service = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .user_header::<CustomHeaderMarker>()
-                .__internal_set_payload_type_details(&type_detail)
-                .create()?
+        let sut = service
+            .publisher_builder()
+            .set_degradation_callback(Some(|_, _, _| DegradationAction::Fail))
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.send_copy(1), eq Ok(1));
+
+        let result = unsafe {
+            iceoryx2::port::testing::__internal_publisher_corrupt_connection(&sut, subscriber.id())
         };
+        assert_that!(result, eq Err(SendError::ConnectionCorrupted));
+    }
 
-        let sut = service.publisher_builder().create()?;
+    #[conformance_test]
+    pub fn loaned_samples_tracks_loans_sends_and_drops<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-        let sample = unsafe { sut.loan_custom_payload(1)? };
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(3)
+            .create()
+            .unwrap();
+        assert_that!(sut.loaned_samples(), eq 0);
 
-        assert_that!(sample.payload(), len TYPE_SIZE_OVERRIDE);
+        let sample_1 = sut.loan().unwrap();
+        assert_that!(sut.loaned_samples(), eq 1);
 
-        Ok(())
+        let sample_2 = sut.loan_uninit().unwrap();
+        assert_that!(sut.loaned_samples(), eq 2);
+
+        let sample_3 = sut.loan().unwrap();
+        assert_that!(sut.loaned_samples(), eq 3);
+        assert_that!(sut.loan().err(), eq Some(LoanError::ExceedsMaxLoans));
+        assert_that!(sut.loaned_samples(), eq 3);
+
+        assert_that!(sample_1.send(), is_ok);
+        assert_that!(sut.loaned_samples(), eq 2);
+
+        drop(sample_2);
+        assert_that!(sut.loaned_samples(), eq 1);
+
+        let sample_4 = sut.loan().unwrap();
+        assert_that!(sut.loaned_samples(), eq 2);
+
+        drop(sample_3);
+        assert_that!(sample_4.send(), is_ok);
+        assert_that!(sut.loaned_samples(), eq 0);
     }
 
     #[conformance_test]
-    #[should_panic]
-    #[cfg(debug_assertions)]
-    pub fn publisher_with_custom_payload_details_panics_when_calling_loan_slice_uninit<
-        Sut: Service,
-    >() {
-        const TYPE_SIZE_OVERRIDE: usize = 128;
+    pub fn send_copy_blocking_times_out_when_subscriber_buffer_stays_full<Sut: Service>() {
+        let _watchdog = Watchdog::new();
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut type_detail = TypeDetail::new::<u8>(TypeVariant::FixedSize);
-        testing::type_detail_set_size(&mut type_detail, TYPE_SIZE_OVERRIDE);
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()
+            .unwrap();
 
-        let service = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&type_detail)
-                .create()
-                .unwrap()
-        };
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
 
-        let sut = service.publisher_builder().create().unwrap();
+        assert_that!(sut.send_copy(8192), eq Ok(1));
 
-        // panics here
-        let _sample = sut.loan_slice_uninit(1);
+        let now = Time::now().unwrap();
+        assert_that!(sut.send_copy_blocking(2, TIMEOUT), eq Err(SendError::Timeout));
+        assert_that!(now.elapsed().unwrap(), time_at_least TIMEOUT);
+        assert_that!(sut.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::DiscardSample);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 8192);
+        drop(sample);
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
     #[conformance_test]
-    #[should_panic]
-    #[cfg(debug_assertions)]
-    pub fn custom_fixed_size_payload_panics_when_loaning_more_than_one_element<Sut: Service>() {
-        set_log_level(LogLevel::Error);
+    pub fn send_copy_blocking_succeeds_when_subscriber_drains_buffer<Sut: Service>() {
+        let _watchdog = Watchdog::new();
         let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let handle = MutexHandle::new();
+        let node = MutexBuilder::new()
+            .create(
+                NodeBuilder::new().config(&config).create::<Sut>().unwrap(),
+                &handle,
+            )
+            .unwrap();
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        thread_scope(|s| {
+            s.thread_builder().spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .subscriber_max_buffer_size(1)
+                    .open()
+                    .unwrap();
+
+                let subscriber = service.subscriber_builder().create().unwrap();
+                let receive_sample = || loop {
+                    if let Some(sample) = subscriber.receive().unwrap() {
+                        return sample;
+                    }
+                };
+
+                barrier.wait();
+                nanosleep(TIMEOUT).unwrap();
+                let sample_1 = receive_sample();
+                let sample_2 = receive_sample();
+
+                assert_that!(*sample_1, eq 8192);
+                assert_that!(*sample_2, eq 2);
+            })?;
+
+            barrier.wait();
+            let now = Time::now().unwrap();
+            assert_that!(sut.send_copy(8192), eq Ok(1));
+            assert_that!(sut.send_copy_blocking(2, TIMEOUT * 1000), eq Ok(1));
+            assert_that!(now.elapsed().unwrap(), time_at_least TIMEOUT);
+
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[conformance_test]
+    pub fn multi_publisher_delivers_sample_to_all_services<Sut: Service>() {
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let type_details = TypeDetail::new::<u8>(TypeVariant::FixedSize);
+        let service_1 = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let service_2 = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-        let service = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .user_header::<CustomHeaderMarker>()
-                .__internal_set_payload_type_details(&type_details)
+        let subscriber_1 = service_1.subscriber_builder().create().unwrap();
+        let subscriber_2 = service_2.subscriber_builder().create().unwrap();
+        let subscriber_3 = service_2.subscriber_builder().create().unwrap();
+
+        let sut = MultiPublisher::new(vec![
+            service_1.publisher_builder().create().unwrap(),
+            service_2.publisher_builder().create().unwrap(),
+        ]);
+        assert_that!(sut.publishers(), len 2);
+
+        let report = sut.send_copy(271828).unwrap();
+        assert_that!(report, eq vec![1, 2]);
+
+        for subscriber in [&subscriber_1, &subscriber_2, &subscriber_3] {
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq 271828);
+        }
+    }
+
+    #[conformance_test]
+    pub fn multi_publisher_sends_nothing_when_one_publisher_cannot_loan<Sut: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_1 = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let service_2 = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber_1 = service_1.subscriber_builder().create().unwrap();
+        let subscriber_2 = service_2.subscriber_builder().create().unwrap();
+
+        let sut = MultiPublisher::new(vec![
+            service_1.publisher_builder().create().unwrap(),
+            service_2
+                .publisher_builder()
+                .max_loaned_samples(1)
                 .create()
-                .unwrap()
-        };
+                .unwrap(),
+        ]);
+
+        let _sample = sut.publishers()[1].loan_uninit().unwrap();
+        let result = sut.send_copy(314159);
+        assert_that!(result, eq Err(MultiSendError::LoanError {
+            index: 1,
+            error: LoanError::ExceedsMaxLoans
+        }));
+
+        assert_that!(subscriber_1.receive().unwrap(), is_none);
+        assert_that!(subscriber_2.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn segment_info_of_static_publisher_has_one_segment<Sut: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
         let sut = service.publisher_builder().create().unwrap();
+        let segment_info = sut.segment_info();
 
-        let _sample = unsafe { sut.loan_custom_payload(2) };
+        assert_that!(segment_info.segment_type, eq DataSegmentType::Static);
+        assert_that!(segment_info.active_segments, eq 1);
+        assert_that!(segment_info.max_segments, eq 1);
     }
 
     #[conformance_test]
-    pub fn reclaims_all_samples_when_subscriber_is_disconnected_and_never_received_them<
-        Sut: Service,
-    >() -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
-        const MAX_SUBSCRIBERS: usize = 4;
-        const ITERATIONS: usize = 20;
-        const SUBSCRIBER_BUFFER_SIZE: usize = 7;
+    pub fn segment_info_of_dynamic_publisher_tracks_active_segments<Sut: Service>() {
+        const SLICE_LENGTHS: [usize; 3] = [1, 1024, 65536];
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LENGTHS[0])
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .max_loaned_samples(SLICE_LENGTHS.len())
+            .create()
+            .unwrap();
+
+        let segment_info = sut.segment_info();
+        assert_that!(segment_info.segment_type, eq DataSegmentType::Dynamic);
+        assert_that!(segment_info.active_segments, eq 1);
+        assert_that!(segment_info.max_segments, ge SLICE_LENGTHS.len());
+
+        // the loans are held so that the older segments stay in use
+        let mut samples = vec![];
+        for (n, slice_len) in SLICE_LENGTHS.iter().enumerate() {
+            samples.push(sut.loan_slice(*slice_len).unwrap());
+            assert_that!(sut.segment_info().active_segments, eq n + 1);
+        }
+    }
+
+    #[conformance_test]
+    pub fn try_loan_returns_none_when_max_loaned_samples_are_exceeded<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let service = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_subscribers(MAX_SUBSCRIBERS)
-            .subscriber_max_buffer_size(SUBSCRIBER_BUFFER_SIZE)
-            .create()?;
+            .create()
+            .unwrap();
 
-        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(2)
+            .create()
+            .unwrap();
 
-        for n in 0..MAX_SUBSCRIBERS {
-            for _ in 0..ITERATIONS {
-                let mut subscribers = vec![];
+        let sample1 = sut.try_loan().unwrap();
+        let sample2 = sut.try_loan().unwrap();
+        assert_that!(sample1, is_some);
+        assert_that!(sample2, is_some);
 
-                for _ in 0..n {
-                    subscribers.push(service.subscriber_builder().create()?);
-                }
+        assert_that!(sut.try_loan().unwrap(), is_none);
 
-                for _ in 0..SUBSCRIBER_BUFFER_SIZE {
-                    sut.send_copy(1293)?;
-                }
+        drop(sample1);
+        assert_that!(sut.try_loan().unwrap(), is_some);
+    }
 
-                // disconnect all subscribers
-                drop(subscribers);
+    #[conformance_test]
+    pub fn history_yields_retained_samples_from_oldest_to_newest<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 7;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        for history_size in [0, 3, 10] {
+            let service = node
+                .service_builder(&generate_service_name())
+                .publish_subscribe::<u64>()
+                .history_size(history_size)
+                .subscriber_max_buffer_size(history_size.max(1))
+                .create()
+                .unwrap();
+            let sut = service.publisher_builder().create().unwrap();
+
+            for value in 0..NUMBER_OF_SAMPLES {
+                assert_that!(sut.send_copy(value), eq Ok(0));
             }
-        }
 
-        let sample = sut.loan()?;
+            let mut retained = vec![];
+            sut.history(|payload| retained.push(*payload));
 
-        assert_that!(sample.send(), is_ok);
+            let number_of_retained = NUMBER_OF_SAMPLES.min(history_size as u64);
+            let expected: alloc::vec::Vec<u64> =
+                (NUMBER_OF_SAMPLES - number_of_retained..NUMBER_OF_SAMPLES).collect();
+            assert_that!(retained, eq expected);
 
-        Ok(())
+            // reading the history does not affect its delivery
+            let subscriber = service.subscriber_builder().create().unwrap();
+            assert_that!(sut.update_connections(), is_ok);
+            let mut received = vec![];
+            while let Some(sample) = subscriber.receive().unwrap() {
+                received.push(*sample);
+            }
+            assert_that!(received, eq expected);
+        }
     }
 }
@@ -15,6 +15,7 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[allow(clippy::module_inception)]
 #[conformance_tests]
 pub mod sample {
+    use iceoryx2::port::ReceiveError;
     use iceoryx2::port::publisher::Publisher;
     use iceoryx2::port::subscriber::Subscriber;
     use iceoryx2::prelude::*;
@@ -170,4 +171,141 @@ pub mod sample {
         assert_that!(*sample_1, eq PAYLOAD_1);
         assert_that!(*sample_2, eq PAYLOAD_2);
     }
+
+    #[conformance_test]
+    pub fn owned_sample_outlives_subscriber<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        const PAYLOAD: u64 = 8812349123;
+
+        let subscriber = test_context.subscriber;
+
+        assert_that!(test_context.publisher_1.send_copy(PAYLOAD), eq Ok(1));
+        let owned_sample = subscriber.receive().unwrap().unwrap().into_owned();
+
+        drop(subscriber);
+
+        assert_that!(*owned_sample, eq PAYLOAD);
+        assert_that!(owned_sample.origin(), eq test_context.publisher_1.id());
+        assert_that!(*owned_sample.into_payload(), eq PAYLOAD);
+    }
+
+    #[conformance_test]
+    pub fn owned_slice_sample_outlives_subscriber<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        const PAYLOAD: [u64; 3] = [1, 23, 456];
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(PAYLOAD.len())
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(PAYLOAD.len()).unwrap();
+        assert_that!(sample.write_from_slice(&PAYLOAD).send(), eq Ok(1));
+        let owned_sample = subscriber.receive().unwrap().unwrap().into_owned();
+
+        drop(subscriber);
+
+        assert_that!(*owned_sample, eq PAYLOAD);
+        assert_that!(owned_sample.origin(), eq publisher.id());
+        assert_that!(*owned_sample.clone().into_payload(), eq PAYLOAD);
+    }
+
+    #[conformance_test]
+    pub fn clone_ref_keeps_slot_reserved_until_last_reference_is_dropped<Sut: Service>() {
+        const PAYLOAD: u64 = 5647382910;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(PAYLOAD), eq Ok(1));
+        assert_that!(publisher.send_copy(PAYLOAD + 1), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let sample_ref_1 = sample.clone_ref();
+        let sample_ref_2 = sample.clone_ref();
+        drop(sample);
+
+        assert_that!(*sample_ref_1, eq PAYLOAD);
+        assert_that!(*sample_ref_2, eq PAYLOAD);
+        assert_that!(sample_ref_1.origin(), eq publisher.id());
+
+        drop(sample_ref_1);
+        assert_that!(*sample_ref_2, eq PAYLOAD);
+        // the slot is still borrowed by the remaining reference
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::ExceedsMaxBorrows));
+
+        drop(sample_ref_2);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq PAYLOAD + 1);
+    }
+
+    #[conformance_test]
+    pub fn sample_guard_keeps_slot_reserved_across_await_point<Sut: Service>() {
+        use core::future::Future;
+        use core::pin::pin;
+        use core::task::{Context, Poll, Waker};
+
+        struct YieldOnce(bool);
+
+        impl Future for YieldOnce {
+            type Output = ();
+            fn poll(mut self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+                if self.0 {
+                    Poll::Ready(())
+                } else {
+                    self.0 = true;
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            }
+        }
+
+        const PAYLOAD: u64 = 918273645;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(PAYLOAD), eq Ok(1));
+        assert_that!(publisher.send_copy(PAYLOAD + 1), eq Ok(1));
+
+        let guard = subscriber.receive().unwrap().unwrap().into_guard();
+        let mut task = pin!(async {
+            YieldOnce(false).await;
+            let payload = *guard;
+            guard.release();
+            payload
+        });
+
+        let mut context = Context::from_waker(Waker::noop());
+        assert_that!(task.as_mut().poll(&mut context), eq Poll::Pending);
+        // the guarded sample is still borrowed while the task is suspended
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::ExceedsMaxBorrows));
+
+        assert_that!(task.as_mut().poll(&mut context), eq Poll::Ready(PAYLOAD));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq PAYLOAD + 1);
+    }
 }
@@ -16,17 +16,23 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[conformance_tests]
 pub mod sample_mut {
     use alloc::vec;
+    use core::time::Duration;
     use iceoryx2::port::LoanError;
     use iceoryx2::port::publisher::Publisher;
     use iceoryx2::port::subscriber::Subscriber;
     use iceoryx2::prelude::*;
+    use iceoryx2::sample_mut::{PushError, SerializeError};
     use iceoryx2::service::Service;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
     use iceoryx2::testing::generate_service_name;
     use iceoryx2::testing::*;
+    use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing_macros::conformance_test;
+    use iceoryx2_cal::serialize::Serialize as _;
+    use iceoryx2_cal::serialize::postcard::Postcard;
+    use serde::{Deserialize, Serialize};
 
     const MAX_LOANED_SAMPLES: usize = 5;
 
@@ -144,6 +150,44 @@ pub mod sample_mut {
         assert_that!(*received_sample, eq PAYLOAD);
     }
 
+    #[conformance_test]
+    pub fn into_uninit_allows_rewriting_the_payload_before_send<Sut: Service>() {
+        const PAYLOAD_1: u64 = 19283746;
+        const PAYLOAD_2: u64 = 64738291;
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        let sample = test_context
+            .publisher
+            .loan_uninit()
+            .unwrap()
+            .write_payload(PAYLOAD_1);
+
+        let sample = sample.into_uninit().write_payload(PAYLOAD_2);
+        assert_that!(*sample.payload(), eq PAYLOAD_2);
+        assert_that!(sample.send(), eq Ok(1));
+
+        let received_sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(*received_sample, eq PAYLOAD_2);
+        assert_that!(test_context.subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn send_timed_reports_recipients_and_send_duration<Sut: Service>() {
+        const PAYLOAD: u64 = 9182734;
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        let sample = test_context.publisher.loan_uninit().unwrap();
+        let sample = sample.write_payload(PAYLOAD);
+
+        let (number_of_recipients, send_duration) = sample.send_timed().unwrap();
+        assert_that!(number_of_recipients, eq 1);
+        assert_that!(send_duration, gt Duration::ZERO);
+        assert_that!(send_duration, lt Duration::from_secs(10));
+
+        let received_sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(*received_sample, eq PAYLOAD);
+    }
+
     #[conformance_test]
     pub fn sample_of_dropped_service_does_block_new_service_creation<Sut: Service>() {
         let config = generate_isolated_config();
@@ -175,4 +219,199 @@ pub mod sample_mut {
 
         assert_that!(service.publisher_builder().create(), is_ok);
     }
+
+    #[conformance_test]
+    pub fn push_fills_slice_up_to_capacity<Sut: Service>() {
+        const CAPACITY: usize = 4;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(CAPACITY)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sut = publisher.loan_slice(CAPACITY).unwrap();
+        assert_that!(sut.remaining_capacity(), eq CAPACITY);
+
+        for n in 0..CAPACITY {
+            assert_that!(sut.push(n as u64 * 10), is_ok);
+            assert_that!(sut.remaining_capacity(), eq CAPACITY - n - 1);
+        }
+
+        assert_that!(sut.push(123), eq Err(PushError::Full));
+        assert_that!(sut.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq [0u64, 10, 20, 30].as_slice());
+    }
+
+    #[conformance_test]
+    pub fn send_after_push_delivers_only_pushed_prefix<Sut: Service>() {
+        const CAPACITY: usize = 8;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(CAPACITY)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sut = publisher.loan_slice(CAPACITY).unwrap();
+        assert_that!(sut.push(7), is_ok);
+        assert_that!(sut.push(9), is_ok);
+        assert_that!(sut.remaining_capacity(), eq CAPACITY - 2);
+        assert_that!(sut.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().number_of_elements(), eq 2);
+        assert_that!(sample.payload(), eq [7u64, 9].as_slice());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SerializedTestData {
+        id: u64,
+        name: alloc::string::String,
+        values: vec::Vec<u16>,
+    }
+
+    #[conformance_test]
+    pub fn write_serialized_round_trip_works<Sut: Service>() {
+        const SLICE_LEN: usize = 128;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let data = SerializedTestData {
+            id: 42,
+            name: "hypnotoad".into(),
+            values: vec![1, 2, 3],
+        };
+
+        let mut sut = publisher.loan_slice(SLICE_LEN).unwrap();
+        let number_of_bytes = sut.write_serialized(&data).unwrap();
+        assert_that!(number_of_bytes, lt SLICE_LEN);
+        assert_that!(sut.payload(), len number_of_bytes);
+        assert_that!(sut.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), len number_of_bytes);
+        let received: SerializedTestData = Postcard::deserialize(sample.payload()).unwrap();
+        assert_that!(received, eq data);
+    }
+
+    #[conformance_test]
+    pub fn write_serialized_fails_when_value_exceeds_slice<Sut: Service>() {
+        const SLICE_LEN: usize = 4;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .create()
+            .unwrap();
+
+        let mut sut = publisher.loan_slice(SLICE_LEN).unwrap();
+        let data = SerializedTestData {
+            id: u64::MAX,
+            name: "too long for the slice".into(),
+            values: vec![],
+        };
+
+        assert_that!(sut.write_serialized(&data), eq Err(SerializeError::ExceedsSliceLength));
+        assert_that!(sut.payload(), eq [0u8; SLICE_LEN].as_slice());
+    }
+
+    #[conformance_test]
+    pub fn payload_capacity_bytes_covers_padding_of_over_aligned_payload<Sut: Service>() {
+        const PAYLOAD_ALIGNMENT: usize = 64;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(PAYLOAD_ALIGNMENT).unwrap())
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let sut = publisher.loan().unwrap();
+
+        assert_that!(sut.payload() as *const u64 as usize, aligned_to PAYLOAD_ALIGNMENT);
+        assert_that!(sut.payload_capacity_bytes(), gt core::mem::size_of::<u64>());
+    }
+
+    #[conformance_test]
+    pub fn push_error_display_works<Sut: Service>() {
+        assert_that!(alloc::format!("{}", PushError::Full), eq "PushError::Full");
+    }
+
+    #[conformance_test]
+    pub fn pinned_sample_slot_is_not_reused_until_unpinned<Sut: Service>() {
+        const PAYLOAD: u64 = 8129371;
+        const NUMBER_OF_ITERATIONS: usize = 10;
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        let mut sample = test_context.publisher.loan().unwrap();
+        *sample.payload_mut() = PAYLOAD;
+        let pinned_slot = sample.payload() as *const u64;
+        let sut = sample.send_pinned().unwrap();
+        assert_that!(sut.number_of_recipients(), eq 1);
+
+        let received_sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(received_sample.is_pinned(), eq true);
+        assert_that!(*received_sample, eq PAYLOAD);
+        drop(received_sample);
+
+        for n in 0..NUMBER_OF_ITERATIONS {
+            let mut samples = vec![];
+            while let Ok(mut sample) = test_context.publisher.loan() {
+                assert_that!(sample.payload() as *const u64, ne pinned_slot);
+                *sample.payload_mut() = n as u64;
+                samples.push(sample);
+            }
+            assert_that!(samples, len MAX_LOANED_SAMPLES);
+
+            for sample in samples {
+                assert_that!(sample.send(), is_ok);
+            }
+            while let Some(sample) = test_context.subscriber.receive().unwrap() {
+                assert_that!(sample.is_pinned(), eq false);
+            }
+        }
+
+        assert_that!(unsafe { *pinned_slot }, eq PAYLOAD);
+        assert_that!(test_context.publisher.number_of_samples_in_use(), eq 1);
+
+        sut.unpin();
+        assert_that!(test_context.publisher.number_of_samples_in_use(), eq 0);
+    }
 }
@@ -243,7 +243,7 @@ pub mod server {
         let active_request = sut.receive().unwrap().unwrap();
 
         let _response = active_request.loan().unwrap();
-        assert_that!(active_request.loan().err(), eq Some(iceoryx2::port::LoanError::OutOfMemory));
+        assert_that!(active_request.loan().err(), eq Some(iceoryx2::port::LoanError::SegmentFull));
     }
 
     pub fn override_preallocated_responses_to_zero_rounds_up_to_one<Sut: Service>() {
@@ -268,7 +268,7 @@ pub mod server {
         let active_request = sut.receive().unwrap().unwrap();
 
         let _response = active_request.loan().unwrap();
-        assert_that!(active_request.loan().err(), eq Some(iceoryx2::port::LoanError::OutOfMemory));
+        assert_that!(active_request.loan().err(), eq Some(iceoryx2::port::LoanError::SegmentFull));
     }
 
     pub fn override_preallocated_responses_many_works<Sut: Service>() {
@@ -301,7 +301,7 @@ pub mod server {
                 responses.push(active_request.loan().unwrap());
             }
 
-            assert_that!(active_request.loan().err(), eq Some(iceoryx2::port::LoanError::OutOfMemory));
+            assert_that!(active_request.loan().err(), eq Some(iceoryx2::port::LoanError::SegmentFull));
         }
     }
 
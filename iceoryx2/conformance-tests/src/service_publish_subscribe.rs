@@ -15,11 +15,15 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[allow(clippy::module_inception)]
 #[conformance_tests]
 pub mod service_publish_subscribe {
+    use alloc::string::{String, ToString};
     use alloc::{format, vec, vec::Vec};
+    use core::time::Duration;
 
     use iceoryx2::config::Config;
     use iceoryx2::port::LoanError;
-    use iceoryx2::port::publisher::PublisherCreateError;
+    use iceoryx2::port::publisher::{
+        ExtendedUserHeaderLoanError, PublisherCreateError, SetLatchedValueError,
+    };
     use iceoryx2::port::subscriber::SubscriberCreateError;
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::{AllocationStrategy, *};
@@ -27,7 +31,9 @@ pub mod service_publish_subscribe {
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
     use iceoryx2::service::messaging_pattern::MessagingPattern;
-    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2::service::static_config::message_type_details::{
+        TypeCompatibility, TypeDetail, TypeVariant,
+    };
     use iceoryx2::service::{Service, ServiceDetails};
     use iceoryx2::testing;
     use iceoryx2::testing::generate_service_name;
@@ -37,6 +43,7 @@ pub mod service_publish_subscribe {
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
+    use iceoryx2_bb_posix::clock::nanosleep;
     use iceoryx2_bb_posix::ipc_capable::Handle;
     use iceoryx2_bb_posix::mutex::{MutexBuilder, MutexHandle};
     use iceoryx2_bb_posix::thread::thread_scope;
@@ -44,6 +51,7 @@ pub mod service_publish_subscribe {
     use iceoryx2_bb_testing::watchdog::Watchdog;
     use iceoryx2_bb_testing_macros::conformance_test;
     use iceoryx2_log::{LogLevel, set_log_level};
+    use serde::{Deserialize, Serialize};
 
     #[derive(Debug, ZeroCopySend)]
     #[repr(C)]
@@ -219,6 +227,56 @@ pub mod service_publish_subscribe {
         assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
     }
 
+    #[conformance_test]
+    pub fn open_with_timeout_fails_with_timeout_when_service_does_not_appear<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_with_timeout(Duration::from_millis(50));
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::Timeout);
+    }
+
+    #[conformance_test]
+    pub fn open_with_timeout_succeeds_when_service_is_created_concurrently<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+
+        let barrier_handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&barrier_handle).unwrap();
+
+        thread_scope(|s| {
+            s.thread_builder().spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                nanosleep(Duration::from_millis(100)).unwrap();
+                let _service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .create()
+                    .unwrap();
+
+                barrier.wait();
+            })?;
+
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let sut = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open_with_timeout(Duration::from_secs(10));
+            barrier.wait();
+
+            assert_that!(sut, is_ok);
+
+            Ok(())
+        })
+        .unwrap();
+    }
+
     #[conformance_test]
     pub fn open_succeeds_when_service_does_exist<Sut: Service>() {
         let service_name = generate_service_name();
@@ -256,6 +314,35 @@ pub mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[conformance_test]
+    pub fn open_fails_with_incompatible_type_name_when_type_names_are_required_to_match<
+        Sut: Service,
+    >() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<i64>()
+            .require_matching_type_name(true)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypeName);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .require_matching_type_name(true)
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
     #[conformance_test]
     pub fn open_fails_when_service_has_wrong_slice_base_type<Sut: Service>() {
         let service_name = generate_service_name();
@@ -1521,6 +1608,128 @@ pub mod service_publish_subscribe {
         }
     }
 
+    #[conformance_test]
+    pub fn overflowed_samples_are_counted_in_subscriber_details<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 2;
+        const NUMBER_OF_EVICTED_SAMPLES: usize = 5;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let overflowed_samples = || {
+            let mut overflowed_samples = vec![];
+            sut.dynamic_config().list_subscribers(|details| {
+                overflowed_samples.push(details.overflowed_samples());
+                CallbackProgression::Continue
+            });
+            overflowed_samples
+        };
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+        assert_that!(overflowed_samples(), eq vec![0]);
+
+        for i in 0..NUMBER_OF_EVICTED_SAMPLES {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+        assert_that!(overflowed_samples(), eq vec![NUMBER_OF_EVICTED_SAMPLES as u64]);
+
+        while subscriber.receive().unwrap().is_some() {}
+        assert_that!(publisher.send_copy(0), is_ok);
+        assert_that!(overflowed_samples(), eq vec![NUMBER_OF_EVICTED_SAMPLES as u64]);
+    }
+
+    #[conformance_test]
+    pub fn consecutive_sends_have_consecutive_sequence_numbers<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 8;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.last_sequence_number(publisher.id()), is_none);
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.sequence_number(), eq i);
+            assert_that!(sample.header().sequence_number(), eq i);
+            assert_that!(subscriber.last_sequence_number(publisher.id()), eq Some(i));
+        }
+
+        let other_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(
+            subscriber.last_sequence_number(other_publisher.id()),
+            is_none
+        );
+        assert_that!(other_publisher.send_copy(0), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.sequence_number(), eq 0);
+        assert_that!(subscriber.last_sequence_number(other_publisher.id()), eq Some(0));
+        assert_that!(subscriber.last_sequence_number(publisher.id()), eq Some(NUMBER_OF_SAMPLES - 1));
+    }
+
+    #[conformance_test]
+    pub fn sample_after_overflow_is_marked_as_preceded_by_overflow<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 2;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(0), is_ok);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.was_preceded_by_overflow(), eq false);
+        drop(sample);
+
+        for i in 1..=2 * BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq BUFFER_SIZE + 1);
+        assert_that!(sample.was_preceded_by_overflow(), eq true);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2 * BUFFER_SIZE);
+        assert_that!(sample.was_preceded_by_overflow(), eq false);
+    }
+
     #[conformance_test]
     pub fn publish_does_not_overflow_when_deactivated<Sut: Service>() {
         let service_name = generate_service_name();
@@ -1633,6 +1842,171 @@ pub mod service_publish_subscribe {
         assert_that!(data, is_none);
     }
 
+    #[conformance_test]
+    pub fn latched_value_is_delivered_only_to_late_joining_subscribers<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(1)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        let early_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        assert_that!(sut_publisher.send_copy(12), is_ok);
+        assert_that!(sut_publisher.set_latched_value(34), is_ok);
+
+        let data = early_subscriber.receive().unwrap();
+        assert_that!(data, is_some);
+        assert_that!(*data.unwrap(), eq 12);
+        assert_that!(early_subscriber.receive().unwrap(), is_none);
+
+        let late_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        let data = late_subscriber.receive().unwrap();
+        assert_that!(data, is_some);
+        assert_that!(*data.unwrap(), eq 34);
+        assert_that!(late_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn latched_value_and_live_sample_have_different_sequence_numbers<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(1)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.set_latched_value(34), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+        assert_that!(sut_publisher.send_copy(56), is_ok);
+
+        let latched_sample = sut_subscriber.receive().unwrap().unwrap();
+        assert_that!(*latched_sample, eq 34);
+        let live_sample = sut_subscriber.receive().unwrap().unwrap();
+        assert_that!(*live_sample, eq 56);
+
+        assert_that!(latched_sample.sequence_number(), ne live_sample.sequence_number());
+    }
+
+    #[conformance_test]
+    pub fn set_latched_value_fails_without_history<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+
+        assert_that!(sut_publisher.set_latched_value(34).err(), eq Some(SetLatchedValueError::HistoryNotEnabled));
+    }
+
+    #[conformance_test]
+    pub fn send_to_nodes_delivers_only_to_subscribers_of_the_given_nodes<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node_a = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node_b = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service_a = node_a
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(2)
+            .create()
+            .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let sut_publisher = service_a.publisher_builder().create().unwrap();
+        let subscriber_a = service_a.subscriber_builder().create().unwrap();
+        let subscriber_b = service_b.subscriber_builder().create().unwrap();
+
+        let sample = sut_publisher.loan_uninit().unwrap().write_payload(81);
+        assert_that!(sample.send_to_nodes(&[*node_b.id()]), eq Ok(1));
+
+        assert_that!(subscriber_a.receive().unwrap(), is_none);
+        let data = subscriber_b.receive().unwrap();
+        assert_that!(data, is_some);
+        assert_that!(*data.unwrap(), eq 81);
+
+        let sample = sut_publisher.loan_uninit().unwrap().write_payload(82);
+        assert_that!(sample.send_to_nodes(&[*node_a.id(), *node_b.id()]), eq Ok(2));
+        assert_that!(*subscriber_a.receive().unwrap().unwrap(), eq 82);
+        assert_that!(*subscriber_b.receive().unwrap().unwrap(), eq 82);
+
+        let sample = sut_publisher.loan_uninit().unwrap().write_payload(83);
+        assert_that!(sample.send_to_nodes(&[]), eq Ok(0));
+        assert_that!(subscriber_a.receive().unwrap(), is_none);
+        assert_that!(subscriber_b.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn send_to_nodes_acquires_sequence_number_and_is_not_added_to_history<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node_a = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node_b = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service_a = node_a
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(1)
+            .max_subscribers(2)
+            .create()
+            .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let sut_publisher = service_a.publisher_builder().create().unwrap();
+        let subscriber_b = service_b.subscriber_builder().create().unwrap();
+
+        assert_that!(sut_publisher.send_copy(80), eq Ok(1));
+        assert_that!(*subscriber_b.receive().unwrap().unwrap(), eq 80);
+
+        let sample = sut_publisher.loan_uninit().unwrap().write_payload(81);
+        assert_that!(sample.send_to_nodes(&[*node_b.id()]), eq Ok(1));
+        let sample = sut_publisher.loan_uninit().unwrap().write_payload(82);
+        assert_that!(sample.send_to_nodes(&[*node_b.id()]), eq Ok(1));
+
+        let first = subscriber_b.receive().unwrap().unwrap();
+        let second = subscriber_b.receive().unwrap().unwrap();
+        assert_that!(*first, eq 81);
+        assert_that!(*second, eq 82);
+        assert_that!(first.sequence_number(), lt second.sequence_number());
+
+        let late_subscriber_a = service_a.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+        assert_that!(*late_subscriber_a.receive().unwrap().unwrap(), eq 80);
+        assert_that!(late_subscriber_a.receive().unwrap(), is_none);
+    }
+
     #[conformance_test]
     pub fn publish_send_copy_with_huge_overflow_works<Sut: Service>() {
         let service_name = generate_service_name();
@@ -2133,16 +2507,227 @@ pub mod service_publish_subscribe {
     }
 
     #[conformance_test]
-    pub fn does_exist_works_many<Sut: Service>() {
-        const NUMBER_OF_SERVICES: usize = 8;
+    pub fn shm_footprint_contains_data_segments_of_all_publishers<Sut: Service>() {
+        const SLICE_LEN: usize = 128;
+        const PAYLOAD_SIZE: usize = SLICE_LEN * core::mem::size_of::<u64>();
+        // covers the header, the user header and the alignment of every sample
+        const MAX_SAMPLE_OVERHEAD: usize = 512;
 
+        let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut services = vec![];
-        let mut service_names = vec![];
 
-        for i in 0..NUMBER_OF_SERVICES {
-            let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let footprint = sut.shm_footprint();
+        assert_that!(footprint.static_config_bytes, gt 0);
+        assert_that!(footprint.dynamic_config_bytes, gt 0);
+        assert_that!(footprint.total_segment_bytes, eq 0);
+        assert_that!(footprint.total_bytes(), eq footprint.static_config_bytes + footprint.dynamic_config_bytes);
+
+        let _publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .create()
+            .unwrap();
+
+        let mut number_of_samples = 0;
+        sut.dynamic_config().list_publishers(|details| {
+            number_of_samples = details.number_of_samples;
+            CallbackProgression::Continue
+        });
+
+        let footprint_with_publisher = sut.shm_footprint();
+        assert_that!(footprint_with_publisher.static_config_bytes, eq footprint.static_config_bytes);
+        assert_that!(footprint_with_publisher.dynamic_config_bytes, eq footprint.dynamic_config_bytes);
+        assert_that!(footprint_with_publisher.total_segment_bytes, ge PAYLOAD_SIZE * number_of_samples);
+        assert_that!(
+            footprint_with_publisher.total_segment_bytes,
+            le(PAYLOAD_SIZE + MAX_SAMPLE_OVERHEAD) * number_of_samples
+        );
+
+        let _other_publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.shm_footprint().total_segment_bytes, eq 2 * footprint_with_publisher.total_segment_bytes);
+    }
+
+    #[conformance_test]
+    pub fn estimated_resource_usage_matches_shm_footprint<Sut: Service>() {
+        const MAX_PUBLISHERS: usize = 3;
+        const SLICE_LEN: usize = 32;
+        // covers small differences in the serialized static configuration
+        const TOLERANCE: usize = 128;
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let builder = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .max_publishers(MAX_PUBLISHERS)
+            .max_subscribers(4)
+            .subscriber_max_buffer_size(5)
+            .history_size(2);
+
+        let estimate = builder.estimate_resource_usage(SLICE_LEN);
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+        assert_that!(estimate.total_segment_bytes, eq MAX_PUBLISHERS * estimate.segment_bytes_per_publisher);
+
+        let sut = builder.create().unwrap();
+        let mut publishers = vec![];
+        for _ in 0..MAX_PUBLISHERS {
+            publishers.push(
+                sut.publisher_builder()
+                    .initial_max_slice_len(SLICE_LEN)
+                    .create()
+                    .unwrap(),
+            );
+        }
+
+        let footprint = sut.shm_footprint();
+        assert_that!(estimate.dynamic_config_bytes, eq footprint.dynamic_config_bytes);
+        assert_that!(estimate.total_segment_bytes, eq footprint.total_segment_bytes);
+        assert_that!(estimate.total_bytes(), le footprint.total_bytes() + TOLERANCE);
+        assert_that!(estimate.total_bytes() + TOLERANCE, ge footprint.total_bytes());
+    }
+
+    #[conformance_test]
+    pub fn persistent_service_outlives_all_nodes<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .persistent(true)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        assert_that!(sut.is_persistent(), eq true);
+
+        drop(sut);
+        drop(node);
+
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap().is_persistent(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn service_is_not_persistent_by_default<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        assert_that!(sut.is_persistent(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn is_creator_is_set_for_create_and_open<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let creator = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        assert_that!(creator.is_creator(), eq true);
+
+        let opener = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(opener.is_creator(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn is_creator_reflects_whether_open_or_create_created_the_service<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let creator = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        assert_that!(creator.is_creator(), eq true);
+
+        let opener = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        assert_that!(opener.is_creator(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn creation_epoch_increases_when_service_is_recreated<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let first_epoch = sut.creation_epoch();
+        assert_that!(first_epoch, eq 1);
+        assert_that!(sut.static_config().creation_epoch(), eq first_epoch);
+
+        let opener = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(opener.creation_epoch(), eq first_epoch);
+
+        drop(opener);
+        drop(sut);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        assert_that!(sut.creation_epoch(), eq first_epoch + 1);
+    }
+
+    #[conformance_test]
+    pub fn does_exist_works_many<Sut: Service>() {
+        const NUMBER_OF_SERVICES: usize = 8;
+
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut services = vec![];
+        let mut service_names = vec![];
+
+        for i in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_service_name();
             assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
 
             services.push(
@@ -2537,6 +3122,35 @@ pub mod service_publish_subscribe {
         }
     }
 
+    #[conformance_test]
+    pub fn send_external_bytes_works<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 64;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let external_buffer: Vec<u8> = (0..MAX_ELEMENTS).map(|i| (i * 3) as u8).collect();
+        let number_of_recipients = unsafe {
+            publisher.send_external_bytes(external_buffer.as_ptr(), external_buffer.len())
+        };
+        assert_that!(number_of_recipients, eq Ok(1));
+
+        let recv_sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(recv_sample.payload(), eq external_buffer.as_slice());
+    }
+
     #[conformance_test]
     pub fn slice_aligned_service_works<Sut: Service>() {
         const MAX_ELEMENTS: usize = 91;
@@ -2647,6 +3261,104 @@ pub mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[derive(Debug, Default, ZeroCopySend)]
+    #[repr(C)]
+    struct UserHeaderV1 {
+        value: u64,
+    }
+
+    #[derive(Debug, Default, ZeroCopySend)]
+    #[repr(C)]
+    struct UserHeaderV2 {
+        value: u32,
+        appended_value: u32,
+    }
+
+    #[derive(Debug, Default, ZeroCopySend)]
+    #[repr(C)]
+    struct UserHeaderV3 {
+        value: u64,
+        appended_value: u64,
+    }
+
+    #[conformance_test]
+    pub fn open_with_exact_header_compatibility_requires_identical_user_header<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<UserHeaderV1>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<UserHeaderV1>()
+            .open_with_header_compatibility(TypeCompatibility::Exact);
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<UserHeaderV2>()
+            .open_with_header_compatibility(TypeCompatibility::Exact);
+        assert_that!(sut3.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[conformance_test]
+    pub fn open_with_matching_layout_header_compatibility_accepts_same_sized_user_header<
+        Sut: Service,
+    >() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<UserHeaderV1>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<UserHeaderV1>()
+            .open_with_header_compatibility(TypeCompatibility::MatchingLayout);
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<UserHeaderV2>()
+            .open_with_header_compatibility(TypeCompatibility::MatchingLayout)
+            .unwrap();
+
+        let sut4 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<UserHeaderV3>()
+            .open_with_header_compatibility(TypeCompatibility::MatchingLayout);
+        assert_that!(sut4.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let publisher = sut3.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        sample.user_header_mut().value = 12;
+        sample.user_header_mut().appended_value = 34;
+        *sample.payload_mut() = 5678;
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 5678);
+        assert_that!(sample.user_header().value, ne 0);
+    }
+
     #[conformance_test]
     pub fn create_with_custom_payload_type_works<Sut: Service>() {
         let service_name = generate_service_name();
@@ -2778,6 +3490,8 @@ pub mod service_publish_subscribe {
                                   "PublishSubscribeOpenError::InternalFailure");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleTypes), eq
                                   "PublishSubscribeOpenError::IncompatibleTypes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleTypeName), eq
+                                  "PublishSubscribeOpenError::IncompatibleTypeName");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleMessagingPattern), eq
                                   "PublishSubscribeOpenError::IncompatibleMessagingPattern");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleAttributes), eq
@@ -2806,6 +3520,8 @@ pub mod service_publish_subscribe {
                                   "PublishSubscribeOpenError::ExceedsMaxNumberOfNodes");
         assert_that!(format!("{}", PublishSubscribeOpenError::IsMarkedForDestruction), eq
                                   "PublishSubscribeOpenError::IsMarkedForDestruction");
+        assert_that!(format!("{}", PublishSubscribeOpenError::Timeout), eq
+                                  "PublishSubscribeOpenError::Timeout");
     }
 
     #[conformance_test]
@@ -3334,30 +4050,85 @@ pub mod service_publish_subscribe {
     }
 
     #[conformance_test]
-    pub fn listing_all_publishers_works<S: Service>() {
-        const NUMBER_OF_PUBLISHERS: usize = 18;
+    pub fn fingerprint_of_opened_service_is_identical<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node_1 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let sut = node
+        let sut = node_1
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .subscriber_max_buffer_size(4)
             .create()
             .unwrap();
 
-        let mut publishers = vec![];
+        let sut2 = node_2
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
 
-        for _ in 0..NUMBER_OF_PUBLISHERS {
-            publishers.push(sut.publisher_builder().create().unwrap());
-        }
+        assert_that!(sut.static_config().fingerprint(), eq sut2.static_config().fingerprint());
+    }
 
-        let mut publisher_details = vec![];
-        sut.dynamic_config().list_publishers(|details| {
-            publisher_details.push(details.publisher_id);
-            CallbackProgression::Continue
-        });
+    #[conformance_test]
+    pub fn fingerprint_differs_when_subscriber_max_buffer_size_differs<Sut: Service>() {
+        let service_name_1 = generate_service_name();
+        let service_name_2 = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name_1)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name_2)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let sut3 = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().fingerprint(), ne sut2.static_config().fingerprint());
+        assert_that!(sut.static_config().fingerprint(), eq sut3.static_config().fingerprint());
+    }
+
+    #[conformance_test]
+    pub fn listing_all_publishers_works<S: Service>() {
+        const NUMBER_OF_PUBLISHERS: usize = 18;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .create()
+            .unwrap();
+
+        let mut publishers = vec![];
+
+        for _ in 0..NUMBER_OF_PUBLISHERS {
+            publishers.push(sut.publisher_builder().create().unwrap());
+        }
+
+        let mut publisher_details = vec![];
+        sut.dynamic_config().list_publishers(|details| {
+            publisher_details.push(details.publisher_id);
+            CallbackProgression::Continue
+        });
 
         assert_that!(publisher_details, len NUMBER_OF_PUBLISHERS);
         for publisher in publishers {
@@ -3365,6 +4136,107 @@ pub mod service_publish_subscribe {
         }
     }
 
+    #[conformance_test]
+    pub fn listed_publisher_details_contain_unable_to_deliver_strategy<S: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let blocking_publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let discarding_publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+
+        let mut strategies = vec![];
+        sut.dynamic_config().list_publishers(|details| {
+            strategies.push((details.publisher_id, details.unable_to_deliver_strategy()));
+            CallbackProgression::Continue
+        });
+
+        let blocking = (blocking_publisher.id(), UnableToDeliverStrategy::Block);
+        let discarding = (
+            discarding_publisher.id(),
+            UnableToDeliverStrategy::DiscardSample,
+        );
+        assert_that!(strategies, len 2);
+        assert_that!(strategies, contains blocking);
+        assert_that!(strategies, contains discarding);
+    }
+
+    #[conformance_test]
+    pub fn publisher_created_from_config_snapshot_has_same_details<S: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let original_service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let mirrored_service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let original = original_service
+            .publisher_builder()
+            .initial_max_slice_len(16)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .max_loaned_samples(3)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+
+        let snapshot = original.builder_config();
+        let mirrored = mirrored_service
+            .publisher_builder()
+            .from_config(&snapshot)
+            .create()
+            .unwrap();
+
+        let mut original_details = vec![];
+        original_service
+            .dynamic_config()
+            .list_publishers(|details| {
+                original_details.push(*details);
+                CallbackProgression::Continue
+            });
+        let mut mirrored_details = vec![];
+        mirrored_service
+            .dynamic_config()
+            .list_publishers(|details| {
+                mirrored_details.push(*details);
+                CallbackProgression::Continue
+            });
+
+        assert_that!(original_details, len 1);
+        assert_that!(mirrored_details, len 1);
+        let original_details = original_details[0];
+        let mirrored_details = mirrored_details[0];
+
+        assert_that!(mirrored_details.publisher_id, eq mirrored.id());
+        assert_that!(mirrored_details.number_of_samples, eq original_details.number_of_samples);
+        assert_that!(mirrored_details.max_slice_len, eq original_details.max_slice_len);
+        assert_that!(mirrored_details.data_segment_type, eq original_details.data_segment_type);
+        assert_that!(mirrored_details.max_number_of_segments, eq original_details.max_number_of_segments);
+        assert_that!(mirrored_details.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::DiscardSample);
+        assert_that!(mirrored.initial_max_slice_len(), eq 16);
+    }
+
     #[conformance_test]
     pub fn listing_all_publishers_stops_on_request<S: Service>() {
         const NUMBER_OF_PUBLISHERS: usize = 16;
@@ -3496,4 +4368,379 @@ pub mod service_publish_subscribe {
         let recv_res = subscriber.receive();
         assert_that!(recv_res, is_ok);
     }
+
+    #[derive(Debug, Default, PartialEq, ZeroCopySend)]
+    #[repr(C)]
+    struct SomeExtendedUserHeader {
+        timestamp: u64,
+        tag: u8,
+    }
+
+    #[conformance_test]
+    pub fn samples_with_and_without_extended_user_header_can_be_mixed<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u8>()
+            .extended_user_header::<SomeExtendedUserHeader>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+
+        let mut sample = publisher
+            .loan_with_extended_header::<SomeExtendedUserHeader>()
+            .unwrap();
+        assert_that!(sample.extended_user_header::<SomeExtendedUserHeader>(), eq Some(&SomeExtendedUserHeader::default()));
+        *sample
+            .extended_user_header_mut::<SomeExtendedUserHeader>()
+            .unwrap() = SomeExtendedUserHeader {
+            timestamp: 123456789,
+            tag: 42,
+        };
+        sample.write_payload(2).send().unwrap();
+
+        publisher.send_copy(3).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        assert_that!(sample.header().has_extended_user_header(), eq false);
+        assert_that!(
+            sample.extended_user_header::<SomeExtendedUserHeader>(),
+            is_none
+        );
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(sample.header().has_extended_user_header(), eq true);
+        assert_that!(sample.extended_user_header::<SomeExtendedUserHeader>(), eq Some(&SomeExtendedUserHeader {
+            timestamp: 123456789,
+            tag: 42,
+        }));
+        assert_that!(sample.extended_user_header::<u64>(), is_none);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+        assert_that!(
+            sample.extended_user_header::<SomeExtendedUserHeader>(),
+            is_none
+        );
+    }
+
+    #[conformance_test]
+    pub fn loan_with_extended_header_fails_when_type_is_not_defined_by_service<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        let sample = publisher.loan_with_extended_header::<SomeExtendedUserHeader>();
+        assert_that!(sample.err(), eq Some(ExtendedUserHeaderLoanError::IncompatibleExtendedUserHeaderType));
+    }
+
+    #[conformance_test]
+    pub fn open_with_different_extended_user_header_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .extended_user_header::<SomeExtendedUserHeader>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .extended_user_header::<u64>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .extended_user_header::<SomeExtendedUserHeader>()
+            .open();
+        assert_that!(sut3, is_ok);
+
+        let sut4 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut4, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn signal_coalesces_multiple_notifications_into_a_count<Sut: Service>() {
+        const NUMBER_OF_NOTIFICATIONS: usize = 3;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .signal()
+            .subscriber_max_buffer_size(NUMBER_OF_NOTIFICATIONS)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().unwrap();
+        let subscriber = sut.subscriber().unwrap();
+
+        assert_that!(subscriber.receive_signal().unwrap(), is_none);
+        assert_that!(subscriber.coalesced_signals(), eq 0);
+
+        for _ in 0..NUMBER_OF_NOTIFICATIONS {
+            assert_that!(publisher.notify(), eq Ok(1));
+        }
+
+        assert_that!(subscriber.has_signals(), eq Ok(true));
+        assert_that!(subscriber.receive_signal().unwrap(), eq Some(()));
+        assert_that!(subscriber.coalesced_signals(), eq NUMBER_OF_NOTIFICATIONS);
+
+        assert_that!(subscriber.receive_signal().unwrap(), is_none);
+        assert_that!(subscriber.coalesced_signals(), eq NUMBER_OF_NOTIFICATIONS);
+
+        assert_that!(publisher.notify(), eq Ok(1));
+        assert_that!(subscriber.receive_signal().unwrap(), eq Some(()));
+        assert_that!(subscriber.coalesced_signals(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn signal_service_can_be_opened_as_publish_subscribe_service<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .signal()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<()>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut3.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[conformance_test]
+    pub fn create_or_attach_reports_exactly_one_creator_when_racing<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+
+        const NUMBER_OF_THREADS: usize = 2;
+        const NUMBER_OF_ITERATIONS: usize = 20;
+
+        let config = testing::generate_isolated_config();
+        let start_handle = BarrierHandle::new();
+        let end_handle = BarrierHandle::new();
+        let start = BarrierBuilder::new(NUMBER_OF_THREADS as _)
+            .create(&start_handle)
+            .unwrap();
+        let end = BarrierBuilder::new(NUMBER_OF_THREADS as _)
+            .create(&end_handle)
+            .unwrap();
+        let service_names: Vec<ServiceName> = (0..NUMBER_OF_ITERATIONS)
+            .map(|_| generate_service_name())
+            .collect();
+        let number_of_creators: Vec<AtomicUsize> = (0..NUMBER_OF_ITERATIONS)
+            .map(|_| AtomicUsize::new(0))
+            .collect();
+
+        thread_scope(|s| {
+            for _ in 0..NUMBER_OF_THREADS {
+                s.thread_builder().spawn(|| {
+                    let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+                    for (service_name, creators) in
+                        service_names.iter().zip(number_of_creators.iter())
+                    {
+                        start.wait();
+                        let (_sut, created) = node
+                            .service_builder(service_name)
+                            .publish_subscribe::<u64>()
+                            .create_or_attach()
+                            .unwrap();
+                        if created {
+                            creators.fetch_add(1, Ordering::Relaxed);
+                        }
+                        // keep the service alive until every thread has attached to it
+                        end.wait();
+                    }
+                })?;
+            }
+
+            Ok(())
+        })
+        .unwrap();
+
+        for creators in number_of_creators.iter() {
+            assert_that!(creators.load(Ordering::Relaxed), eq 1);
+        }
+    }
+
+    #[conformance_test]
+    pub fn fixed_size_array_payload_can_be_loaned_sent_and_received<Sut: Service>() {
+        const NUMBER_OF_ELEMENTS: usize = 16;
+        type Telemetry = [f32; NUMBER_OF_ELEMENTS];
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Telemetry>()
+            .create()
+            .unwrap();
+
+        let payload_details = &sut.static_config().message_type_details().payload;
+        assert_that!(payload_details.variant(), eq TypeVariant::FixedSize);
+        assert_that!(payload_details.size(), eq core::mem::size_of::<Telemetry>());
+        assert_that!(payload_details.alignment(), eq core::mem::align_of::<f32>());
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        assert_that!(*sample.payload(), eq [0.0; NUMBER_OF_ELEMENTS]);
+        for (n, element) in sample.payload_mut().iter_mut().enumerate() {
+            *element = n as f32 * 0.5;
+        }
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let payload: &Telemetry = sample.payload();
+        for (n, element) in payload.iter().enumerate() {
+            assert_that!(*element, eq n as f32 * 0.5);
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NonPodPayload {
+        name: String,
+        values: Vec<u64>,
+        nested: Option<alloc::boxed::Box<NonPodPayload>>,
+    }
+
+    #[conformance_test]
+    pub fn list_with_dynamic_reports_number_of_publishers_and_subscribers<Sut: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service_name_1 = generate_service_name();
+        let sut_1 = node
+            .service_builder(&service_name_1)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let service_name_2 = generate_service_name();
+        let sut_2 = node
+            .service_builder(&service_name_2)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let _publisher_1 = sut_1.publisher_builder().create().unwrap();
+        let _subscriber_1a = sut_1.subscriber_builder().create().unwrap();
+        let _subscriber_1b = sut_1.subscriber_builder().create().unwrap();
+        let _subscriber_2 = sut_2.subscriber_builder().create().unwrap();
+
+        let mut listed_services = vec![];
+        let result = Sut::list_with_dynamic(
+            &config,
+            |static_config, number_of_publishers, number_of_subscribers| {
+                listed_services.push((
+                    *static_config.name(),
+                    number_of_publishers,
+                    number_of_subscribers,
+                ));
+                CallbackProgression::Continue
+            },
+        );
+        assert_that!(result, is_ok);
+
+        assert_that!(listed_services, len 2);
+        assert_that!(listed_services, contains(service_name_1, 1, 2));
+        assert_that!(listed_services, contains(service_name_2, 0, 1));
+    }
+
+    #[conformance_test]
+    pub fn serialized_service_round_trips_non_zero_copy_payload<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .serialized::<NonPodPayload>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().unwrap();
+        let subscriber = sut.subscriber().unwrap();
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        let payload = NonPodPayload {
+            name: "hypnotoad".to_string(),
+            values: (0..512).collect(),
+            nested: Some(alloc::boxed::Box::new(NonPodPayload {
+                name: "all glory".to_string(),
+                values: vec![],
+                nested: None,
+            })),
+        };
+        assert_that!(publisher.send(&payload), eq Ok(1));
+
+        assert_that!(subscriber.receive().unwrap(), eq Some(payload));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn serialized_service_cannot_be_opened_with_different_type<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .serialized::<NonPodPayload>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .serialized::<String>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypeName);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .serialized::<NonPodPayload>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
 }
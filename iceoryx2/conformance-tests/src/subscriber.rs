@@ -18,11 +18,19 @@ pub mod subscriber {
     use alloc::collections::BTreeSet;
     use alloc::{format, vec};
     use iceoryx2::port::ReceiveError;
+    use iceoryx2::port::buffer_pool::BufferPool;
+    use iceoryx2::port::dispatch::{Dispatchable, EnumVisitor};
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::prelude::{CallbackProgression, PortFactory, ZeroCopySend};
+    use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_testing_macros::conformance_test;
 
     use iceoryx2::testing::generate_service_name;
     use iceoryx2::{
-        node::NodeBuilder, port::subscriber::SubscriberCreateError, service::Service, testing,
+        node::NodeBuilder,
+        port::subscriber::{ResyncError, SubscriberCreateError},
+        service::Service,
+        testing,
     };
     use iceoryx2_bb_testing::assert_that;
 
@@ -64,6 +72,695 @@ pub mod subscriber {
         }
     }
 
+    #[conformance_test]
+    pub fn receive_filtered_returns_only_samples_with_matching_user_header<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 8;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let mut sample = publisher.loan_uninit().unwrap();
+            *sample.user_header_mut() = n % 2;
+            sample.write_payload(n).send().unwrap();
+        }
+
+        for n in (1..NUMBER_OF_SAMPLES).step_by(2) {
+            let sample = subscriber.receive_filtered(|flag| *flag == 1).unwrap();
+            assert_that!(sample, is_some);
+            let sample = sample.unwrap();
+            assert_that!(*sample.user_header(), eq 1);
+            assert_that!(*sample, eq n);
+        }
+
+        assert_that!(
+            subscriber.receive_filtered(|flag| *flag == 1).unwrap(),
+            is_none
+        );
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn receive_borrowed_releases_slot_on_explicit_release<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(12).unwrap();
+        publisher.send_copy(34).unwrap();
+
+        let borrowed = subscriber.receive_borrowed().unwrap().unwrap();
+        assert_that!(*borrowed, eq 12);
+        assert_that!(*borrowed.sample().payload() + 1, eq 13);
+        assert_that!(
+            subscriber.receive().err(),
+            eq Some(ReceiveError::ExceedsMaxBorrows)
+        );
+
+        borrowed.release();
+
+        let borrowed = subscriber.receive_borrowed().unwrap().unwrap();
+        assert_that!(*borrowed, eq 34);
+        drop(borrowed);
+
+        assert_that!(subscriber.receive_borrowed().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn retain_window_holds_the_last_n_received_samples<Sut: Service>() {
+        const WINDOW_SIZE: usize = 3;
+        const NUMBER_OF_SAMPLES: u64 = 7;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .subscriber_max_borrowed_samples(WINDOW_SIZE + 1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let mut subscriber = sut
+            .subscriber_builder()
+            .retain_window(WINDOW_SIZE)
+            .create()
+            .unwrap();
+
+        assert_that!(subscriber.window(), len 0);
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(n).unwrap();
+        }
+
+        while let Some(sample) = subscriber.receive().unwrap() {
+            drop(sample);
+        }
+
+        let window = subscriber.window();
+        assert_that!(window, len WINDOW_SIZE);
+        for (sample, expected) in window.iter().zip(NUMBER_OF_SAMPLES - WINDOW_SIZE as u64..) {
+            assert_that!(**sample, eq expected);
+        }
+    }
+
+    #[conformance_test]
+    pub fn manual_release_keeps_slots_of_dropped_samples_until_released<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 3;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES + 1)
+            .subscriber_max_borrowed_samples(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .manual_release(true)
+            .create()
+            .unwrap();
+
+        for n in 0..=NUMBER_OF_SAMPLES {
+            publisher.send_copy(n as u64).unwrap();
+        }
+
+        for _ in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+        }
+
+        assert_that!(publisher.number_of_samples_in_use(), eq NUMBER_OF_SAMPLES + 1);
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::ExceedsMaxBorrows));
+
+        assert_that!(subscriber.release_all(), eq NUMBER_OF_SAMPLES);
+        assert_that!(subscriber.release_all(), eq 0);
+        assert_that!(publisher.number_of_samples_in_use(), eq 1);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq NUMBER_OF_SAMPLES as u64);
+    }
+
+    #[conformance_test]
+    pub fn release_up_to_releases_only_older_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 4;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .subscriber_max_borrowed_samples(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .manual_release(true)
+            .create()
+            .unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let mut ids = vec![];
+        while let Some(sample) = subscriber.receive().unwrap() {
+            ids.push(sample.id());
+        }
+
+        assert_that!(subscriber.release_up_to(ids[1]), eq 2);
+        assert_that!(publisher.number_of_samples_in_use(), eq 2);
+        assert_that!(subscriber.release_all(), eq 2);
+        assert_that!(publisher.number_of_samples_in_use(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn connected_publishers_reports_all_connected_publishers<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.connected_publishers().count(), eq 0);
+
+        let publisher_1 = sut.publisher_builder().create().unwrap();
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+
+        let connected_publishers: BTreeSet<_> = subscriber.connected_publishers().collect();
+        assert_that!(connected_publishers, len 2);
+        assert_that!(connected_publishers.contains(&publisher_1.id()), eq true);
+        assert_that!(connected_publishers.contains(&publisher_2.id()), eq true);
+
+        drop(publisher_1);
+
+        let connected_publishers: vec::Vec<_> = subscriber.connected_publishers().collect();
+        assert_that!(connected_publishers, eq vec![publisher_2.id()]);
+    }
+
+    #[conformance_test]
+    pub fn keep_latest_only_receives_only_the_last_sample<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(NUMBER_OF_SAMPLES as usize)
+            .keep_latest_only(true)
+            .create()
+            .unwrap();
+        assert_that!(subscriber.buffer_size(), eq 1);
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(n).unwrap();
+        }
+        assert_that!(publisher.number_of_samples_in_use(), eq 1);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq NUMBER_OF_SAMPLES - 1);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn take_all_returns_queued_burst_and_leaves_buffer_empty<Sut: Service>() {
+        const BURST_SIZE: usize = 5;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BURST_SIZE)
+            .subscriber_max_borrowed_samples(BURST_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.take_all().unwrap(), len 0);
+
+        for n in 0..BURST_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let samples = subscriber.take_all().unwrap();
+        assert_that!(samples, len BURST_SIZE);
+        for (sample, expected) in samples.iter().zip(0..) {
+            assert_that!(**sample, eq expected);
+        }
+
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn take_all_stops_at_max_borrowed_samples<Sut: Service>() {
+        const BURST_SIZE: usize = 5;
+        const MAX_BORROWED_SAMPLES: usize = 2;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BURST_SIZE)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for n in 0..BURST_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let samples = subscriber.take_all().unwrap();
+        assert_that!(samples, len MAX_BORROWED_SAMPLES);
+        for (sample, expected) in samples.iter().zip(0..) {
+            assert_that!(**sample, eq expected);
+        }
+        assert_that!(subscriber.has_samples().unwrap(), eq true);
+
+        let result = subscriber.take_all();
+        assert_that!(result.err(), eq Some(ReceiveError::ExceedsMaxBorrows));
+
+        let sample = samples.into_iter().next().unwrap();
+        let samples = subscriber.take_all().unwrap();
+        assert_that!(samples, len 1);
+        assert_that!(*samples[0], eq MAX_BORROWED_SAMPLES as u64);
+        drop(sample);
+        drop(samples);
+
+        let samples = subscriber.take_all().unwrap();
+        assert_that!(samples, len MAX_BORROWED_SAMPLES);
+        for (sample, expected) in samples.iter().zip(MAX_BORROWED_SAMPLES as u64 + 1..) {
+            assert_that!(**sample, eq expected);
+        }
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn clear_discards_queued_burst_and_leaves_buffer_empty<Sut: Service>() {
+        const BURST_SIZE: usize = 5;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BURST_SIZE)
+            .subscriber_max_borrowed_samples(BURST_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.clear(), eq 0);
+
+        for n in 0..BURST_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        assert_that!(subscriber.clear(), eq BURST_SIZE);
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        // the discarded samples were released so the publisher can deliver a full burst again
+        for n in 0..BURST_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let samples = subscriber.take_all().unwrap();
+        assert_that!(samples, len BURST_SIZE);
+        for (sample, expected) in samples.iter().zip(0..) {
+            assert_that!(**sample, eq expected);
+            assert_that!(sample.was_preceded_by_overflow(), eq false);
+        }
+    }
+
+    #[conformance_test]
+    pub fn request_resync_redelivers_history_of_publisher<Sut: Service>() {
+        const HISTORY_SIZE: usize = 3;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..HISTORY_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        for n in 0..HISTORY_SIZE as u64 {
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq n);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(subscriber.request_resync(), eq Ok(HISTORY_SIZE));
+        // an idle publisher does not serve the request
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(publisher.update_connections(), is_ok);
+
+        for n in 0..HISTORY_SIZE as u64 {
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq n);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn request_resync_returns_number_of_samples_limited_by_buffer_size<Sut: Service>() {
+        const HISTORY_SIZE: usize = 4;
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(subscriber.request_resync(), eq Ok(0));
+
+        publisher.send_copy(0).unwrap();
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 0);
+        assert_that!(subscriber.request_resync(), eq Ok(1));
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 0);
+
+        for n in 1..HISTORY_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+        while subscriber.receive().unwrap().is_some() {}
+
+        assert_that!(subscriber.request_resync(), eq Ok(BUFFER_SIZE));
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 3);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn request_resync_fails_when_service_has_no_history<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let _publisher = sut.publisher_builder().create().unwrap();
+
+        assert_that!(subscriber.request_resync(), eq Err(ResyncError::HistoryNotEnabled));
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    enum Command {
+        Start(u64),
+        Pause,
+        Stop { code: u32 },
+    }
+
+    #[derive(Default)]
+    struct CommandHandler {
+        started: vec::Vec<u64>,
+        number_of_pauses: usize,
+        stop_codes: vec::Vec<u32>,
+    }
+
+    impl EnumVisitor for CommandHandler {}
+
+    impl Dispatchable for Command {
+        type Visitor = CommandHandler;
+
+        fn dispatch(&self, _user_header: &(), visitor: &mut CommandHandler) {
+            match self {
+                Command::Start(value) => visitor.started.push(*value),
+                Command::Pause => visitor.number_of_pauses += 1,
+                Command::Stop { code } => visitor.stop_codes.push(*code),
+            }
+        }
+    }
+
+    #[conformance_test]
+    pub fn receive_dispatch_calls_handler_of_received_variant<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Command>()
+            .subscriber_max_buffer_size(8)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let mut handler = CommandHandler::default();
+
+        assert_that!(subscriber.receive_dispatch(&mut handler).unwrap(), eq false);
+
+        publisher.send_copy(Command::Start(12)).unwrap();
+        publisher.send_copy(Command::Pause).unwrap();
+        publisher.send_copy(Command::Stop { code: 3 }).unwrap();
+        publisher.send_copy(Command::Start(34)).unwrap();
+
+        assert_that!(subscriber.receive_dispatch(&mut handler).unwrap(), eq true);
+        assert_that!(handler.started, eq vec![12]);
+        assert_that!(handler.number_of_pauses, eq 0);
+        assert_that!(handler.stop_codes, len 0);
+
+        assert_that!(subscriber.receive_dispatch(&mut handler).unwrap(), eq true);
+        assert_that!(handler.number_of_pauses, eq 1);
+
+        assert_that!(subscriber.receive_dispatch(&mut handler).unwrap(), eq true);
+        assert_that!(handler.stop_codes, eq vec![3]);
+
+        assert_that!(subscriber.receive_dispatch(&mut handler).unwrap(), eq true);
+        assert_that!(handler.started, eq vec![12, 34]);
+        assert_that!(handler.number_of_pauses, eq 1);
+
+        assert_that!(subscriber.receive_dispatch(&mut handler).unwrap(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_connected_only_to_one_publisher_receives_only_its_samples<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let selected_publisher = sut.publisher_builder().create().unwrap();
+        let other_publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .connect_only_to(selected_publisher.id())
+            .create()
+            .unwrap();
+        let unbound_subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut subscriber_details = vec![];
+        sut.dynamic_config().list_subscribers(|details| {
+            subscriber_details.push(details.connect_only_to());
+            CallbackProgression::Continue
+        });
+        assert_that!(subscriber_details, contains Some(selected_publisher.id()));
+        assert_that!(subscriber_details, contains None);
+
+        assert_that!(other_publisher.send_copy(1).unwrap(), eq 1);
+        assert_that!(selected_publisher.send_copy(2).unwrap(), eq 2);
+        assert_that!(other_publisher.send_copy(3).unwrap(), eq 1);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(sample.origin(), eq selected_publisher.id());
+        drop(sample);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        let mut received_values = BTreeSet::new();
+        while let Some(sample) = unbound_subscriber.receive().unwrap() {
+            received_values.insert(*sample);
+        }
+        assert_that!(received_values, eq BTreeSet::from([1, 2, 3]));
+    }
+
+    #[conformance_test]
+    pub fn subscriber_rejecting_foreign_endian_receives_native_endian_samples<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .reject_foreign_endian(true)
+            .create()
+            .unwrap();
+
+        publisher.send_copy(1234).unwrap();
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        let sample = sample.unwrap();
+        assert_that!(sample.is_native_endian(), eq true);
+        assert_that!(sample.header().is_native_endian(), eq true);
+        assert_that!(*sample, eq 1234);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_asserting_payload_alignment_receives_aligned_samples<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .assert_payload_alignment(Alignment::new(core::mem::align_of::<u64>()).unwrap())
+            .create()
+            .unwrap();
+
+        publisher.send_copy(4567).unwrap();
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 4567);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_asserting_payload_alignment_rejects_misaligned_samples<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        // the one byte user header directly follows the aligned header, therefore the one
+        // byte payload is stored at an odd address
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u8>()
+            .user_header::<u8>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .assert_payload_alignment(Alignment::new(2).unwrap())
+            .create()
+            .unwrap();
+
+        publisher.send_copy(89).unwrap();
+
+        let result = subscriber.receive();
+        assert_that!(result.err(), eq Some(ReceiveError::MisalignedPayload));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
     #[conformance_test]
     #[should_panic]
     #[cfg(debug_assertions)]
@@ -96,4 +793,257 @@ pub mod subscriber {
         // panics here
         let _sample = sut.receive();
     }
+
+    #[conformance_test]
+    pub fn receive_with_more_signals_end_of_queued_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 5;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        assert_that!(subscriber.receive_with_more().unwrap(), is_none);
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(n as u64).unwrap();
+        }
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let (sample, has_more) = subscriber.receive_with_more().unwrap().unwrap();
+            assert_that!(*sample, eq n as u64);
+            assert_that!(has_more, eq n + 1 < NUMBER_OF_SAMPLES);
+        }
+
+        assert_that!(subscriber.receive_with_more().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn receive_into_pool_reuses_buffers_and_reports_exhaustion<Sut: Service>() {
+        const NUMBER_OF_BUFFERS: usize = 2;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().buffer_size(4).create().unwrap();
+        let pool = BufferPool::new(NUMBER_OF_BUFFERS, core::mem::size_of::<u64>());
+
+        assert_that!(subscriber.receive_into_pool(&pool).unwrap(), is_none);
+
+        for value in 0..3u64 {
+            publisher.send_copy(value).unwrap();
+        }
+
+        let first = subscriber.receive_into_pool(&pool).unwrap().unwrap();
+        let second = subscriber.receive_into_pool(&pool).unwrap().unwrap();
+        assert_that!(*first, eq 0u64.to_ne_bytes());
+        assert_that!(*second, eq 1u64.to_ne_bytes());
+        assert_that!(pool.number_of_free_buffers(), eq 0);
+
+        let result = subscriber.receive_into_pool(&pool);
+        assert_that!(result.err(), eq Some(ReceiveError::PoolExhausted));
+        assert_that!(subscriber.has_samples().unwrap(), eq true);
+
+        let released_index = first.index();
+        drop(first);
+        assert_that!(pool.number_of_free_buffers(), eq 1);
+
+        let third = subscriber.receive_into_pool(&pool).unwrap().unwrap();
+        assert_that!(third.index(), eq released_index);
+        assert_that!(*third, eq 2u64.to_ne_bytes());
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn receive_into_pool_discards_payload_exceeding_buffer_size<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let pool = BufferPool::new(1, core::mem::size_of::<u32>());
+
+        publisher.send_copy(1234).unwrap();
+
+        let result = subscriber.receive_into_pool(&pool);
+        assert_that!(result.err(), eq Some(ReceiveError::ExceedsPoolBufferSize));
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+        assert_that!(pool.number_of_free_buffers(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn payload_size_stats_reflect_received_slice_lengths<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(128)
+            .create()
+            .unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(4)
+            .track_payload_size_stats(true)
+            .create()
+            .unwrap();
+        let untracked_subscriber = sut.subscriber_builder().buffer_size(4).create().unwrap();
+
+        let stats = subscriber.payload_size_stats().unwrap();
+        assert_that!(stats.count, eq 0);
+
+        for len in [16, 128, 4] {
+            let sample = publisher.loan_slice(len).unwrap();
+            sample.send().unwrap();
+        }
+
+        while let Some(_sample) = subscriber.receive().unwrap() {}
+        while let Some(_sample) = untracked_subscriber.receive().unwrap() {}
+
+        let stats = subscriber.payload_size_stats().unwrap();
+        assert_that!(stats.count, eq 3);
+        assert_that!(stats.min, eq 4);
+        assert_that!(stats.max, eq 128);
+        assert_that!(stats.avg, eq 148.0 / 3.0);
+        assert_that!(untracked_subscriber.payload_size_stats(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_drops_samples_received_after_their_expiry<Sut: Service>() {
+        use core::time::Duration;
+        use iceoryx2_bb_posix::clock::nanosleep;
+
+        const TTL: Duration = Duration::from_millis(10);
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(4)
+            .drop_expired(true)
+            .create()
+            .unwrap();
+        let tolerant_subscriber = sut.subscriber_builder().buffer_size(4).create().unwrap();
+
+        let sample = publisher.loan_uninit().unwrap().write_payload(12);
+        assert_that!(sample.send_with_ttl(TTL), eq Ok(2));
+        let sample = publisher.loan_uninit().unwrap().write_payload(34);
+        assert_that!(sample.send(), eq Ok(2));
+
+        nanosleep(TTL * 2).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 34);
+        assert_that!(sample.header().expiry(), is_none);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.expired_samples(), eq 1);
+
+        let sample = tolerant_subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 12);
+        assert_that!(sample.header().expiry(), is_some);
+        assert_that!(tolerant_subscriber.expired_samples(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn swapped_degradation_callback_is_invoked_on_subsequent_degradation<Sut: Service>() {
+        use alloc::sync::Arc;
+        use iceoryx2::port::DegradationAction;
+        use iceoryx2::port::update_connections::ConnectionFailure;
+        use iceoryx2::service::static_config::StaticConfig;
+        use iceoryx2_bb_concurrency::atomic::{AtomicUsize, Ordering};
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let startup_calls = Arc::new(AtomicUsize::new(0));
+        let runtime_calls = Arc::new(AtomicUsize::new(0));
+
+        let startup_calls_in_callback = startup_calls.clone();
+        let sut = service
+            .subscriber_builder()
+            .set_degradation_callback(Some(move |_, _, _| {
+                startup_calls_in_callback.fetch_add(1, Ordering::Relaxed);
+                DegradationAction::Ignore
+            }))
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let result = unsafe {
+            iceoryx2::port::testing::__internal_subscriber_fail_connection(&sut, publisher.id())
+        };
+        assert_that!(result, is_ok);
+        assert_that!(startup_calls.load(Ordering::Relaxed), eq 1);
+
+        let runtime_calls_in_callback = runtime_calls.clone();
+        sut.set_degradation_callback(Some(move |_, _, _| {
+            runtime_calls_in_callback.fetch_add(1, Ordering::Relaxed);
+            DegradationAction::Fail
+        }));
+
+        let result = unsafe {
+            iceoryx2::port::testing::__internal_subscriber_fail_connection(&sut, publisher.id())
+        };
+        assert_that!(result, is_err);
+        assert_that!(matches!(result.err(), Some(ConnectionFailure::FailedToEstablishConnection(_))), eq true);
+        assert_that!(startup_calls.load(Ordering::Relaxed), eq 1);
+        assert_that!(runtime_calls.load(Ordering::Relaxed), eq 1);
+
+        sut.set_degradation_callback::<fn(&StaticConfig, u128, u128) -> DegradationAction>(None);
+        let result = unsafe {
+            iceoryx2::port::testing::__internal_subscriber_fail_connection(&sut, publisher.id())
+        };
+        assert_that!(result, is_ok);
+        assert_that!(runtime_calls.load(Ordering::Relaxed), eq 1);
+    }
 }
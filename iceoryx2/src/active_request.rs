@@ -243,11 +243,14 @@ impl<
         UniqueClientId(UniqueSystemId::from(self.details.origin))
     }
 
-    fn increment_loan_counter(&self) -> Result<(), LoanError> {
+    fn increment_loan_counter(
+        &self,
+        shared_state: &SharedServerState<Service>,
+    ) -> Result<(), LoanError> {
         let mut current_loan_count = self.shared_loan_counter.load(Ordering::Relaxed);
         loop {
             if self.max_loan_count <= current_loan_count {
-                fail!(from self,
+                fail!(from shared_state.log_origin(self),
                 with LoanError::ExceedsMaxLoans,
                 "Unable to loan memory for Response since it would exceed the maximum number of loans of {}.",
                 self.max_loan_count);
@@ -306,8 +309,8 @@ impl<
         &self,
     ) -> Result<ResponseMutUninit<Service, MaybeUninit<ResponsePayload>, ResponseHeader>, LoanError>
     {
-        self.increment_loan_counter()?;
         let shared_state = self.shared_state.lock();
+        self.increment_loan_counter(&shared_state)?;
 
         let chunk = shared_state
             .response_sender
@@ -380,7 +383,7 @@ impl<
     /// ```
     pub fn send_copy(&self, value: ResponsePayload) -> Result<(), SendError> {
         let msg = "Unable to send copy of response";
-        let response = fail!(from self,
+        let response = fail!(from self.shared_state.lock().log_origin(self),
                             when self.loan_uninit(),
                             "{} since the loan of the response failed.", msg);
 
@@ -550,12 +553,12 @@ impl<
         if shared_state.config.allocation_strategy == AllocationStrategy::Static
             && max_slice_len < slice_len
         {
-            fail!(from self, with LoanError::ExceedsMaxLoanSize,
+            fail!(from shared_state.log_origin(self), with LoanError::ExceedsMaxLoanSize,
                 "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
                 slice_len, max_slice_len);
         }
 
-        self.increment_loan_counter()?;
+        self.increment_loan_counter(&shared_state)?;
 
         let response_layout = shared_state.response_sender.sample_layout(slice_len);
         let chunk = shared_state.response_sender.allocate(response_layout)?;
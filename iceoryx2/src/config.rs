@@ -126,6 +126,34 @@ impl core::fmt::Display for ConfigCreationError {
 
 impl core::error::Error for ConfigCreationError {}
 
+/// Failures detected by [`Config::validate()`]. Each variant describes the first invalid
+/// setting that was found.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigValidationError {
+    /// The directory in which all service files are stored is empty.
+    EmptyServiceDirectory,
+    /// The directory in which all node files are stored is empty.
+    EmptyNodeDirectory,
+    /// A limit or buffer size of the publish-subscribe defaults is zero.
+    ZeroPublishSubscribeLimit,
+    /// A limit of the event defaults is zero.
+    ZeroEventLimit,
+    /// An event id of the event defaults is larger than the maximum event id value.
+    EventIdExceedsMaxValue,
+    /// A limit or buffer size of the request-response defaults is zero.
+    ZeroRequestResponseLimit,
+    /// A limit of the blackboard defaults is zero.
+    ZeroBlackboardLimit,
+}
+
+impl core::fmt::Display for ConfigValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ConfigValidationError::{self:?}")
+    }
+}
+
+impl core::error::Error for ConfigValidationError {}
+
 /// All configurable settings of a [`Service`](crate::service::Service).
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -151,6 +179,8 @@ pub struct Service {
     pub blackboard_mgmt_suffix: FileName,
     /// The suffix of the blackboard payload data segment
     pub blackboard_data_suffix: FileName,
+    /// The suffix of the file that stores the creation epoch of a service
+    pub creation_epoch_suffix: FileName,
 }
 
 impl Default for Service {
@@ -165,6 +195,7 @@ impl Default for Service {
             event_connection_suffix: FileName::new(b".event").unwrap(),
             blackboard_mgmt_suffix: FileName::new(b".blackboard_mgmt").unwrap(),
             blackboard_data_suffix: FileName::new(b".blackboard_data").unwrap(),
+            creation_epoch_suffix: FileName::new(b".epoch").unwrap(),
         }
     }
 }
@@ -183,6 +214,14 @@ pub struct Node {
     pub static_config_suffix: FileName,
     /// The suffix of the service tags.
     pub service_tag_suffix: FileName,
+    /// The suffix of the heartbeat that is stamped with
+    /// [`Node::heartbeat()`](crate::node::Node::heartbeat()).
+    pub heartbeat_suffix: FileName,
+    /// When true, every [`Node`](crate::node::Node) creates a heartbeat that can be stamped
+    /// with [`Node::heartbeat()`](crate::node::Node::heartbeat()). Otherwise, no heartbeat
+    /// is created and [`Node::is_alive()`](crate::node::Node::is_alive()) returns `false`
+    /// for the [`Node`](crate::node::Node).
+    pub enable_heartbeat: bool,
     /// When true, the [`NodeBuilder`](crate::node::NodeBuilder) checks for dead nodes and
     /// cleans up all their stale resources whenever a new [`Node`](crate::node::Node) is
     /// created.
@@ -200,6 +239,8 @@ impl Default for Node {
             monitor_suffix: FileName::new(b".node_monitor").unwrap(),
             static_config_suffix: FileName::new(b".details").unwrap(),
             service_tag_suffix: FileName::new(b".service_tag").unwrap(),
+            heartbeat_suffix: FileName::new(b".heartbeat").unwrap(),
+            enable_heartbeat: false,
             cleanup_dead_nodes_on_creation: true,
             cleanup_dead_nodes_on_destruction: true,
         }
@@ -662,6 +703,114 @@ impl Config {
         Ok(new_config)
     }
 
+    /// Checks the invariants of the [`Config`] so that an invalid combination of settings is
+    /// detected before it is passed to the [`NodeBuilder`](crate::node::NodeBuilder). Returns
+    /// the [`ConfigValidationError`] of the first invalid setting that was found.
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        let msg = "Invalid config";
+        if self.global.service.directory.is_empty() {
+            fail!(from self, with ConfigValidationError::EmptyServiceDirectory,
+                "{} since the service directory is empty.", msg);
+        }
+
+        if self.global.node.directory.is_empty() {
+            fail!(from self, with ConfigValidationError::EmptyNodeDirectory,
+                "{} since the node directory is empty.", msg);
+        }
+
+        let publish_subscribe = &self.defaults.publish_subscribe;
+        for (name, value) in [
+            ("max-subscribers", publish_subscribe.max_subscribers),
+            ("max-publishers", publish_subscribe.max_publishers),
+            ("max-nodes", publish_subscribe.max_nodes),
+            (
+                "subscriber-max-buffer-size",
+                publish_subscribe.subscriber_max_buffer_size,
+            ),
+            (
+                "subscriber-max-borrowed-samples",
+                publish_subscribe.subscriber_max_borrowed_samples,
+            ),
+            (
+                "publisher-max-loaned-samples",
+                publish_subscribe.publisher_max_loaned_samples,
+            ),
+        ] {
+            if value == 0 {
+                fail!(from self, with ConfigValidationError::ZeroPublishSubscribeLimit,
+                    "{} since the publish-subscribe default \"{}\" is zero.", msg, name);
+            }
+        }
+
+        let event = &self.defaults.event;
+        for (name, value) in [
+            ("max-listeners", event.max_listeners),
+            ("max-notifiers", event.max_notifiers),
+            ("max-nodes", event.max_nodes),
+        ] {
+            if value == 0 {
+                fail!(from self, with ConfigValidationError::ZeroEventLimit,
+                    "{} since the event default \"{}\" is zero.", msg, name);
+            }
+        }
+
+        for (name, value) in [
+            ("notifier-created-event", event.notifier_created_event),
+            ("notifier-dropped-event", event.notifier_dropped_event),
+            ("notifier-dead-event", event.notifier_dead_event),
+        ] {
+            if let Some(value) = value {
+                if value > event.event_id_max_value {
+                    fail!(from self, with ConfigValidationError::EventIdExceedsMaxValue,
+                        "{} since the event default \"{}\" with the value {} exceeds the max event id value of {}.",
+                        msg, name, value, event.event_id_max_value);
+                }
+            }
+        }
+
+        let request_response = &self.defaults.request_response;
+        for (name, value) in [
+            (
+                "max-active-requests-per-client",
+                request_response.max_active_requests_per_client,
+            ),
+            (
+                "max-response-buffer-size",
+                request_response.max_response_buffer_size,
+            ),
+            ("max-servers", request_response.max_servers),
+            ("max-clients", request_response.max_clients),
+            ("max-nodes", request_response.max_nodes),
+            (
+                "max-borrowed-responses-per-pending-response",
+                request_response.max_borrowed_responses_per_pending_response,
+            ),
+            ("max-loaned-requests", request_response.max_loaned_requests),
+            (
+                "server-max-loaned-responses-per-request",
+                request_response.server_max_loaned_responses_per_request,
+            ),
+        ] {
+            if value == 0 {
+                fail!(from self, with ConfigValidationError::ZeroRequestResponseLimit,
+                    "{} since the request-response default \"{}\" is zero.", msg, name);
+            }
+        }
+
+        let blackboard = &self.defaults.blackboard;
+        for (name, value) in [
+            ("max-readers", blackboard.max_readers),
+            ("max-nodes", blackboard.max_nodes),
+        ] {
+            if value == 0 {
+                fail!(from self, with ConfigValidationError::ZeroBlackboardLimit,
+                    "{} since the blackboard default \"{}\" is zero.", msg, name);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Sets up the global configuration from a file. If the global configuration was already setup
     /// it will print a warning and does not load the file. It returns the [`Config`] when the file
     /// could be successfully loaded otherwise a [`ConfigCreationError`] describing the error.
@@ -112,6 +112,36 @@ generate_id! {
     UniqueNodeId
 }
 
+/// Identifies a sent [`Sample`](crate::sample::Sample) system-wide by the
+/// [`UniquePublisherId`] of its [`Publisher`](crate::port::publisher::Publisher) and the
+/// sequence number the [`Publisher`](crate::port::publisher::Publisher) stamped on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SampleId {
+    publisher_id: UniquePublisherId,
+    sequence_number: u64,
+}
+
+impl SampleId {
+    pub(crate) fn new(publisher_id: UniquePublisherId, sequence_number: u64) -> Self {
+        Self {
+            publisher_id,
+            sequence_number,
+        }
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
+    /// that sent the [`Sample`](crate::sample::Sample).
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the sequence number of the [`Sample`](crate::sample::Sample).
+    /// See [`Header::sequence_number()`](crate::service::header::publish_subscribe::Header::sequence_number()).
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+}
+
 /// Enum that contains the unique port id
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UniquePortId {
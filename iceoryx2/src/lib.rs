@@ -515,6 +515,10 @@ pub mod constants;
 /// Handles iceoryx2s global configuration
 pub mod config;
 
+/// Process-wide port counters in the Prometheus text format
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
 /// Central instance that owns all service entities and can handle incoming event in an event loop
 pub mod node;
 
@@ -556,15 +560,27 @@ pub mod response_mut;
 /// [`Client`](crate::port::client::Client)
 pub mod response_mut_uninit;
 
+/// A heap allocated copy of a received [`Sample`](crate::sample::Sample) that no longer
+/// occupies the shared memory.
+pub mod owned_sample;
+
 /// The payload that is received by a [`Subscriber`](crate::port::subscriber::Subscriber).
 pub mod sample;
 
+/// Keeps a received [`Sample`](crate::sample::Sample) reserved, for instance across an
+/// `.await` point.
+pub mod sample_guard;
+
 /// The payload that is sent by a [`Publisher`](crate::port::publisher::Publisher).
 pub mod sample_mut;
 
 /// The uninitialized payload that is sent by a [`Publisher`](crate::port::publisher::Publisher).
 pub mod sample_mut_uninit;
 
+/// A pool of pre-loaned uninitialized samples of a
+/// [`Publisher`](crate::port::publisher::Publisher).
+pub mod sample_pool;
+
 /// The foundation of communication the service with its
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
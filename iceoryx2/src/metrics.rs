@@ -0,0 +1,96 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Process-wide counters of all ports that can be exported in the Prometheus text format.
+//! Only available with the `metrics` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .publish_subscribe::<u64>()
+//! #   .open_or_create()?;
+//! # let publisher = service.publisher_builder().create()?;
+//!
+//! publisher.send_copy(1234)?;
+//!
+//! // serve it for instance via an HTTP endpoint that is scraped by Prometheus
+//! println!("{}", iceoryx2::metrics::render_prometheus());
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
+
+static SENT_SAMPLES: AtomicU64 = AtomicU64::new(0);
+static RECEIVED_SAMPLES: AtomicU64 = AtomicU64::new(0);
+static DROPPED_SAMPLES: AtomicU64 = AtomicU64::new(0);
+static LOANED_SAMPLES: AtomicU64 = AtomicU64::new(0);
+
+pub(crate) fn record_sent_sample() {
+    SENT_SAMPLES.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn record_received_sample() {
+    RECEIVED_SAMPLES.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn record_dropped_sample() {
+    DROPPED_SAMPLES.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn record_loaned_sample() {
+    LOANED_SAMPLES.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Renders the counters of all ports of the process in the Prometheus text exposition
+/// format. The counters cover every zero-copy port, meaning samples, requests and responses
+/// alike.
+pub fn render_prometheus() -> String {
+    let counters = [
+        (
+            "iceoryx2_sent_samples_total",
+            "Number of samples that were delivered to a receiving port.",
+            &SENT_SAMPLES,
+        ),
+        (
+            "iceoryx2_received_samples_total",
+            "Number of samples that were received by a receiving port.",
+            &RECEIVED_SAMPLES,
+        ),
+        (
+            "iceoryx2_dropped_samples_total",
+            "Number of samples that could not be delivered since the receive buffer was full.",
+            &DROPPED_SAMPLES,
+        ),
+        (
+            "iceoryx2_loaned_samples_total",
+            "Number of samples that were loaned by a sending port.",
+            &LOANED_SAMPLES,
+        ),
+    ];
+
+    let mut output = String::new();
+    for (name, help, counter) in counters {
+        let _ = writeln!(output, "# HELP {name} {help}");
+        let _ = writeln!(output, "# TYPE {name} counter");
+        let _ = writeln!(output, "{name} {}", counter.load(Ordering::Relaxed));
+    }
+
+    output
+}
@@ -145,6 +145,7 @@ pub mod node_name;
 #[doc(hidden)]
 pub mod testing;
 
+use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::time::Duration;
 use iceoryx2_bb_concurrency::atomic::Ordering;
@@ -157,14 +158,15 @@ use alloc::sync::Arc;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64};
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
-use iceoryx2_bb_posix::clock::{NanosleepError, nanosleep};
+use iceoryx2_bb_posix::clock::{ClockType, NanosleepError, Time, nanosleep};
+use iceoryx2_bb_posix::file::AccessMode;
 use iceoryx2_bb_posix::mutex::Handle;
 use iceoryx2_bb_posix::mutex::Mutex;
 use iceoryx2_bb_posix::mutex::MutexBuilder;
@@ -173,7 +175,12 @@ use iceoryx2_bb_posix::mutex::MutexType;
 use iceoryx2_bb_posix::process::Process;
 use iceoryx2_bb_posix::signal::SignalHandler;
 use iceoryx2_bb_system_types::file_name::FileName;
-use iceoryx2_cal::named_concept::{NamedConceptPathHintRemoveError, NamedConceptRemoveError};
+use iceoryx2_cal::dynamic_storage::{
+    DynamicStorage, DynamicStorageBuilder, DynamicStorageCreateError,
+};
+use iceoryx2_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptMgmt, NamedConceptPathHintRemoveError, NamedConceptRemoveError,
+};
 use iceoryx2_cal::{
     monitoring::*, named_concept::NamedConceptListError, serialize::*, static_storage::*,
 };
@@ -183,10 +190,11 @@ use crate::identifiers::UniqueNodeId;
 use crate::node::node_name::NodeName;
 use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
-    node_details_path, node_monitoring_config, service_tag_config,
+    node_details_path, node_heartbeat_config, node_monitoring_config, service_tag_config,
 };
 use crate::service::service_hash::ServiceHash;
 use crate::service::service_name::ServiceName;
+use crate::service::static_config::StaticConfig;
 use crate::service::{
     self, ServiceRemoveNodeError, remove_service_tag, remove_static_service_config,
 };
@@ -207,6 +215,9 @@ pub enum NodeCreationFailure {
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalError,
+    /// The prefix provided via [`NodeBuilder::shm_name_prefix()`] contains characters that are
+    /// not allowed in the names of system resources or is too long.
+    InvalidShmNamePrefix,
 }
 
 impl core::fmt::Display for NodeCreationFailure {
@@ -695,6 +706,30 @@ fn remove_node_details_directory<Service: service::Service>(
     }
 }
 
+fn remove_heartbeat<Service: service::Service>(
+    origin: &str,
+    config: &Config,
+    node_id: &UniqueNodeId,
+) -> Result<(), NodeCleanupFailure> {
+    let msg = "Unable to remove node heartbeat";
+    match unsafe {
+        <Service::BlackboardMgmt<NodeHeartbeat> as NamedConceptMgmt>::remove_cfg(
+            &node_id.as_file_name(),
+            &node_heartbeat_config::<Service>(config),
+        )
+    } {
+        Ok(_) => Ok(()),
+        Err(NamedConceptRemoveError::InsufficientPermissions) => {
+            fail!(from origin, with NodeCleanupFailure::InsufficientPermissions,
+                "{} due to insufficient permissions.", msg);
+        }
+        Err(NamedConceptRemoveError::InternalError) => {
+            fail!(from origin, with NodeCleanupFailure::InternalError,
+                "{} due to an internal failure.", msg);
+        }
+    }
+}
+
 fn remove_node<Service: service::Service>(
     id: UniqueNodeId,
     config: &Config,
@@ -708,14 +743,22 @@ fn remove_node<Service: service::Service>(
     let details_config = node_details_config::<Service>(config, &id);
     let detail_storages = acquire_all_node_detail_storages::<Service>(&origin, &details_config)?;
     remove_detail_storages::<Service>(&origin, detail_storages, &details_config)?;
+    remove_heartbeat::<Service>(&origin, config, &id)?;
     remove_node_details_directory::<Service>(config, &id)?;
 
     Ok(true)
 }
 
+#[derive(Debug)]
+struct RegisteredService {
+    handle: ContainerHandle,
+    reference_counter: u64,
+    static_config: Option<StaticConfig>,
+}
+
 #[derive(Debug)]
 pub(crate) struct RegisteredServices {
-    handle: MutexHandle<BTreeMap<ServiceHash, (ContainerHandle, u64)>>,
+    handle: MutexHandle<BTreeMap<ServiceHash, RegisteredService>>,
 }
 
 unsafe impl Send for RegisteredServices {}
@@ -739,11 +782,17 @@ impl RegisteredServices {
     }
 
     fn insert(
-        services: &mut BTreeMap<ServiceHash, (ContainerHandle, u64)>,
+        services: &mut BTreeMap<ServiceHash, RegisteredService>,
         service_hash: ServiceHash,
         handle: ContainerHandle,
     ) {
-        if services.insert(service_hash, (handle, 1)).is_some() {
+        let service = RegisteredService {
+            handle,
+            reference_counter: 1,
+            static_config: None,
+        };
+
+        if services.insert(service_hash, service).is_some() {
             fatal_panic!(from "RegisteredServices::insert()",
                 "This should never happen! The service with the {:?} was already registered.",
                 service_hash);
@@ -773,7 +822,7 @@ impl RegisteredServices {
 
         match guard.get_mut(service_hash) {
             Some(entry) => {
-                entry.1 += 1;
+                entry.reference_counter += 1;
             }
             None => {
                 let new_handle = or_callback()?;
@@ -791,9 +840,9 @@ impl RegisteredServices {
         let mut guard = self.mutex().lock().expect("Failed to lock mutex");
 
         if let Some(entry) = guard.get_mut(service_hash) {
-            entry.1 -= 1;
-            if entry.1 == 0 {
-                let handle = entry.0;
+            entry.reference_counter -= 1;
+            if entry.reference_counter == 0 {
+                let handle = entry.handle;
                 cleanup_call(handle);
                 guard.remove(service_hash);
             }
@@ -805,13 +854,73 @@ impl RegisteredServices {
         drop(guard);
     }
 
-    fn mutex(&self) -> Mutex<'_, '_, BTreeMap<ServiceHash, (ContainerHandle, u64)>> {
+    pub(crate) fn set_static_config(&self, static_config: &StaticConfig) {
+        let mut guard = fatal_panic!(
+            from self,
+            when self.mutex().lock(),
+            "Failed to lock mutex"
+        );
+
+        if let Some(entry) = guard.get_mut(static_config.service_hash()) {
+            if entry.static_config.is_none() {
+                entry.static_config = Some(static_config.clone());
+            }
+        }
+    }
+
+    pub(crate) fn static_configs(&self) -> Vec<StaticConfig> {
+        let guard = fatal_panic!(
+            from self,
+            when self.mutex().lock(),
+            "Failed to lock mutex"
+        );
+
+        guard
+            .values()
+            .filter_map(|entry| entry.static_config.clone())
+            .collect()
+    }
+
+    fn mutex(&self) -> Mutex<'_, '_, BTreeMap<ServiceHash, RegisteredService>> {
         // Safe - the mutex is initialized when constructing the struct and
         // not interacted with by anything else.
         unsafe { Mutex::from_handle(&self.handle) }
     }
 }
 
+/// Stores the monotonic timestamp in nanoseconds of the last [`Node::heartbeat()`] in the
+/// shared memory so that every other [`Node`] can read it.
+#[derive(Debug)]
+#[repr(C)]
+pub(crate) struct NodeHeartbeat {
+    last_heartbeat: AtomicU64,
+}
+
+fn monotonic_timestamp() -> Option<u64> {
+    Time::now_with_clock(ClockType::Monotonic)
+        .ok()
+        .map(|now| now.as_duration().as_nanos() as u64)
+}
+
+/// Origin of a log message of an entity that is owned by a [`Node`]. It prepends the
+/// [`NodeBuilder::log_prefix()`] of the [`Node`] to the [`Debug`] output of the origin.
+pub(crate) struct NodeLogOrigin<'a, T: Debug + ?Sized> {
+    log_prefix: &'a str,
+    origin: &'a T,
+}
+
+impl<'a, T: Debug + ?Sized> NodeLogOrigin<'a, T> {
+    pub(crate) fn new(log_prefix: &'a str, origin: &'a T) -> Self {
+        Self { log_prefix, origin }
+    }
+}
+
+impl<T: Debug + ?Sized> Debug for NodeLogOrigin<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}{:?}", self.log_prefix, self.origin)
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct SharedNode<Service: service::Service> {
     id: UniqueNodeId,
@@ -819,6 +928,9 @@ pub(crate) struct SharedNode<Service: service::Service> {
     monitoring_token: UnsafeCell<Option<<Service::Monitoring as Monitoring>::Token>>,
     registered_services: RegisteredServices,
     signal_handling_mode: SignalHandlingMode,
+    log_prefix: String,
+    are_publishers_paused: AtomicBool,
+    heartbeat: Option<Service::BlackboardMgmt<NodeHeartbeat>>,
     _details_storage: Service::StaticStorage,
 }
 
@@ -837,6 +949,21 @@ impl<Service: service::Service> SharedNode<Service> {
     pub(crate) fn registered_services(&self) -> &RegisteredServices {
         &self.registered_services
     }
+
+    pub(crate) fn log_prefix(&self) -> &str {
+        &self.log_prefix
+    }
+
+    pub(crate) fn log_origin<'a, T: Debug + ?Sized>(
+        &'a self,
+        origin: &'a T,
+    ) -> NodeLogOrigin<'a, T> {
+        NodeLogOrigin::new(&self.log_prefix, origin)
+    }
+
+    pub(crate) fn are_publishers_paused(&self) -> bool {
+        self.are_publishers_paused.load(Ordering::Relaxed)
+    }
 }
 
 impl<Service: service::Service> Drop for SharedNode<Service> {
@@ -846,7 +973,7 @@ impl<Service: service::Service> Drop for SharedNode<Service> {
                 Node::<Service>::cleanup_dead_nodes(self.config());
             }
 
-            warn!(from self, when remove_node::<Service>(self.id, self.details.config()),
+            warn!(from self.log_origin(self), when remove_node::<Service>(self.id, self.details.config()),
                 "Unable to remove node resources.");
         }
     }
@@ -859,11 +986,19 @@ impl<Service: service::Service> Drop for SharedNode<Service> {
 /// were created via the [`Node`].
 ///
 /// Can be created via the [`NodeBuilder`].
-#[derive(Debug)]
 pub struct Node<Service: service::Service> {
     shared: Arc<SharedNode<Service>>,
 }
 
+impl<Service: service::Service> Debug for Node<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.shared.log_prefix)?;
+        f.debug_struct("Node")
+            .field("shared", &self.shared)
+            .finish()
+    }
+}
+
 unsafe impl<Service: service::Service> Send for Node<Service> {}
 
 impl<Service: service::Service> Node<Service> {
@@ -887,6 +1022,42 @@ impl<Service: service::Service> Node<Service> {
         Builder::new(name, self.shared.clone())
     }
 
+    /// Calls the provided callback for every service this [`Node`] has currently opened or
+    /// created, meaning a service for which a
+    /// [`PortFactory`](crate::service::port_factory::PortFactory) of this [`Node`] exists.
+    /// Services that were opened multiple times are listed only once and services of other
+    /// [`Node`]s are not listed. With every iteration the callback has to return
+    /// [`CallbackProgression::Continue`] to perform the next iteration or
+    /// [`CallbackProgression::Stop`] to stop the iteration immediately.
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// node.list_open_services(|service_name, static_config| {
+    ///     println!("{} uses the {:?}", service_name, static_config.messaging_pattern());
+    ///     CallbackProgression::Continue
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_open_services<F: FnMut(&ServiceName, &StaticConfig) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        // the configs are copied so that the callback can open or close services of this node
+        // without dead locking
+        for static_config in self.shared.registered_services().static_configs() {
+            if callback(static_config.name(), &static_config) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+
     /// Calls the provided callback for all [`Node`]s in the system under a given [`Config`] and
     /// provides [`NodeState<Service>`] as input argument. With every iteration the callback has to
     /// return [`CallbackProgression::Continue`] to perform the next iteration or
@@ -935,6 +1106,132 @@ impl<Service: service::Service> Node<Service> {
         Ok(())
     }
 
+    /// Pauses all [`Publisher`](crate::port::publisher::Publisher)s that were created by this
+    /// [`Node`]. While paused, sending a sample succeeds but delivers it to no
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) and returns `Ok(0)`. The sample is
+    /// neither added to the history nor does it consume a sequence number.
+    /// [`Publisher`](crate::port::publisher::Publisher)s of other [`Node`]s are not affected.
+    pub fn pause_publishers(&self) {
+        self.shared
+            .are_publishers_paused
+            .store(true, Ordering::Relaxed);
+    }
+
+    /// Resumes the delivery of all [`Publisher`](crate::port::publisher::Publisher)s that were
+    /// paused with [`Node::pause_publishers()`].
+    pub fn resume_publishers(&self) {
+        self.shared
+            .are_publishers_paused
+            .store(false, Ordering::Relaxed);
+    }
+
+    /// Returns `true` when the [`Publisher`](crate::port::publisher::Publisher)s of this
+    /// [`Node`] are paused, otherwise `false`.
+    pub fn are_publishers_paused(&self) -> bool {
+        self.shared.are_publishers_paused()
+    }
+
+    /// Refreshes the heartbeat of the [`Node`] with the current time. Other [`Node`]s can use
+    /// [`Node::is_alive()`] to check if the heartbeat is recent enough. In contrast to the dead
+    /// node detection, which only detects crashed processes, a missing heartbeat also reveals a
+    /// [`Node`] that is stuck. The heartbeat is stamped once when the [`Node`] is created.
+    ///
+    /// The heartbeat is only available when it is enabled in the [`Config`] with
+    /// `global.node.enable-heartbeat`, otherwise the call has no effect.
+    pub fn heartbeat(&self) {
+        let heartbeat = match &self.shared.heartbeat {
+            Some(heartbeat) => heartbeat,
+            None => {
+                warn!(from self, "Unable to stamp the heartbeat since it is not enabled in the config.");
+                return;
+            }
+        };
+
+        match monotonic_timestamp() {
+            Some(now) => heartbeat.get().last_heartbeat.store(now, Ordering::Relaxed),
+            None => {
+                warn!(from self, "Unable to stamp the heartbeat since the monotonic clock could not be read.");
+            }
+        }
+    }
+
+    /// Returns `true` when the [`Node`] with the provided [`UniqueNodeId`] stamped its
+    /// heartbeat with [`Node::heartbeat()`] within the last `max_age`. If the [`Node`] does
+    /// not exist, has no heartbeat enabled or its heartbeat is older, it returns `false`.
+    pub fn is_alive(&self, node_id: &UniqueNodeId, max_age: Duration) -> bool {
+        let heartbeat = match <Service::BlackboardMgmt<NodeHeartbeat> as DynamicStorage<
+            NodeHeartbeat,
+        >>::Builder::new(&node_id.as_file_name())
+        .config(&node_heartbeat_config::<Service>(self.config()))
+        .has_ownership(false)
+        .open(AccessMode::Read)
+        {
+            Ok(heartbeat) => heartbeat,
+            Err(_) => return false,
+        };
+
+        let last_heartbeat = heartbeat.get().last_heartbeat.load(Ordering::Relaxed);
+        match monotonic_timestamp() {
+            Some(now) => Duration::from_nanos(now.saturating_sub(last_heartbeat)) <= max_age,
+            None => false,
+        }
+    }
+
+    /// Provides the [`Node`] with a new identity after the process was forked. It must be
+    /// called in the child process before it performs any IPC. The child acquires a new
+    /// [`UniqueNodeId`] and registers itself with its own node details and monitoring token,
+    /// so that it operates independently of the parent.
+    ///
+    /// The resources of the parent remain untouched and are neither used nor removed by the
+    /// child. Therefore, all [`Service`](crate::service::Service)s and ports that were created
+    /// in the parent must not be used in the child. Dropping them would detach them from
+    /// the parent's connections, hence they shall be leaked with [`core::mem::forget()`].
+    /// Services and ports that are created after this call belong to the child.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let mut node = NodeBuilder::new().create::<ipc::Service>()?;
+    ///
+    /// // ... fork the process, then in the child:
+    /// node.reinit_after_fork()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reinit_after_fork(&mut self) -> Result<(), NodeCreationFailure> {
+        let msg = "Unable to reinitialize node after fork";
+
+        let builder = NodeBuilder::new()
+            .name(self.name())
+            .config(self.config())
+            .signal_handling_mode(self.signal_handling_mode())
+            .log_prefix(self.log_prefix());
+
+        let node = fail!(from self, when builder.create::<Service>(),
+            "{msg} since a new node identity could not be created.");
+        node.shared
+            .are_publishers_paused
+            .store(self.shared.are_publishers_paused(), Ordering::Relaxed);
+
+        // The monitoring token belongs to the parent process. Dropping it would remove the
+        // parent's node resources, therefore it is leaked. Safe since the forked child
+        // consists of a single thread.
+        if let Some(token) = unsafe { (*self.shared.monitoring_token.get()).take() } {
+            core::mem::forget(token);
+        }
+
+        debug!(from self, "reinitialized after fork, new node id {:?}", node.shared.id);
+        self.shared = node.shared;
+
+        Ok(())
+    }
+
     pub(crate) unsafe fn staged_death(&mut self) -> <Service::Monitoring as Monitoring>::Token {
         unsafe { (*self.shared.monitoring_token.get()).take().unwrap() }
     }
@@ -979,6 +1276,18 @@ impl<Service: service::Service> Node<Service> {
         self.shared.signal_handling_mode
     }
 
+    /// Returns the prefix that is prepended to the log messages of the [`Node`] and all
+    /// entities it owns. See [`NodeBuilder::log_prefix()`].
+    pub fn log_prefix(&self) -> &str {
+        self.shared.log_prefix()
+    }
+
+    /// Removes the stale system resources of all dead [`Node`]s that use the same [`Config`]
+    /// as this [`Node`]. See [`Node::cleanup_dead_nodes()`].
+    pub fn cleanup_stale_resources(&self) -> CleanupState {
+        Self::cleanup_dead_nodes(self.config())
+    }
+
     /// Removes the stale system resources of all dead [`Node`]s. The dead [`Node`]s are also
     /// removed from all registered [`Service`](crate::service::Service)s.
     ///
@@ -1218,11 +1527,31 @@ impl<Service: service::Service> Node<Service> {
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Default, Clone)]
+#[derive(Default, Clone)]
 pub struct NodeBuilder {
     name: Option<NodeName>,
     signal_handling_mode: SignalHandlingMode,
     config: Option<Config>,
+    log_prefix: String,
+    automatic_dead_node_cleanup: Option<bool>,
+    shm_name_prefix: Option<String>,
+}
+
+impl Debug for NodeBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.log_prefix)?;
+        f.debug_struct("NodeBuilder")
+            .field("name", &self.name)
+            .field("signal_handling_mode", &self.signal_handling_mode)
+            .field("config", &self.config)
+            .field("log_prefix", &self.log_prefix)
+            .field(
+                "automatic_dead_node_cleanup",
+                &self.automatic_dead_node_cleanup,
+            )
+            .field("shm_name_prefix", &self.shm_name_prefix)
+            .finish()
+    }
 }
 
 impl NodeBuilder {
@@ -1252,6 +1581,41 @@ impl NodeBuilder {
         self
     }
 
+    /// Defines a prefix that is prepended to the origin of all log messages of the [`Node`]
+    /// and of all entities it owns, like the services and ports created via the [`Node`].
+    /// Useful to correlate the log output of a process that hosts multiple [`Node`]s.
+    /// By default, no prefix is prepended.
+    pub fn log_prefix(mut self, value: &str) -> Self {
+        self.log_prefix = value.to_string();
+        self
+    }
+
+    /// Defines if the [`Node`] removes the stale resources of dead [`Node`]s automatically when
+    /// it is created or dropped. When disabled, the [`Node`] never scans for dead [`Node`]s on
+    /// its own and the stale resources remain until [`Node::cleanup_stale_resources()`] or
+    /// [`Node::cleanup_dead_nodes()`] is called explicitly. Useful for latency sensitive
+    /// applications that require a deterministic behavior. By default, the settings
+    /// `cleanup_dead_nodes_on_creation` and `cleanup_dead_nodes_on_destruction` of
+    /// [`crate::config::Node`] apply.
+    pub fn automatic_dead_node_cleanup(mut self, value: bool) -> Self {
+        self.automatic_dead_node_cleanup = Some(value);
+        self
+    }
+
+    /// Defines a prefix that is prepended to the names of all system resources, like the shared
+    /// memory of the data segments and of the [`Service`](crate::service::Service)s, that are
+    /// created or opened via the [`Node`]. It is prepended to the `prefix` of
+    /// [`crate::config::Global`]. Useful to separate tenants that share the same system, for
+    /// instance in containerized environments. Only [`Node`]s that use the same prefix can
+    /// discover each other and communicate.
+    ///
+    /// If the prefix contains characters that are not allowed in file names,
+    /// [`NodeBuilder::create()`] fails with [`NodeCreationFailure::InvalidShmNamePrefix`].
+    pub fn shm_name_prefix(mut self, value: &str) -> Self {
+        self.shm_name_prefix = Some(value.to_string());
+        self
+    }
+
     /// Creates a new [`Node`] for a specific [`service::Service`]. All entities owned by the
     /// [`Node`] will have the same [`service::Service`].
     pub fn create<Service: service::Service>(self) -> Result<Node<Service>, NodeCreationFailure> {
@@ -1263,12 +1627,26 @@ impl NodeBuilder {
         self,
         node_id: UniqueNodeId,
     ) -> Result<Node<Service>, NodeCreationFailure> {
-        let config = if let Some(ref config) = self.config {
+        let mut config = if let Some(ref config) = self.config {
             config.clone()
         } else {
             Config::global_config().clone()
         };
 
+        if let Some(shm_name_prefix) = &self.shm_name_prefix {
+            let mut prefix = shm_name_prefix.as_bytes().to_vec();
+            prefix.extend_from_slice(config.global.prefix.as_bytes());
+            config.global.prefix = fail!(from self, when FileName::new(&prefix),
+                with NodeCreationFailure::InvalidShmNamePrefix,
+                "Unable to create node since the shm name prefix \"{}\" is not a valid file name prefix.",
+                shm_name_prefix);
+        }
+
+        if let Some(value) = self.automatic_dead_node_cleanup {
+            config.global.node.cleanup_dead_nodes_on_creation = value;
+            config.global.node.cleanup_dead_nodes_on_destruction = value;
+        }
+
         if config.global.node.cleanup_dead_nodes_on_creation {
             Node::<Service>::cleanup_dead_nodes(&config);
         }
@@ -1279,6 +1657,11 @@ impl NodeBuilder {
         let (details_storage, details) =
             self.create_node_details_storage::<Service>(&config, &node_id)?;
         let monitoring_token = self.create_token::<Service>(&config, &monitor_name)?;
+        let heartbeat = if config.global.node.enable_heartbeat {
+            Some(self.create_heartbeat::<Service>(&config, &monitor_name)?)
+        } else {
+            None
+        };
 
         Ok(Node {
             shared: Arc::new(SharedNode {
@@ -1287,6 +1670,9 @@ impl NodeBuilder {
                 registered_services: RegisteredServices::new(),
                 _details_storage: details_storage,
                 signal_handling_mode: self.signal_handling_mode,
+                log_prefix: self.log_prefix.clone(),
+                are_publishers_paused: AtomicBool::new(false),
+                heartbeat,
                 details,
             }),
         })
@@ -1319,6 +1705,38 @@ impl NodeBuilder {
         }
     }
 
+    fn create_heartbeat<Service: service::Service>(
+        &self,
+        config: &Config,
+        heartbeat_name: &FileName,
+    ) -> Result<Service::BlackboardMgmt<NodeHeartbeat>, NodeCreationFailure> {
+        let msg = "Unable to create heartbeat for new node";
+        let heartbeat_result = <Service::BlackboardMgmt<NodeHeartbeat> as DynamicStorage<
+            NodeHeartbeat,
+        >>::Builder::new(heartbeat_name)
+        .config(&node_heartbeat_config::<Service>(config))
+        .has_ownership(false)
+        .create(NodeHeartbeat {
+            last_heartbeat: AtomicU64::new(monotonic_timestamp().unwrap_or(0)),
+        });
+
+        match heartbeat_result {
+            Ok(heartbeat) => Ok(heartbeat),
+            Err(DynamicStorageCreateError::InsufficientPermissions) => {
+                fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to create the heartbeat storage.");
+            }
+            Err(DynamicStorageCreateError::AlreadyExists) => {
+                fatal_panic!(from self,
+                    "This should never happen! {msg} since a node with the same UniqueNodeId already exists.");
+            }
+            Err(e) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} since the heartbeat storage could not be created ({:?}).", e);
+            }
+        }
+    }
+
     fn create_node_details_storage<Service: service::Service>(
         &self,
         config: &Config,
@@ -0,0 +1,102 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .publish_subscribe::<u64>()
+//! #   .open_or_create()?;
+//! # let subscriber = service.subscriber_builder().create()?;
+//!
+//! let mut buffer = vec![];
+//! while let Some(sample) = subscriber.receive()? {
+//!     // releases the shared memory of the sample right away
+//!     buffer.push(sample.into_owned());
+//! }
+//!
+//! for sample in buffer {
+//!     println!("received: {:?}", *sample);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt::Debug, ops::Deref};
+
+use alloc::boxed::Box;
+
+use crate::identifiers::UniquePublisherId;
+use crate::service::header::publish_subscribe::Header;
+
+/// A heap allocated copy of the payload, user header and header of a received
+/// [`Sample`](crate::sample::Sample) acquired via
+/// [`Sample::into_owned()`](crate::sample::Sample::into_owned()). In contrast to the
+/// [`Sample`](crate::sample::Sample) it does not hold a slot in the shared memory of the
+/// [`Publisher`](crate::port::publisher::Publisher) and can outlive the
+/// [`Subscriber`](crate::port::subscriber::Subscriber).
+#[derive(Debug)]
+pub struct OwnedSample<Payload: Debug + ?Sized, UserHeader: Debug> {
+    pub(crate) header: Header,
+    pub(crate) user_header: UserHeader,
+    pub(crate) payload: Box<Payload>,
+}
+
+impl<Payload: Debug + ?Sized, UserHeader: Debug + Clone> Clone for OwnedSample<Payload, UserHeader>
+where
+    Box<Payload>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header,
+            user_header: self.user_header.clone(),
+            payload: self.payload.clone(),
+        }
+    }
+}
+
+impl<Payload: Debug + ?Sized, UserHeader: Debug> Deref for OwnedSample<Payload, UserHeader> {
+    type Target = Payload;
+    fn deref(&self) -> &Self::Target {
+        &self.payload
+    }
+}
+
+impl<Payload: Debug + ?Sized, UserHeader: Debug> OwnedSample<Payload, UserHeader> {
+    /// Returns a reference to the payload of the [`OwnedSample`]
+    pub fn payload(&self) -> &Payload {
+        &self.payload
+    }
+
+    /// Returns a reference to the user_header of the [`OwnedSample`]
+    pub fn user_header(&self) -> &UserHeader {
+        &self.user_header
+    }
+
+    /// Returns a reference to the [`Header`] of the [`OwnedSample`].
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
+    pub fn origin(&self) -> UniquePublisherId {
+        self.header.publisher_id()
+    }
+
+    /// Consumes the [`OwnedSample`] and returns the heap allocated payload.
+    pub fn into_payload(self) -> Box<Payload> {
+        self.payload
+    }
+}
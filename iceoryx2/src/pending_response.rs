@@ -243,7 +243,7 @@ impl<
     fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
         let client_shared_state = self.request.client_shared_state.lock();
         let msg = "Unable to receive response";
-        fail!(from self, when client_shared_state.update_connections(),
+        fail!(from client_shared_state.log_origin(self), when client_shared_state.update_connections(),
                 "{msg} since the connections could not be updated.");
 
         client_shared_state
@@ -0,0 +1,171 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::buffer_pool::BufferPool;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder().create()?;
+//! let pool = BufferPool::new(4, core::mem::size_of::<u64>());
+//!
+//! while let Some(buffer) = subscriber.receive_into_pool(&pool)? {
+//!     println!("received {} bytes", buffer.len());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::ops::Deref;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, Ordering};
+use iceoryx2_bb_concurrency::cell::UnsafeCell;
+
+/// A fixed set of equally sized, pre-allocated byte buffers. Received payloads can be copied
+/// into it with [`Subscriber::receive_into_pool()`](crate::port::subscriber::Subscriber::receive_into_pool)
+/// so that the shared memory is released immediately without allocating on the receive path.
+/// A buffer is returned to the pool when its [`PooledBuffer`] goes out of scope.
+pub struct BufferPool {
+    buffer_size: usize,
+    buffers: Vec<UnsafeCell<Vec<u8>>>,
+    is_in_use: Vec<AtomicBool>,
+}
+
+// SAFETY: a buffer is only accessed by the single [`PooledBuffer`] that acquired it via the
+// atomic `is_in_use` flag
+unsafe impl Send for BufferPool {}
+unsafe impl Sync for BufferPool {}
+
+impl Debug for BufferPool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "BufferPool {{ number_of_buffers: {}, buffer_size: {}, number_of_free_buffers: {} }}",
+            self.number_of_buffers(),
+            self.buffer_size,
+            self.number_of_free_buffers()
+        )
+    }
+}
+
+impl BufferPool {
+    /// Creates a new [`BufferPool`] with `number_of_buffers` buffers that can store
+    /// `buffer_size` bytes each. All memory is allocated here.
+    pub fn new(number_of_buffers: usize, buffer_size: usize) -> Self {
+        Self {
+            buffer_size,
+            buffers: (0..number_of_buffers)
+                .map(|_| UnsafeCell::new(vec![0u8; buffer_size]))
+                .collect(),
+            is_in_use: (0..number_of_buffers)
+                .map(|_| AtomicBool::new(false))
+                .collect(),
+        }
+    }
+
+    /// Returns the number of bytes a single buffer can store.
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Returns the total number of buffers.
+    pub fn number_of_buffers(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Returns the number of buffers that are currently not acquired.
+    pub fn number_of_free_buffers(&self) -> usize {
+        self.is_in_use
+            .iter()
+            .filter(|in_use| !in_use.load(Ordering::Relaxed))
+            .count()
+    }
+
+    pub(crate) fn acquire(&self) -> Option<PooledBuffer<'_>> {
+        self.is_in_use
+            .iter()
+            .position(|in_use| {
+                in_use
+                    .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            })
+            .map(|index| PooledBuffer {
+                pool: self,
+                index,
+                len: 0,
+            })
+    }
+}
+
+/// A buffer acquired from a [`BufferPool`] that contains a copy of a received payload. It
+/// dereferences to the payload bytes and returns itself to the [`BufferPool`] on drop.
+pub struct PooledBuffer<'pool> {
+    pool: &'pool BufferPool,
+    index: usize,
+    len: usize,
+}
+
+impl Debug for PooledBuffer<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "PooledBuffer {{ index: {}, len: {} }}",
+            self.index, self.len
+        )
+    }
+}
+
+impl PooledBuffer<'_> {
+    /// Returns the index of the buffer inside the [`BufferPool`].
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.pool.buffer_size
+    }
+
+    /// Copies `data` into the buffer. The caller must ensure that `data` fits into the buffer.
+    pub(crate) fn copy_from(&mut self, data: &[u8]) {
+        debug_assert!(data.len() <= self.capacity());
+        let buffer = unsafe { &mut *self.pool.buffers[self.index].get() };
+        buffer[..data.len()].copy_from_slice(data);
+        self.len = data.len();
+    }
+}
+
+impl Deref for PooledBuffer<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        let buffer = unsafe { &*self.pool.buffers[self.index].get() };
+        &buffer[..self.len]
+    }
+}
+
+impl Drop for PooledBuffer<'_> {
+    fn drop(&mut self) {
+        self.pool.is_in_use[self.index].store(false, Ordering::Release);
+    }
+}
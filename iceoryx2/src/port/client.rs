@@ -72,6 +72,7 @@
 //! # }
 //! ```
 
+use alloc::string::{String, ToString};
 use core::{any::TypeId, fmt::Debug, marker::PhantomData, mem::MaybeUninit};
 use iceoryx2_bb_container::{queue::Queue, slotmap::SlotMap, vector::polymorphic_vec::*};
 
@@ -92,10 +93,15 @@ use iceoryx2_cal::{
 use iceoryx2_log::{fail, fatal_panic, warn};
 
 use crate::active_request::RequestId;
+use crate::node::NodeLogOrigin;
 use crate::{
     identifiers::UniqueClientId,
     pending_response::PendingResponse,
-    port::{details::data_segment::DataSegment, update_connections::UpdateConnections},
+    port::{
+        details::data_segment::DataSegment,
+        unable_to_deliver_strategy::AtomicUnableToDeliverStrategy,
+        update_connections::UpdateConnections,
+    },
     prelude::{PortFactory, UnableToDeliverStrategy},
     raw_sample::RawSampleMut,
     request_mut::RequestMut,
@@ -185,6 +191,13 @@ impl<Service: service::Service> Drop for ClientSharedState<Service> {
 }
 
 impl<Service: service::Service> ClientSharedState<Service> {
+    pub(crate) fn log_origin<'a, T: Debug + ?Sized>(
+        &'a self,
+        origin: &'a T,
+    ) -> NodeLogOrigin<'a, T> {
+        self.request_sender.shared_node.log_origin(origin)
+    }
+
     fn prepare_channel_to_receive_responses(&self, channel_id: ChannelId, request_id: RequestId) {
         self.response_receiver
             .set_channel_state(channel_id, request_id);
@@ -208,11 +221,11 @@ impl<Service: service::Service> ClientSharedState<Service> {
             .max_active_requests_per_client
             <= active_request_counter
         {
-            fail!(from self, with RequestSendError::ExceedsMaxActiveRequests,
+            fail!(from self.log_origin(self), with RequestSendError::ExceedsMaxActiveRequests,
                     "{} since the number of active requests is limited to {} and sending this request would exceed the limit.", msg, active_request_counter);
         }
 
-        fail!(from self, when self.update_connections(),
+        fail!(from self.log_origin(self), when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
         self.prepare_channel_to_receive_responses(channel_id, request_id);
@@ -230,6 +243,8 @@ impl<Service: service::Service> ClientSharedState<Service> {
     pub(crate) fn update_connections(
         &self,
     ) -> Result<(), super::update_connections::ConnectionFailure> {
+        let reconnect_requested = self.request_sender.take_reconnect_request()
+            | self.response_receiver.take_reconnect_request();
         if unsafe {
             self.request_sender
                 .service_state
@@ -238,8 +253,9 @@ impl<Service: service::Service> ClientSharedState<Service> {
                 .request_response()
                 .servers
                 .update_state(&mut *self.server_list_state.get())
-        } {
-            fail!(from self, when self.force_update_connections(),
+        } || reconnect_requested
+        {
+            fail!(from self.log_origin(self), when self.force_update_connections(),
                 "Connections were updated only partially since at least one connection to a Server port failed.");
         }
 
@@ -291,7 +307,6 @@ impl<Service: service::Service> ClientSharedState<Service> {
 
 /// Sends [`RequestMut`]s to a [`Server`](crate::port::server::Server) in a
 /// request-response based communication.
-#[derive(Debug)]
 pub struct Client<
     Service: service::Service,
     RequestPayload: Debug + ZeroCopySend + ?Sized,
@@ -306,6 +321,32 @@ pub struct Client<
     _request_header: PhantomData<RequestHeader>,
     _response_payload: PhantomData<ResponsePayload>,
     _response_header: PhantomData<ResponseHeader>,
+    log_prefix: String,
+}
+
+impl<
+    Service: service::Service,
+    RequestPayload: Debug + ZeroCopySend + ?Sized,
+    RequestHeader: Debug + ZeroCopySend,
+    ResponsePayload: Debug + ZeroCopySend + ?Sized,
+    ResponseHeader: Debug + ZeroCopySend,
+> Debug for Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}Client<{}, {}, {}, {}, {}> {{ client_id: {:?}, client_shared_state: {:?}, request_id_counter: {:?} }}",
+            self.log_prefix,
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<RequestHeader>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseHeader>(),
+            self.client_id,
+            self.client_shared_state,
+            self.request_id_counter,
+        )
+    }
 }
 
 unsafe impl<
@@ -350,8 +391,8 @@ impl<
         >,
     ) -> Result<Self, ClientCreateError> {
         let msg = "Unable to create Client port";
-        let origin = "Client::new()";
         let service = &client_factory.factory.service;
+        let origin = service.shared_node.log_origin("Client::new()");
         let client_id = UniqueClientId::new();
         let static_config = client_factory.factory.static_config();
         let number_of_requests =
@@ -364,7 +405,6 @@ impl<
             .call(number_of_requests);
         let server_list = &service.dynamic_storage.get().request_response().servers;
 
-        let global_config = service.shared_node.config();
         let segment_name = data_segment_name(client_id.value());
         let data_segment_type = DataSegmentType::new_from_allocation_strategy(
             client_factory.config.allocation_strategy,
@@ -380,15 +420,17 @@ impl<
             DataSegmentType::Static => DataSegment::<Service>::create_static_segment(
                 &segment_name,
                 sample_layout,
-                global_config,
+                &service.shared_node,
                 number_of_requests,
+                None,
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
                 sample_layout,
-                global_config,
+                &service.shared_node,
                 number_of_requests,
                 client_factory.config.allocation_strategy,
+                None,
             ),
         };
 
@@ -426,13 +468,16 @@ impl<
             receiver_max_borrowed_samples: static_config.max_active_requests_per_client,
             enable_safe_overflow: static_config.enable_safe_overflow_for_requests,
             degradation_callback: client_factory.request_degradation_callback,
+            sample_reclaimed_callback: None,
             number_of_samples: number_of_requests,
             max_number_of_segments,
             service_state: service.clone(),
             tagger: CyclicTagger::new(),
             loan_counter: AtomicUsize::new(0),
             sender_max_borrowed_samples: static_config.max_loaned_requests,
-            unable_to_deliver_strategy: client_factory.config.unable_to_deliver_strategy,
+            unable_to_deliver_strategy: alloc::sync::Arc::new(AtomicUnableToDeliverStrategy::new(
+                client_factory.config.unable_to_deliver_strategy,
+            )),
             message_type_details: static_config.request_message_type_details,
             // all requests are sent via one channel, only the responses require different
             // channels to guarantee that one response does not fill the buffer of another
@@ -441,6 +486,8 @@ impl<
             // one channel suffices
             number_of_channels: 1,
             initial_channel_state: CHANNEL_STATE_OPEN,
+            reconnect_requested: AtomicBool::new(false),
+            detached_connections: UnsafeCell::new(alloc::vec::Vec::new()),
         };
 
         let number_of_to_be_removed_connections = service
@@ -468,7 +515,7 @@ impl<
                 PolymorphicVec::new(HeapAllocator::global(), number_of_to_be_removed_connections)
                     .expect("Heap allocator provides memory."),
             )),
-            degradation_callback: client_factory.response_degradation_callback,
+            degradation_callback: UnsafeCell::new(client_factory.response_degradation_callback),
             message_type_details: static_config.response_message_type_details,
             receiver_max_borrowed_samples: static_config
                 .max_borrowed_responses_per_pending_response,
@@ -476,6 +523,7 @@ impl<
             number_of_channels: number_of_requests,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
             initial_channel_state: CHANNEL_STATE_CLOSED,
+            reconnect_requested: AtomicBool::new(false),
         };
 
         let client_shared_state = Service::ArcThreadSafetyPolicy::new(ClientSharedState {
@@ -506,6 +554,7 @@ impl<
             request_id_counter: AtomicU64::new(0),
             client_shared_state,
             client_id,
+            log_prefix: service.shared_node.log_prefix().to_string(),
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -568,6 +617,7 @@ impl<
             .lock()
             .request_sender
             .unable_to_deliver_strategy
+            .load()
     }
 }
 
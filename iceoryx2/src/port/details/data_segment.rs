@@ -12,6 +12,10 @@
 
 use core::alloc::Layout;
 
+use alloc::sync::Arc;
+
+#[cfg(target_os = "linux")]
+use iceoryx2_bb_linux::numa::NumaNode;
 use iceoryx2_bb_posix::file::AccessMode;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::{
@@ -29,7 +33,7 @@ use iceoryx2_cal::{
 use iceoryx2_log::fail;
 
 use crate::{
-    config,
+    node::SharedNode,
     service::{
         self,
         config_scheme::{data_segment_config, resizable_data_segment_config},
@@ -55,6 +59,21 @@ impl DataSegmentType {
     }
 }
 
+/// Defines the failures that can occur when a [`DataSegment`] is created.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum DataSegmentCreateError {
+    /// The underlying shared memory could not be created.
+    SharedMemoryCreationFailed(SharedMemoryCreateError),
+    /// The data segment could not be bound to the requested NUMA node.
+    UnableToBindToNumaNode,
+}
+
+impl From<SharedMemoryCreateError> for DataSegmentCreateError {
+    fn from(value: SharedMemoryCreateError) -> Self {
+        DataSegmentCreateError::SharedMemoryCreationFailed(value)
+    }
+}
+
 #[derive(Debug)]
 enum MemoryType<Service: service::Service> {
     Static(Service::SharedMemory),
@@ -64,47 +83,77 @@ enum MemoryType<Service: service::Service> {
 #[derive(Debug)]
 pub(crate) struct DataSegment<Service: service::Service> {
     memory: MemoryType<Service>,
+    shared_node: Arc<SharedNode<Service>>,
 }
 
 impl<Service: service::Service> DataSegment<Service> {
+    /// Returns the number of bytes a segment requires to store `number_of_chunks` chunks with
+    /// the provided `chunk_layout`.
+    pub(crate) fn segment_size(chunk_layout: Layout, number_of_chunks: usize) -> usize {
+        chunk_layout.size() * number_of_chunks + chunk_layout.align() - 1
+    }
+
+    /// Creates a [`DataSegment`] that is allocated once. When a `numa_node` is provided, the
+    /// payload memory is bound to it right after the shared memory and its allocator are
+    /// created. Pages that were already touched during the creation are migrated to the node
+    /// with `MPOL_MF_MOVE`, all other pages are allocated from the node on first touch.
     pub(crate) fn create_static_segment(
         segment_name: &FileName,
         chunk_layout: Layout,
-        global_config: &config::Config,
+        shared_node: &Arc<SharedNode<Service>>,
         number_of_chunks: usize,
-    ) -> Result<Self, SharedMemoryCreateError> {
+        numa_node: Option<u32>,
+    ) -> Result<Self, DataSegmentCreateError> {
         let allocator_config = shm_allocator::pool_allocator::Config {
             bucket_layout: chunk_layout,
         };
         let msg = "Unable to create the static data segment since the underlying shared memory could not be created.";
-        let origin = "DataSegment::create_static_segment()";
+        let origin = shared_node.log_origin("DataSegment::create_static_segment()");
 
-        let segment_config = data_segment_config::<Service>(global_config);
+        let segment_config = data_segment_config::<Service>(shared_node.config());
         let memory = fail!(from origin,
                                 when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
                                 Service::SharedMemory,
                                     >>::new(segment_name)
                                     .config(&segment_config)
-                                    .size(chunk_layout.size() * number_of_chunks + chunk_layout.align() - 1)
+                                    .size(Self::segment_size(chunk_layout, number_of_chunks))
                                     .create(&allocator_config),
                                 "{msg}");
 
+        if let Some(id) = numa_node {
+            Self::bind_to_numa_node(
+                shared_node,
+                memory.payload_start_address(),
+                memory.size(),
+                id,
+            )?;
+        }
+
         Ok(Self {
             memory: MemoryType::Static(memory),
+            shared_node: shared_node.clone(),
         })
     }
 
+    /// Creates a [`DataSegment`] that can be resized. It cannot be bound to a NUMA node, since
+    /// its memory is acquired on demand, therefore it fails when a `numa_node` is provided.
     pub(crate) fn create_dynamic_segment(
         segment_name: &FileName,
         chunk_layout: Layout,
-        global_config: &config::Config,
+        shared_node: &Arc<SharedNode<Service>>,
         number_of_chunks: usize,
         allocation_strategy: AllocationStrategy,
-    ) -> Result<Self, SharedMemoryCreateError> {
-        let msg = "Unable to create the dynamic data segment since the underlying shared memory could not be created.";
-        let origin = "DataSegment::create_dynamic_segment()";
+        numa_node: Option<u32>,
+    ) -> Result<Self, DataSegmentCreateError> {
+        let msg = "Unable to create the dynamic data segment";
+        let origin = shared_node.log_origin("DataSegment::create_dynamic_segment()");
 
-        let segment_config = resizable_data_segment_config::<Service>(global_config);
+        if let Some(id) = numa_node {
+            fail!(from origin, with DataSegmentCreateError::UnableToBindToNumaNode,
+                "{msg} since it cannot be bound to the NUMA node {id}. Only a data segment with the allocation strategy AllocationStrategy::Static can be bound to a NUMA node.");
+        }
+
+        let segment_config = resizable_data_segment_config::<Service>(shared_node.config());
         let memory = fail!(from origin,
                     when <<Service::ResizableSharedMemory as ResizableSharedMemory<
                         PoolAllocator,
@@ -117,31 +166,67 @@ impl<Service: service::Service> DataSegment<Service> {
                     .max_chunk_layout_hint(chunk_layout)
                     .allocation_strategy(allocation_strategy)
                     .create(),
-                    "{msg}");
+                    "{msg} since the underlying shared memory could not be created.");
 
         Ok(Self {
             memory: MemoryType::Dynamic(memory),
+            shared_node: shared_node.clone(),
         })
     }
 
+    #[cfg(target_os = "linux")]
+    fn bind_to_numa_node(
+        shared_node: &SharedNode<Service>,
+        address: usize,
+        len: usize,
+        id: u32,
+    ) -> Result<(), DataSegmentCreateError> {
+        let msg = "Unable to bind the data segment to the NUMA node";
+        let origin = shared_node.log_origin("DataSegment::bind_to_numa_node()");
+
+        let numa_node = fail!(from origin, when NumaNode::new(id),
+            with DataSegmentCreateError::UnableToBindToNumaNode,
+            "{msg} {id} since the node could not be acquired.");
+
+        fail!(from origin, when unsafe { numa_node.bind_memory(address as *const _, len) },
+            with DataSegmentCreateError::UnableToBindToNumaNode,
+            "{msg} {id} since the memory could not be bound.");
+
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn bind_to_numa_node(
+        shared_node: &SharedNode<Service>,
+        _address: usize,
+        _len: usize,
+        id: u32,
+    ) -> Result<(), DataSegmentCreateError> {
+        fail!(from shared_node.log_origin("DataSegment::bind_to_numa_node()"),
+            with DataSegmentCreateError::UnableToBindToNumaNode,
+            "Unable to bind the data segment to the NUMA node {id} since NUMA is only supported on Linux.");
+    }
+
     pub(crate) fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
         let msg = "Unable to allocate memory from the data segment";
         match &self.memory {
-            MemoryType::Static(memory) => Ok(fail!(from self, when memory.allocate(layout),
-                                            "{msg}.")),
+            MemoryType::Static(memory) => Ok(
+                fail!(from self.shared_node.log_origin(self), when memory.allocate(layout),
+                                            "{msg}."),
+            ),
             MemoryType::Dynamic(memory) => match memory.allocate(layout) {
                 Ok(ptr) => Ok(ptr),
                 Err(ResizableShmAllocationError::ShmAllocationError(e)) => {
-                    fail!(from self, with e,
+                    fail!(from self.shared_node.log_origin(self), with e,
                         "{msg} caused by {:?}.", e);
                 }
                 Err(ResizableShmAllocationError::MaxReallocationsReached) => {
-                    fail!(from self,
+                    fail!(from self.shared_node.log_origin(self),
                         with ShmAllocationError::AllocationError(AllocationError::OutOfMemory),
                         "{msg} since the maxmimum number of reallocations was reached. Try to provide initial_max_slice_len({}) as hint when creating the publisher to have a more fitting initial setup.", layout.size());
                 }
                 Err(ResizableShmAllocationError::SharedMemoryCreateError(e)) => {
-                    fail!(from self,
+                    fail!(from self.shared_node.log_origin(self),
                         with ShmAllocationError::AllocationError(AllocationError::InternalError),
                         "{msg} since the shared memory segment creation failed while resizing the memory due to ({:?}).", e);
                 }
@@ -165,6 +250,21 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
+    pub(crate) fn data_segment_type(&self) -> DataSegmentType {
+        match &self.memory {
+            MemoryType::Static(_) => DataSegmentType::Static,
+            MemoryType::Dynamic(_) => DataSegmentType::Dynamic,
+        }
+    }
+
+    /// Returns the number of shared memory segments that are currently in use.
+    pub(crate) fn number_of_active_segments(&self) -> usize {
+        match &self.memory {
+            MemoryType::Static(_) => 1,
+            MemoryType::Dynamic(memory) => memory.number_of_active_segments(),
+        }
+    }
+
     pub(crate) fn max_number_of_segments(data_segment_type: DataSegmentType) -> u8 {
         match data_segment_type {
             DataSegmentType::Static => 1,
@@ -189,40 +289,42 @@ enum MemoryViewType<Service: service::Service> {
 #[derive(Debug)]
 pub(crate) struct DataSegmentView<Service: service::Service> {
     memory: MemoryViewType<Service>,
+    shared_node: Arc<SharedNode<Service>>,
 }
 
 impl<Service: service::Service> DataSegmentView<Service> {
     pub(crate) fn open_static_segment(
         segment_name: &FileName,
-        global_config: &config::Config,
+        shared_node: &Arc<SharedNode<Service>>,
     ) -> Result<Self, SharedMemoryOpenError> {
-        let origin = "DataSegment::open()";
+        let origin = shared_node.log_origin("DataSegment::open()");
         let msg =
             "Unable to open data segment since the underlying shared memory could not be opened.";
 
-        let segment_config = data_segment_config::<Service>(global_config);
+        let segment_config = data_segment_config::<Service>(shared_node.config());
         let memory = fail!(from origin,
                             when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
                                 Builder::new(segment_name)
                                 .config(&segment_config)
-                                .timeout(global_config.global.service.creation_timeout)
+                                .timeout(shared_node.config().global.service.creation_timeout)
                                 .open(AccessMode::Read),
                             "{msg}");
 
         Ok(Self {
             memory: MemoryViewType::Static(memory),
+            shared_node: shared_node.clone(),
         })
     }
 
     pub(crate) fn open_dynamic_segment(
         segment_name: &FileName,
-        global_config: &config::Config,
+        shared_node: &Arc<SharedNode<Service>>,
     ) -> Result<Self, SharedMemoryOpenError> {
-        let origin = "DataSegment::open()";
+        let origin = shared_node.log_origin("DataSegment::open()");
         let msg =
             "Unable to open data segment since the underlying shared memory could not be opened.";
 
-        let segment_config = resizable_data_segment_config::<Service>(global_config);
+        let segment_config = resizable_data_segment_config::<Service>(shared_node.config());
         let memory = fail!(from origin,
                     when <<Service::ResizableSharedMemory as ResizableSharedMemory<
                         PoolAllocator,
@@ -236,6 +338,7 @@ impl<Service: service::Service> DataSegmentView<Service> {
 
         Ok(Self {
             memory: MemoryViewType::Dynamic(memory),
+            shared_node: shared_node.clone(),
         })
     }
 
@@ -249,7 +352,7 @@ impl<Service: service::Service> DataSegmentView<Service> {
                 match memory.register_and_translate_offset(offset) {
                     Ok(ptr) => Ok(ptr as usize),
                     Err(e) => {
-                        fail!(from self, with e,
+                        fail!(from self.shared_node.log_origin(self), with e,
                             "Failed to register and translate pointer due to a failure while opening the corresponding shared memory segment ({:?}).",
                             e);
                     }
@@ -16,3 +16,6 @@ pub(crate) mod data_segment;
 pub(crate) mod receiver;
 pub(crate) mod segment_state;
 pub(crate) mod sender;
+pub(crate) mod slow_receiver_detector;
+pub(crate) mod token_bucket;
+pub(crate) mod write_tracker;
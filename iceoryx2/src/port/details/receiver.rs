@@ -13,6 +13,7 @@
 use alloc::format;
 use alloc::sync::Arc;
 
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, Ordering};
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_container::slotmap::SlotMap;
 use iceoryx2_bb_container::slotmap::SlotMapKey;
@@ -74,7 +75,7 @@ impl<Service: service::Service> Connection<Service> {
         );
 
         let global_config = this.service_state.shared_node.config();
-        let receiver = fail!(from this,
+        let receiver = fail!(from this.service_state.shared_node.log_origin(this),
                         when <Service::Connection as ZeroCopyConnection>::
                             Builder::new( &connection_name(sender_port_id, this.receiver_port_id))
                                     .config(&connection_config::<Service>(global_config))
@@ -92,14 +93,15 @@ impl<Service: service::Service> Connection<Service> {
         let segment_name = data_segment_name(sender_port_id);
         let data_segment = match data_segment_type {
             DataSegmentType::Static => {
-                DataSegmentView::open_static_segment(&segment_name, global_config)
-            }
-            DataSegmentType::Dynamic => {
-                DataSegmentView::open_dynamic_segment(&segment_name, global_config)
+                DataSegmentView::open_static_segment(&segment_name, &this.service_state.shared_node)
             }
+            DataSegmentType::Dynamic => DataSegmentView::open_dynamic_segment(
+                &segment_name,
+                &this.service_state.shared_node,
+            ),
         };
 
-        let data_segment = fail!(from this,
+        let data_segment = fail!(from this.service_state.shared_node.log_origin(this),
                                  when data_segment,
                                 "{} since the sender data segment could not be opened.", msg);
 
@@ -121,13 +123,14 @@ pub(crate) struct Receiver<Service: service::Service> {
     pub(crate) tagger: CyclicTagger,
     pub(crate) to_be_removed_connections:
         Option<UnsafeCell<PolymorphicVec<'static, SlotMapKey, HeapAllocator>>>,
-    pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
+    pub(crate) degradation_callback: UnsafeCell<Option<DegradationCallback<'static>>>,
     pub(crate) message_type_details: MessageTypeDetails,
     pub(crate) receiver_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
     pub(crate) number_of_channels: usize,
     pub(crate) connection_storage: UnsafeCell<SlotMap<Connection<Service>>>,
     pub(crate) initial_channel_state: ChannelState,
+    pub(crate) reconnect_requested: AtomicBool,
 }
 
 impl<Service: service::Service> Receiver<Service> {
@@ -142,7 +145,7 @@ impl<Service: service::Service> Receiver<Service> {
             match connection.receiver.release(chunk.offset, channel_id) {
                 Ok(()) => (),
                 Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
-                    error!(from self, "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
+                    error!(from self.service_state.shared_node.log_origin(self), "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
                 }
             }
         }
@@ -202,8 +205,7 @@ impl<Service: service::Service> Receiver<Service> {
         index: usize,
         sender_details: &SenderDetails,
     ) -> Result<(), ConnectionFailure> {
-        let connection_storage = unsafe { &mut *self.connection_storage.get() };
-        let key = connection_storage.insert(Connection::new(
+        let connection = Connection::new(
             self,
             sender_details.data_segment_type,
             sender_details.port_id,
@@ -211,11 +213,22 @@ impl<Service: service::Service> Receiver<Service> {
             sender_details.max_number_of_segments,
             &self.tagger,
             self.initial_channel_state,
-        )?);
+        )?;
+
+        if connection.receiver.has_reconnect_request() {
+            // the sender has not yet released the connection it detached, it is
+            // re-established with a later connection update
+            unsafe { *self.connections[index].get() = None };
+            self.reconnect_requested.store(true, Ordering::Relaxed);
+            return Ok(());
+        }
+
+        let connection_storage = unsafe { &mut *self.connection_storage.get() };
+        let key = connection_storage.insert(connection);
         let key = match key {
             Some(v) => v,
             None => {
-                fatal_panic!(from self, "This should never happen! Internal connection storage capacity exceeded.")
+                fatal_panic!(from self.service_state.shared_node.log_origin(self), "This should never happen! Internal connection storage capacity exceeded.")
             }
         };
 
@@ -248,7 +261,7 @@ impl<Service: service::Service> Receiver<Service> {
                         .push(key)
                         .is_err()
                     {
-                        warn!(from self,
+                        warn!(from self.service_state.shared_node.log_origin(self),
                             "Expired connection buffer exceeded. A sender disconnected with undelivered samples that will be discarded. Increase the expired connection buffer to mitigate the problem.");
                         connection_storage.remove(key);
                     }
@@ -289,6 +302,32 @@ impl<Service: service::Service> Receiver<Service> {
         false
     }
 
+    /// Requests a re-delivery of the history from every active connection and returns the
+    /// number of samples the senders announced to re-deliver.
+    pub(crate) fn request_resync(&self, channel_id: ChannelId) -> usize {
+        let mut number_of_resync_samples = 0;
+        self.for_each_active_connection(|connection| {
+            connection.receiver.request_resync(channel_id);
+            number_of_resync_samples +=
+                connection.receiver.number_of_resync_samples(channel_id) as usize;
+        });
+        number_of_resync_samples
+    }
+
+    pub(crate) fn for_each_active_connection<F: FnMut(&Connection<Service>)>(
+        &self,
+        mut callback: F,
+    ) {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        for connection_key in self.connections.iter() {
+            if let Some(connection_key) = unsafe { &*connection_key.get() } {
+                if let Some(connection) = connection_storage.get(*connection_key) {
+                    callback(connection);
+                }
+            }
+        }
+    }
+
     fn receive_from_connection(
         &self,
         connection: &Connection<Service>,
@@ -314,15 +353,18 @@ impl<Service: service::Service> Receiver<Service> {
                         Ok(offset) => offset,
                         Err(e) => {
                             if connection.data_segment.is_dynamic() {
-                                warn!(from self, "Lost a sample. This only happens in the dynamic use case when a sender has reallocated its data segment and gone out of scope before the receiver has mapped the realloacted data segment. To circumvent this, you could either use static memory or increase the initial max slice len.");
+                                warn!(from self.service_state.shared_node.log_origin(self), "Lost a sample. This only happens in the dynamic use case when a sender has reallocated its data segment and gone out of scope before the receiver has mapped the realloacted data segment. To circumvent this, you could either use static memory or increase the initial max slice len.");
                                 return Ok(None);
                             }
-                            fail!(from self, with ReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapSendersDataSegment(e)),
+                            fail!(from self.service_state.shared_node.log_origin(self), with ReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapSendersDataSegment(e)),
                                 "Unable to register and translate offset from sender {:?} since the received offset {:?} could not be registered and translated.",
                                 connection.sender_port_id, offset);
                         }
                     };
 
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::record_received_sample();
+
                     Ok(Some((
                         details,
                         Chunk::new(&self.message_type_details, offset),
@@ -330,7 +372,7 @@ impl<Service: service::Service> Receiver<Service> {
                 }
             },
             Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
-                fail!(from self, with ReceiveError::ExceedsMaxBorrows,
+                fail!(from self.service_state.shared_node.log_origin(self), with ReceiveError::ExceedsMaxBorrows,
                     "{} since it would exceed the maximum {} of borrowed samples.",
                     msg, connection.receiver.max_borrowed_samples());
             }
@@ -422,13 +464,46 @@ impl<Service: service::Service> Receiver<Service> {
         }
 
         if all_channels_exceed_max_borrows && active_channel_count != 0 {
-            fail!(from self, with ReceiveError::ExceedsMaxBorrows,
+            fail!(from self.service_state.shared_node.log_origin(self), with ReceiveError::ExceedsMaxBorrows,
                  "{msg} since every channel exceeds the max number of borrows.");
         }
 
         Ok(None)
     }
 
+    /// Returns `true` when a connection could not be established and the degradation callback
+    /// requested [`DegradationAction::Reconnect`] or when a sender requested to release and
+    /// re-establish its connection, so the connections must be updated again.
+    pub(crate) fn take_reconnect_request(&self) -> bool {
+        let mut reconnect_requested = self.reconnect_requested.swap(false, Ordering::Relaxed);
+        self.for_each_active_connection(|connection| {
+            reconnect_requested |= connection.receiver.has_reconnect_request();
+        });
+        reconnect_requested
+    }
+
+    fn has_borrowed_samples(&self, connection: &Connection<Service>) -> bool {
+        (0..self.number_of_channels)
+            .any(|id| connection.receiver.borrow_count(ChannelId::new(id)) != 0)
+    }
+
+    /// Releases a connection whose sender requested a reconnect as soon as no sample that
+    /// was received via it is borrowed anymore, since the sender reclaims all delivered
+    /// samples when the connection is released. Samples that are still queued are discarded.
+    fn release_connection_on_reconnect_request(&self, index: usize, connection_key: SlotMapKey) {
+        let connection_storage = unsafe { &mut *self.connection_storage.get() };
+        if let Some(connection) = connection_storage.get(connection_key) {
+            self.reconnect_requested.store(true, Ordering::Relaxed);
+            if self.has_borrowed_samples(connection) {
+                self.tagger.tag(connection);
+                return;
+            }
+
+            connection_storage.remove(connection_key);
+            unsafe { *self.connections[index].get() = None };
+        }
+    }
+
     pub(crate) fn start_update_connection_cycle(&self) {
         self.tagger.next_cycle();
     }
@@ -445,6 +520,11 @@ impl<Service: service::Service> Receiver<Service> {
             Some(connection_key) => match connection_storage.get(*connection_key) {
                 Some(connection) => {
                     let is_connected = connection.sender_port_id == sender_details.port_id;
+                    if is_connected && connection.receiver.has_reconnect_request() {
+                        self.release_connection_on_reconnect_request(index, *connection_key);
+                        return Ok(());
+                    }
+
                     if is_connected {
                         self.tagger.tag(connection);
                     }
@@ -459,38 +539,56 @@ impl<Service: service::Service> Receiver<Service> {
 
             match self.create(index, &sender_details) {
                 Ok(()) => Ok(()),
-                Err(e) => match &self.degradation_callback {
-                    None => {
-                        warn!(from self,
-                                "Unable to establish connection to new sender {:?}.",
-                                sender_details.port_id);
+                Err(e) => self.handle_failed_connection(sender_details.port_id, e),
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(crate) fn handle_failed_connection(
+        &self,
+        sender_port_id: u128,
+        error: ConnectionFailure,
+    ) -> Result<(), ConnectionFailure> {
+        match unsafe { &*self.degradation_callback.get() } {
+            None => {
+                warn!(from self.service_state.shared_node.log_origin(self),
+                        "Unable to establish connection to new sender {:?}.",
+                        sender_port_id);
+                Ok(())
+            }
+            Some(c) => {
+                match c.call(
+                    &self.service_state.static_config,
+                    sender_port_id,
+                    self.receiver_port_id(),
+                ) {
+                    DegradationAction::Ignore => Ok(()),
+                    DegradationAction::Warn => {
+                        warn!(from self.service_state.shared_node.log_origin(self), "Unable to establish connection to new sender {:?}.",
+                                sender_port_id);
                         Ok(())
                     }
-                    Some(c) => {
-                        match c.call(
-                            &self.service_state.static_config,
-                            sender_details.port_id,
-                            self.receiver_port_id(),
-                        ) {
-                            DegradationAction::Ignore => Ok(()),
-                            DegradationAction::Warn => {
-                                warn!(from self, "Unable to establish connection to new sender {:?}.",
-                                        sender_details.port_id);
-                                Ok(())
-                            }
-                            DegradationAction::Fail => {
-                                fail!(from self, with e, "Unable to establish connection to new sender {:?}.",
-                                        sender_details.port_id);
-                            }
-                        }
+                    DegradationAction::Fail => {
+                        fail!(from self.service_state.shared_node.log_origin(self), with error, "Unable to establish connection to new sender {:?}.",
+                                sender_port_id);
                     }
-                },
+                    DegradationAction::Reconnect => {
+                        warn!(from self.service_state.shared_node.log_origin(self), "Unable to establish connection to new sender {:?}. Retry with the next connection update.",
+                                sender_port_id);
+                        self.reconnect_requested.store(true, Ordering::Relaxed);
+                        Ok(())
+                    }
+                }
             }
-        } else {
-            Ok(())
         }
     }
 
+    pub(crate) fn set_degradation_callback(&self, callback: Option<DegradationCallback<'static>>) {
+        unsafe { *self.degradation_callback.get() = callback };
+    }
+
     pub(crate) fn finish_update_connection_cycle(&self) {
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
         for (n, connection_key) in self.connections.iter().enumerate() {
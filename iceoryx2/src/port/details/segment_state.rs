@@ -53,6 +53,17 @@ impl SegmentState {
             .fetch_add(1, Ordering::Relaxed)
     }
 
+    pub(crate) fn reference_count(&self, distance_to_chunk: usize) -> u64 {
+        self.sample_reference_counter[self.sample_index(distance_to_chunk)].load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn number_of_used_samples(&self) -> usize {
+        self.sample_reference_counter
+            .iter()
+            .filter(|counter| counter.load(Ordering::Relaxed) != 0)
+            .count()
+    }
+
     pub(crate) fn release_sample(&self, distance_to_chunk: usize) -> u64 {
         self.sample_reference_counter[self.sample_index(distance_to_chunk)]
             .fetch_sub(1, Ordering::Relaxed)
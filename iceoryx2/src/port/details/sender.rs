@@ -11,15 +11,18 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use core::alloc::Layout;
+use core::time::Duration;
 use iceoryx2_bb_concurrency::atomic::Ordering;
 
 use alloc::format;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
-use iceoryx2_bb_concurrency::atomic::AtomicUsize;
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64, AtomicUsize};
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_elementary::cyclic_tagger::*;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::shm_allocator::{AllocationError, PointerOffset, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
@@ -29,15 +32,19 @@ use iceoryx2_cal::zero_copy_connection::{
 use iceoryx2_log::{error, fail, fatal_panic, warn};
 
 use crate::node::SharedNode;
-use crate::port::{DegradationAction, DegradationCallback, LoanError, SendError};
+use crate::port::unable_to_deliver_strategy::AtomicUnableToDeliverStrategy;
+use crate::port::{
+    DegradationAction, DegradationCallback, LoanError, SampleReclaimedCallback, SendError,
+};
 use crate::prelude::UnableToDeliverStrategy;
 use crate::service::config_scheme::connection_config;
 use crate::service::static_config::message_type_details::{MessageTypeDetails, TypeVariant};
+use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::{NoResource, ServiceState};
 use crate::{service, service::naming_scheme::connection_name};
 
 use super::chunk::ChunkMut;
-use super::data_segment::DataSegment;
+use super::data_segment::{DataSegment, DataSegmentType};
 use super::segment_state::SegmentState;
 
 #[derive(Clone, Copy)]
@@ -50,6 +57,8 @@ pub(crate) struct ReceiverDetails {
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) receiver_port_id: u128,
+    pub(crate) delivered: AtomicU64,
+    pub(crate) dropped: AtomicU64,
     tag: Tag,
 }
 
@@ -73,12 +82,12 @@ impl<Service: service::Service> Connection<Service> {
             receiver_port_id, this.sender_port_id
         );
         if this.receiver_max_buffer_size < buffer_size {
-            fail!(from this, with ZeroCopyCreationError::IncompatibleBufferSize,
+            fail!(from this.shared_node.log_origin(this), with ZeroCopyCreationError::IncompatibleBufferSize,
                 "{} since the receiver buffer size {} exceeds the max receiver buffer size of {}.",
                 msg, buffer_size, this.receiver_max_buffer_size);
         }
 
-        let sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
+        let sender = fail!(from this.shared_node.log_origin(this), when <Service::Connection as ZeroCopyConnection>::
                         Builder::new( &connection_name(this.sender_port_id, receiver_port_id))
                                 .config(&connection_config::<Service>(this.shared_node.config()))
                                 .buffer_size(buffer_size)
@@ -95,6 +104,8 @@ impl<Service: service::Service> Connection<Service> {
         Ok(Self {
             sender,
             receiver_port_id,
+            delivered: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
             tag,
         })
     }
@@ -114,13 +125,16 @@ pub(crate) struct Sender<Service: service::Service> {
     pub(crate) number_of_samples: usize,
     pub(crate) max_number_of_segments: u8,
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
+    pub(crate) sample_reclaimed_callback: Option<SampleReclaimedCallback<'static>>,
     pub(crate) service_state: Arc<ServiceState<Service, NoResource>>,
     pub(crate) tagger: CyclicTagger,
     pub(crate) loan_counter: AtomicUsize,
-    pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
+    pub(crate) unable_to_deliver_strategy: Arc<AtomicUnableToDeliverStrategy>,
     pub(crate) message_type_details: MessageTypeDetails,
     pub(crate) number_of_channels: usize,
     pub(crate) initial_channel_state: ChannelState,
+    pub(crate) reconnect_requested: AtomicBool,
+    pub(crate) detached_connections: UnsafeCell<Vec<Connection<Service>>>,
 }
 
 impl<Service: service::Service> Sender<Service> {
@@ -137,6 +151,14 @@ impl<Service: service::Service> Sender<Service> {
         }
     }
 
+    #[allow(clippy::mut_from_ref)]
+    fn detached_connections_mut(&self) -> &mut Vec<Connection<Service>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut (*self.detached_connections.get())
+        }
+    }
+
     pub(crate) fn get_connection_id_of(&self, receiver_port_id: u128) -> Option<usize> {
         for i in 0..self.len() {
             if let Some(connection) = self.get(i) {
@@ -149,33 +171,66 @@ impl<Service: service::Service> Sender<Service> {
         None
     }
 
+    fn register_receiver_overflow(&self, connection_id: usize) {
+        if let MessagingPattern::PublishSubscribe(_) =
+            self.service_state.static_config.messaging_pattern
+        {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .increment_overflowed_samples(connection_id);
+        }
+    }
+
     fn deliver_offset_to_connection_impl(
         &self,
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
         connection_id: usize,
+        keep_blocking: Option<&dyn Fn() -> bool>,
     ) -> Result<usize, SendError> {
         let msg = "While delivering the sample:";
-        let deliver_call = match self.unable_to_deliver_strategy {
-            UnableToDeliverStrategy::Block => {
-                <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
-            }
-            UnableToDeliverStrategy::DiscardSample => {
-                <Service::Connection as ZeroCopyConnection>::Sender::try_send
-            }
-        };
-
         let mut number_of_recipients = 0;
         if let Some(connection) = self.get(connection_id) {
-            match deliver_call(&connection.sender, offset, sample_size, channel_id) {
+            let result =
+                match (keep_blocking, self.unable_to_deliver_strategy.load()) {
+                    (Some(keep_blocking), _) => connection.sender.blocking_send_while(
+                        offset,
+                        sample_size,
+                        channel_id,
+                        keep_blocking,
+                    ),
+                    (None, UnableToDeliverStrategy::Block) => connection
+                        .sender
+                        .blocking_send_while(offset, sample_size, channel_id, || {
+                            self.unable_to_deliver_strategy.load() == UnableToDeliverStrategy::Block
+                        }),
+                    (None, UnableToDeliverStrategy::DiscardSample) => {
+                        connection.sender.try_send(offset, sample_size, channel_id)
+                    }
+                };
+
+            match result {
+                Err(ZeroCopySendError::ReceiveBufferFull) if keep_blocking.is_some() => {
+                    connection.dropped.fetch_add(1, Ordering::Relaxed);
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::record_dropped_sample();
+                    fail!(from self.shared_node.log_origin(self), with SendError::Timeout,
+                        "{msg} {:?} to receiver {:?} the receive buffer was still full when the timeout expired and the offset was delivered only to a subset of receivers.", offset, connection.receiver_port_id);
+                }
                 Err(ZeroCopySendError::ReceiveBufferFull)
                 | Err(ZeroCopySendError::UsedChunkListFull) => {
                     /* causes no problem
-                     *   blocking_send => can never happen
+                     *   blocking_send => only when the strategy was switched to
+                     *                    DiscardSample while blocking
                      *   try_send => we tried and expect that the buffer is full
                      *
                      * */
+                    connection.dropped.fetch_add(1, Ordering::Relaxed);
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::record_dropped_sample();
                 }
                 Err(ZeroCopySendError::NoConnectedReceiver)
                 | Err(ZeroCopySendError::ChannelIsClosed) => {
@@ -184,39 +239,22 @@ impl<Service: service::Service> Sender<Service> {
                     // to subscribers that have disconnected
                 }
                 Err(ZeroCopySendError::InternalError) => {
-                    fail!(from self, with SendError::InternalError,
+                    fail!(from self.shared_node.log_origin(self), with SendError::InternalError,
                         "{msg} {:?} to receiver {:?} an internal mechanism failed and the offset was delivered only to a subset of receivers.", offset, connection.receiver_port_id);
                 }
-                Err(ZeroCopySendError::ConnectionCorrupted) => match &self.degradation_callback {
-                    Some(c) => match c.call(
-                        &self.service_state.static_config,
-                        self.sender_port_id,
-                        connection.receiver_port_id,
-                    ) {
-                        DegradationAction::Ignore => (),
-                        DegradationAction::Warn => {
-                            error!(from self,
-                                        "{msg} {:?} a corrupted connection was detected with receiver {:?}.",
-                                        offset, connection.receiver_port_id);
-                        }
-                        DegradationAction::Fail => {
-                            fail!(from self, with SendError::ConnectionCorrupted,
-                                        "{msg} {:?} a corrupted connection was detected with receiver {:?}.",
-                                        offset, connection.receiver_port_id);
-                        }
-                    },
-                    None => {
-                        error!(from self,
-                                    "{msg} {:?} a corrupted connection was detected with receiver {:?}.",
-                                    offset, connection.receiver_port_id);
-                    }
-                },
+                Err(ZeroCopySendError::ConnectionCorrupted) => {
+                    self.handle_corrupted_connection(connection_id, &format!("{msg} {offset:?}"))?;
+                }
                 Ok(overflow) => {
                     self.borrow_sample(offset);
                     number_of_recipients += 1;
+                    connection.delivered.fetch_add(1, Ordering::Relaxed);
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::record_sent_sample();
 
                     if let Some(old) = overflow {
-                        self.release_sample(old)
+                        self.release_sample(old);
+                        self.register_receiver_overflow(connection_id);
                     }
                 }
             }
@@ -224,6 +262,65 @@ impl<Service: service::Service> Sender<Service> {
         Ok(number_of_recipients)
     }
 
+    pub(crate) fn handle_corrupted_connection(
+        &self,
+        connection_id: usize,
+        msg: &str,
+    ) -> Result<(), SendError> {
+        let receiver_port_id = match self.get(connection_id) {
+            Some(connection) => connection.receiver_port_id,
+            None => return Ok(()),
+        };
+
+        match &self.degradation_callback {
+            Some(c) => match c.call(
+                &self.service_state.static_config,
+                self.sender_port_id,
+                receiver_port_id,
+            ) {
+                DegradationAction::Ignore => (),
+                DegradationAction::Warn => {
+                    error!(from self.shared_node.log_origin(self),
+                        "{msg} a corrupted connection was detected with receiver {:?}.",
+                        receiver_port_id);
+                }
+                DegradationAction::Fail => {
+                    fail!(from self.shared_node.log_origin(self), with SendError::ConnectionCorrupted,
+                        "{msg} a corrupted connection was detected with receiver {:?}.",
+                        receiver_port_id);
+                }
+                DegradationAction::Reconnect => {
+                    warn!(from self.shared_node.log_origin(self),
+                        "{msg} a corrupted connection was detected with receiver {:?}. The connection will be re-established as soon as the receiver has released it.",
+                        receiver_port_id);
+                    self.detach_connection(connection_id);
+                    self.reconnect_requested.store(true, Ordering::Relaxed);
+                }
+            },
+            None => {
+                error!(from self.shared_node.log_origin(self),
+                    "{msg} a corrupted connection was detected with receiver {:?}.",
+                    receiver_port_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` when a connection was detached with [`DegradationAction::Reconnect`]
+    /// and the connections must be updated to re-establish it.
+    pub(crate) fn take_reconnect_request(&self) -> bool {
+        self.reconnect_requested.swap(false, Ordering::Relaxed)
+    }
+
+    pub(crate) fn for_each_connection<F: FnMut(&Connection<Service>)>(&self, mut callback: F) {
+        for i in 0..self.len() {
+            if let Some(connection) = self.get(i) {
+                callback(connection);
+            }
+        }
+    }
+
     pub(crate) fn has_disconnect_hint(
         &self,
         channel_id: ChannelId,
@@ -269,7 +366,7 @@ impl<Service: service::Service> Sender<Service> {
         connection_id: usize,
     ) -> Result<usize, SendError> {
         self.retrieve_returned_samples();
-        self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, connection_id)
+        self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, connection_id, None)
     }
 
     pub(crate) fn deliver_offset(
@@ -277,17 +374,78 @@ impl<Service: service::Service> Sender<Service> {
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
+    ) -> Result<usize, SendError> {
+        self.deliver_offset_to_receivers(offset, sample_size, channel_id, &|_| true)
+    }
+
+    /// Delivers the offset like [`Sender::deliver_offset()`] but only to the receivers whose
+    /// port id is accepted by `accepts_receiver`.
+    pub(crate) fn deliver_offset_to_receivers(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        accepts_receiver: &dyn Fn(u128) -> bool,
     ) -> Result<usize, SendError> {
         self.retrieve_returned_samples();
 
         let mut number_of_recipients = 0;
         for i in 0..self.len() {
+            if !self.is_receiver_accepted(i, accepts_receiver) {
+                continue;
+            }
+
             number_of_recipients +=
-                self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, i)?;
+                self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, i, None)?;
         }
         Ok(number_of_recipients)
     }
 
+    /// Delivers the offset like [`Sender::deliver_offset_to_receivers()`] but independent of
+    /// the [`UnableToDeliverStrategy`] it waits up to `timeout` for a full receive buffer. When
+    /// the timeout expires, it returns [`SendError::Timeout`].
+    pub(crate) fn deliver_offset_with_timeout(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        timeout: Duration,
+        accepts_receiver: &dyn Fn(u128) -> bool,
+    ) -> Result<usize, SendError> {
+        let start = fail!(from self.shared_node.log_origin(self), when Time::now_with_clock(ClockType::Monotonic),
+            with SendError::InternalError,
+            "Unable to deliver the offset {:?} with a timeout since the monotonic clock could not be read.", offset);
+        let keep_blocking = || start.elapsed().is_ok_and(|elapsed| elapsed < timeout);
+
+        self.retrieve_returned_samples();
+
+        let mut number_of_recipients = 0;
+        for i in 0..self.len() {
+            if !self.is_receiver_accepted(i, accepts_receiver) {
+                continue;
+            }
+
+            number_of_recipients += self.deliver_offset_to_connection_impl(
+                offset,
+                sample_size,
+                channel_id,
+                i,
+                Some(&keep_blocking),
+            )?;
+        }
+        Ok(number_of_recipients)
+    }
+
+    fn is_receiver_accepted(
+        &self,
+        connection_id: usize,
+        accepts_receiver: &dyn Fn(u128) -> bool,
+    ) -> bool {
+        self.get(connection_id)
+            .as_ref()
+            .is_some_and(|connection| accepts_receiver(connection.receiver_port_id))
+    }
+
     pub(crate) fn return_loaned_sample(&self, distance_to_chunk: PointerOffset) {
         self.release_sample(distance_to_chunk);
         self.loan_counter.fetch_sub(1, Ordering::Relaxed);
@@ -319,34 +477,42 @@ impl<Service: service::Service> Sender<Service> {
         let msg = "Unable to allocate data";
 
         if self.loan_counter.load(Ordering::Relaxed) >= self.sender_max_borrowed_samples {
-            fail!(from self, with LoanError::ExceedsMaxLoans,
+            fail!(from self.shared_node.log_origin(self), with LoanError::ExceedsMaxLoans,
                 "{} {:?} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
                 msg, layout, self.loan_counter.load(Ordering::Relaxed), self.sender_max_borrowed_samples);
         }
 
         let shm_pointer = match self.data_segment.allocate(layout) {
             Ok(chunk) => chunk,
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory))
+                if self.data_segment.data_segment_type() == DataSegmentType::Static =>
+            {
+                fail!(from self.shared_node.log_origin(self), with LoanError::SegmentFull,
+                    "{} {:?} since every slot of the data segment is in use.", msg, layout);
+            }
             Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
-                fail!(from self, with LoanError::OutOfMemory,
+                fail!(from self.shared_node.log_origin(self), with LoanError::OutOfMemory,
                     "{} {:?} since the underlying shared memory is out of memory.", msg, layout);
             }
             Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
             | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
-                fatal_panic!(from self, "{} {:?} since the system seems to be corrupted.", msg, layout);
+                fatal_panic!(from self.shared_node.log_origin(self), "{} {:?} since the system seems to be corrupted.", msg, layout);
             }
             Err(v) => {
-                fail!(from self, with LoanError::InternalFailure,
+                fail!(from self.shared_node.log_origin(self), with LoanError::InternalFailure,
                     "{} {:?} since an internal failure occurred ({:?}).", msg, layout, v);
             }
         };
 
         let (ref_count, sample_size) = self.borrow_sample(shm_pointer.offset);
         if ref_count != 0 {
-            fatal_panic!(from self,
+            fatal_panic!(from self.shared_node.log_origin(self),
                 "{} since the allocated sample is already in use! This should never happen!", msg);
         }
 
         self.loan_counter.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_loaned_sample();
         Ok(ChunkMut::new(
             &self.message_type_details,
             shm_pointer,
@@ -368,24 +534,43 @@ impl<Service: service::Service> Sender<Service> {
     pub(crate) fn retrieve_returned_samples(&self) {
         for i in 0..self.len() {
             if let Some(connection) = self.get(i) {
-                for channel_id in 0..self.number_of_channels {
-                    let id = ChannelId::new(channel_id);
-                    loop {
-                        match connection.sender.reclaim(id) {
-                            Ok(Some(ptr_dist)) => {
-                                self.release_sample(ptr_dist);
-                            }
-                            Ok(None) => break,
-                            Err(e) => {
-                                warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
-                            }
-                        }
+                self.retrieve_returned_samples_from(connection);
+            }
+        }
+
+        for connection in self.detached_connections_mut().iter() {
+            self.retrieve_returned_samples_from(connection);
+        }
+    }
+
+    fn retrieve_returned_samples_from(&self, connection: &Connection<Service>) {
+        for channel_id in 0..self.number_of_channels {
+            let id = ChannelId::new(channel_id);
+            loop {
+                match connection.sender.reclaim(id) {
+                    Ok(Some(ptr_dist)) => {
+                        self.release_sample(ptr_dist);
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        warn!(from self.shared_node.log_origin(self), "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
                     }
                 }
             }
         }
     }
 
+    pub(crate) fn reference_count(&self, offset: PointerOffset) -> u64 {
+        self.segment_states[offset.segment_id().value() as usize].reference_count(offset.offset())
+    }
+
+    pub(crate) fn number_of_used_samples(&self) -> usize {
+        self.segment_states
+            .iter()
+            .map(|state| state.number_of_used_samples())
+            .sum()
+    }
+
     pub(crate) fn release_sample(&self, offset: PointerOffset) {
         if self.segment_states[offset.segment_id().value() as usize].release_sample(offset.offset())
             == 1
@@ -400,16 +585,68 @@ impl<Service: service::Service> Sender<Service> {
         if let Some(connection) = self.get(i) {
             // # SAFETY: the receiver no longer exist, therefore we can
             //           reacquire all delivered samples
-            unsafe {
-                connection
-                    .sender
-                    .acquire_used_offsets(|offset| self.release_sample(offset))
-            };
-
+            unsafe { self.reclaim_used_offsets(connection) };
             *self.get_mut(i) = None;
         }
     }
 
+    /// # Safety
+    ///
+    ///  * the receiver must no longer use the connection
+    unsafe fn reclaim_used_offsets(&self, connection: &Connection<Service>) {
+        let mut number_of_reclaimed_samples = 0;
+        unsafe {
+            connection.sender.acquire_used_offsets(|offset| {
+                number_of_reclaimed_samples += 1;
+                self.release_sample(offset)
+            })
+        };
+
+        if let Some(callback) = &self.sample_reclaimed_callback {
+            if number_of_reclaimed_samples > 0 {
+                callback.call(number_of_reclaimed_samples);
+            }
+        }
+    }
+
+    /// Removes the connection from the active connections without reclaiming the samples
+    /// that were delivered via it, since the receiver may still read them. The receiver is
+    /// asked to release the connection and the samples it returns in the meantime are
+    /// retrieved as usual. The delivered samples are reclaimed when the receiver has released
+    /// the connection or is gone.
+    fn detach_connection(&self, i: usize) {
+        if let Some(connection) = self.get_mut(i).take() {
+            connection.sender.request_reconnect();
+            self.detached_connections_mut().push(connection);
+        }
+    }
+
+    /// Releases the detached connection to the receiver when the receiver no longer uses it.
+    /// Returns `false` when the receiver still uses it, so that no new connection can be
+    /// established yet.
+    fn release_detached_connection(&self, receiver_port_id: u128) -> bool {
+        let detached_connections = self.detached_connections_mut();
+        let index = match detached_connections
+            .iter()
+            .position(|connection| connection.receiver_port_id == receiver_port_id)
+        {
+            Some(index) => index,
+            None => return true,
+        };
+
+        let connection = &detached_connections[index];
+        if connection.sender.is_connected() {
+            self.tagger.tag(connection);
+            return false;
+        }
+
+        // # SAFETY: the receiver has released the connection, therefore we can
+        //           reacquire all delivered samples
+        unsafe { self.reclaim_used_offsets(connection) };
+        detached_connections.remove(index);
+        true
+    }
+
     pub(crate) fn start_update_connection_cycle(&self) {
         self.tagger.next_cycle();
     }
@@ -433,12 +670,19 @@ impl<Service: service::Service> Sender<Service> {
             }
         };
 
+        if create_connection && !self.release_detached_connection(receiver_details.port_id) {
+            // the receiver still uses the detached connection, it is re-established with a
+            // later connection update
+            self.reconnect_requested.store(true, Ordering::Relaxed);
+            return Ok(());
+        }
+
         if create_connection {
             match self.create(index, receiver_details) {
                 Ok(()) => match &self.get(index) {
                     Some(connection) => establish_new_connection_call(connection),
                     None => {
-                        fatal_panic!(from self, "This should never happen! Unable to acquire previously created receiver connection.")
+                        fatal_panic!(from self.shared_node.log_origin(self), "This should never happen! Unable to acquire previously created receiver connection.")
                     }
                 },
                 Err(e) => match &self.degradation_callback {
@@ -449,18 +693,24 @@ impl<Service: service::Service> Sender<Service> {
                     ) {
                         DegradationAction::Ignore => (),
                         DegradationAction::Warn => {
-                            warn!(from self,
+                            warn!(from self.shared_node.log_origin(self),
                                             "Unable to establish connection to new receiver {:?}.",
                                             receiver_details.port_id )
                         }
                         DegradationAction::Fail => {
-                            fail!(from self, with e,
+                            fail!(from self.shared_node.log_origin(self), with e,
                                            "Unable to establish connection to new receiver {:?}.",
                                            receiver_details.port_id );
                         }
+                        DegradationAction::Reconnect => {
+                            warn!(from self.shared_node.log_origin(self),
+                                            "Unable to establish connection to new receiver {:?}. Retry with the next connection update.",
+                                            receiver_details.port_id );
+                            self.reconnect_requested.store(true, Ordering::Relaxed);
+                        }
                     },
                     None => {
-                        warn!(from self,
+                        warn!(from self.shared_node.log_origin(self),
                                         "Unable to establish connection to new receiver {:?}.",
                                         receiver_details.port_id )
                     }
@@ -479,6 +729,17 @@ impl<Service: service::Service> Sender<Service> {
                 }
             }
         }
+
+        self.detached_connections_mut().retain(|connection| {
+            if connection.was_tagged_by(&self.tagger) {
+                return true;
+            }
+
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered samples
+            unsafe { self.reclaim_used_offsets(connection) };
+            false
+        });
     }
 
     pub(crate) fn payload_size(&self) -> usize {
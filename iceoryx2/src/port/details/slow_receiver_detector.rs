@@ -0,0 +1,137 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+#[derive(Debug)]
+struct ReceiverState {
+    receiver_port_id: u128,
+    non_empty_since: Option<Duration>,
+    last_report: Option<Duration>,
+    last_update: Duration,
+}
+
+/// Detects receivers that fall behind. A receiver is considered slow when its buffer was not
+/// empty at every update for longer than the threshold. A slow receiver is reported at most
+/// once per threshold. The current time must be provided by a monotonic clock.
+#[derive(Debug)]
+pub(crate) struct SlowReceiverDetector {
+    threshold: Duration,
+    receivers: Vec<ReceiverState>,
+}
+
+impl SlowReceiverDetector {
+    pub(crate) fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            receivers: Vec::new(),
+        }
+    }
+
+    /// Returns the duration after which a receiver is considered slow.
+    pub(crate) fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// Updates the buffer state of the receiver and returns true when it shall be reported
+    /// as slow.
+    pub(crate) fn update(
+        &mut self,
+        receiver_port_id: u128,
+        has_pending_samples: bool,
+        now: Duration,
+    ) -> bool {
+        let receiver = match self
+            .receivers
+            .iter_mut()
+            .find(|r| r.receiver_port_id == receiver_port_id)
+        {
+            Some(receiver) => receiver,
+            None => {
+                self.receivers.push(ReceiverState {
+                    receiver_port_id,
+                    non_empty_since: None,
+                    last_report: None,
+                    last_update: now,
+                });
+                self.receivers.last_mut().unwrap()
+            }
+        };
+        receiver.last_update = now;
+
+        if !has_pending_samples {
+            receiver.non_empty_since = None;
+            return false;
+        }
+
+        let non_empty_since = *receiver.non_empty_since.get_or_insert(now);
+        if now.saturating_sub(non_empty_since) < self.threshold {
+            return false;
+        }
+
+        match receiver.last_report {
+            Some(last_report) if now.saturating_sub(last_report) < self.threshold => false,
+            _ => {
+                receiver.last_report = Some(now);
+                true
+            }
+        }
+    }
+
+    /// Forgets all receivers that were not updated at `now`, meaning they are no longer
+    /// connected.
+    pub(crate) fn remove_receivers_not_updated_at(&mut self, now: Duration) {
+        self.receivers.retain(|r| r.last_update == now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    const RECEIVER: u128 = 123;
+    const THRESHOLD: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn slow_receiver_detector_reports_stalled_receiver_after_threshold() {
+        let start = Duration::from_secs(10);
+        let mut sut = SlowReceiverDetector::new(THRESHOLD);
+
+        assert_that!(sut.update(RECEIVER, true, start), eq false);
+        assert_that!(sut.update(RECEIVER, true, start + THRESHOLD / 2), eq false);
+        assert_that!(sut.update(RECEIVER, true, start + THRESHOLD), eq true);
+    }
+
+    #[test]
+    fn slow_receiver_detector_reports_at_most_once_per_threshold() {
+        let start = Duration::from_secs(10);
+        let mut sut = SlowReceiverDetector::new(THRESHOLD);
+
+        assert_that!(sut.update(RECEIVER, true, start), eq false);
+        assert_that!(sut.update(RECEIVER, true, start + THRESHOLD), eq true);
+        assert_that!(sut.update(RECEIVER, true, start + THRESHOLD * 3 / 2), eq false);
+        assert_that!(sut.update(RECEIVER, true, start + THRESHOLD * 2), eq true);
+    }
+
+    #[test]
+    fn slow_receiver_detector_does_not_report_receiver_that_empties_its_buffer() {
+        let start = Duration::from_secs(10);
+        let mut sut = SlowReceiverDetector::new(THRESHOLD);
+
+        assert_that!(sut.update(RECEIVER, true, start), eq false);
+        assert_that!(sut.update(RECEIVER, false, start + THRESHOLD / 2), eq false);
+        assert_that!(sut.update(RECEIVER, true, start + THRESHOLD), eq false);
+        assert_that!(sut.update(RECEIVER, true, start + THRESHOLD * 2), eq true);
+    }
+}
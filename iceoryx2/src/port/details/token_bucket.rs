@@ -0,0 +1,119 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Limits the number of operations per second. The bucket holds up to `tokens_per_second`
+/// tokens and starts full, so a burst of that size is allowed. Tokens are refilled
+/// proportionally to the elapsed time, which must be provided by a monotonic clock. The
+/// refill starts with the first acquired token.
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    tokens_per_second: u64,
+    available_tokens: u64,
+    last_refill: Duration,
+}
+
+impl TokenBucket {
+    /// Creates a full [`TokenBucket`]. `tokens_per_second` must be greater than zero.
+    pub(crate) fn new(tokens_per_second: u64) -> Self {
+        debug_assert!(tokens_per_second > 0);
+
+        Self {
+            tokens_per_second,
+            available_tokens: tokens_per_second,
+            last_refill: Duration::ZERO,
+        }
+    }
+
+    /// Returns the maximum number of tokens that can be acquired per second.
+    pub(crate) fn tokens_per_second(&self) -> u64 {
+        self.tokens_per_second
+    }
+
+    /// Consumes a token. If no token is available, it returns the [`Duration`] until the next
+    /// token becomes available.
+    pub(crate) fn try_acquire(&mut self, now: Duration) -> Result<(), Duration> {
+        self.refill(now);
+
+        if self.available_tokens > 0 {
+            self.available_tokens -= 1;
+            return Ok(());
+        }
+
+        let elapsed = now.saturating_sub(self.last_refill).as_nanos();
+        let refill_interval = NANOSECONDS_PER_SECOND.div_ceil(self.tokens_per_second as u128);
+        Err(Duration::from_nanos(
+            refill_interval.saturating_sub(elapsed).max(1) as u64,
+        ))
+    }
+
+    fn refill(&mut self, now: Duration) {
+        let elapsed = now.saturating_sub(self.last_refill).as_nanos();
+        let new_tokens = elapsed * self.tokens_per_second as u128 / NANOSECONDS_PER_SECOND;
+        if new_tokens == 0 {
+            return;
+        }
+
+        let available_tokens = self.available_tokens as u128 + new_tokens;
+        if available_tokens >= self.tokens_per_second as u128 {
+            self.available_tokens = self.tokens_per_second;
+            self.last_refill = now;
+        } else {
+            self.available_tokens = available_tokens as u64;
+            // keep the fraction of the next token that was already accumulated
+            self.last_refill += Duration::from_nanos(
+                (new_tokens * NANOSECONDS_PER_SECOND / self.tokens_per_second as u128) as u64,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn token_bucket_allows_a_burst_of_tokens_per_second() {
+        let start = Duration::from_secs(10);
+        let mut sut = TokenBucket::new(4);
+
+        for _ in 0..4 {
+            assert_that!(sut.try_acquire(start), is_ok);
+        }
+
+        assert_that!(sut.try_acquire(start), eq Err(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn token_bucket_refills_proportionally_to_elapsed_time() {
+        let start = Duration::from_secs(10);
+        let mut sut = TokenBucket::new(4);
+
+        for _ in 0..4 {
+            assert_that!(sut.try_acquire(start), is_ok);
+        }
+
+        let now = start + Duration::from_millis(300);
+        assert_that!(sut.try_acquire(now), is_ok);
+        assert_that!(sut.try_acquire(now), eq Err(Duration::from_millis(200)));
+
+        let now = start + Duration::from_secs(5);
+        for _ in 0..4 {
+            assert_that!(sut.try_acquire(now), is_ok);
+        }
+        assert_that!(sut.try_acquire(now), is_err);
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Tracks which payload elements of a loaned sample were written. Every element starts
+/// unwritten and is marked when it is written via one of the tracked write operations.
+#[derive(Debug)]
+pub(crate) struct WriteTracker {
+    is_written: Vec<bool>,
+}
+
+impl WriteTracker {
+    /// Creates a [`WriteTracker`] for a payload with `number_of_elements` unwritten elements.
+    pub(crate) fn new(number_of_elements: usize) -> Self {
+        Self {
+            is_written: vec![false; number_of_elements],
+        }
+    }
+
+    /// Marks the element at `index` as written.
+    pub(crate) fn mark_as_written(&mut self, index: usize) {
+        if let Some(is_written) = self.is_written.get_mut(index) {
+            *is_written = true;
+        }
+    }
+
+    /// Marks all elements as written.
+    pub(crate) fn mark_all_as_written(&mut self) {
+        self.is_written.fill(true);
+    }
+
+    /// Marks all elements as unwritten.
+    pub(crate) fn mark_all_as_unwritten(&mut self) {
+        self.is_written.fill(false);
+    }
+
+    /// Returns the index of the first element that was never written.
+    pub(crate) fn first_unwritten_element(&self) -> Option<usize> {
+        self.is_written.iter().position(|is_written| !is_written)
+    }
+}
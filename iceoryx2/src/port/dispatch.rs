@@ -0,0 +1,79 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::dispatch::{Dispatchable, EnumVisitor};
+//!
+//! #[derive(Debug, ZeroCopySend)]
+//! #[repr(C)]
+//! enum Command {
+//!     Start(u64),
+//!     Stop,
+//! }
+//!
+//! #[derive(Default)]
+//! struct CommandHandler {
+//!     started: u64,
+//!     stopped: u64,
+//! }
+//!
+//! impl EnumVisitor for CommandHandler {}
+//!
+//! impl Dispatchable for Command {
+//!     type Visitor = CommandHandler;
+//!
+//!     fn dispatch(&self, _user_header: &(), visitor: &mut CommandHandler) {
+//!         match self {
+//!             Command::Start(value) => visitor.started += value,
+//!             Command::Stop => visitor.stopped += 1,
+//!         }
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<Command>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder().create()?;
+//! let mut handler = CommandHandler::default();
+//!
+//! while subscriber.receive_dispatch(&mut handler)? {}
+//! # Ok(())
+//! # }
+//! ```
+
+/// Provides the handlers for every variant of a [`Dispatchable`] payload. It is called by
+/// [`Subscriber::receive_dispatch()`](crate::port::subscriber::Subscriber::receive_dispatch())
+/// for every received [`Sample`](crate::sample::Sample).
+pub trait EnumVisitor {
+    /// Is called by [`Dispatchable::dispatch()`] when the discriminant does not correspond to
+    /// any known variant. This can happen when the discriminant is stored in the user header
+    /// and the sender wrote a value the receiver does not understand. Does nothing by default.
+    fn on_unknown_variant(&mut self) {}
+}
+
+/// A payload that consists of multiple variants, identified by a discriminant stored either in
+/// the payload itself or in the user header. It routes a received
+/// [`Sample`](crate::sample::Sample) to the handler of the corresponding variant of its
+/// [`EnumVisitor`]. The wire format of the payload is not affected.
+pub trait Dispatchable<UserHeader = ()> {
+    /// The [`EnumVisitor`] that provides a handler for every variant.
+    type Visitor: EnumVisitor;
+
+    /// Calls the handler of `visitor` that corresponds to the variant of `self`.
+    fn dispatch(&self, user_header: &UserHeader, visitor: &mut Self::Visitor);
+}
@@ -58,6 +58,7 @@
 //! # }
 //! ```
 
+use core::fmt::Debug;
 use core::time::Duration;
 use iceoryx2_bb_concurrency::atomic::Ordering;
 
@@ -107,7 +108,6 @@ impl core::fmt::Display for ListenerCreateError {
 impl core::error::Error for ListenerCreateError {}
 
 /// Represents the receiving endpoint of an event based communication.
-#[derive(Debug)]
 pub struct Listener<Service: service::Service> {
     dynamic_listener_handle: Option<ContainerHandle>,
     listener:
@@ -116,6 +116,18 @@ pub struct Listener<Service: service::Service> {
     listener_id: UniqueListenerId,
 }
 
+impl<Service: service::Service> Debug for Listener<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.service_state.shared_node.log_prefix())?;
+        f.debug_struct("Listener")
+            .field("dynamic_listener_handle", &self.dynamic_listener_handle)
+            .field("listener", &self.listener)
+            .field("service_state", &self.service_state)
+            .field("listener_id", &self.listener_id)
+            .finish()
+    }
+}
+
 unsafe impl<Service: service::Service> Send for Listener<Service> where
     Service::ArcThreadSafetyPolicy<<Service::Event as iceoryx2_cal::event::Event>::Listener>:
         Send + Sync
@@ -162,7 +174,7 @@ impl<Service: service::Service> Listener<Service> {
         service: Arc<ServiceState<Service, NoResource>>,
     ) -> Result<Self, ListenerCreateError> {
         let msg = "Failed to create listener";
-        let origin = "Listener::new()";
+        let origin = service.shared_node.log_origin("Listener::new()");
         let listener_id = UniqueListenerId::new();
 
         let event_name = event_concept_name(&listener_id);
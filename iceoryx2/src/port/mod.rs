@@ -18,22 +18,35 @@ use update_connections::ConnectionFailure;
 pub(crate) mod details;
 pub use details::data_segment::DataSegmentType;
 
+/// Fixed pool of byte buffers to receive payload copies without allocation.
+pub mod buffer_pool;
 /// Sends requests to a [`Server`](crate::port::server::Server) and receives responses.
 pub mod client;
+/// Routes received enum payloads to the handler of their variant.
+pub mod dispatch;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
 /// Receiving endpoint (port) for event based communication
 pub mod listener;
+/// Sends the same payload with multiple [`Publisher`](crate::port::publisher::Publisher)s.
+pub mod multi_publisher;
 /// Sending endpoint (port) for event based communication
 pub mod notifier;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
 /// Reading endpoint (port) for blackboard based communication
 pub mod reader;
+/// Sending and receiving endpoints (ports) for serialized communication of types that are not
+/// [`ZeroCopySend`](iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend)
+pub mod serialized;
 /// Receives requests from a [`Client`](crate::port::client::Client) port and sends back responses.
 pub mod server;
+/// Sending and receiving endpoints (ports) for payload free signal based communication
+pub mod signal;
 /// Receiving endpoint (port) for publish-subscribe based communication
 pub mod subscriber;
+#[doc(hidden)]
+pub mod testing;
 /// Interface to perform cyclic updates to the ports. Required to deliver history to new
 /// participants or to perform other management tasks.
 pub mod update_connections;
@@ -57,6 +70,14 @@ pub enum DegradationAction {
     Warn,
     /// Returns a failure in the function the degradation was detected
     Fail,
+    /// Stops delivering via the affected connection and asks the receiver to release it. The
+    /// receiver releases it as soon as it no longer holds a sample that was received via it,
+    /// samples that are still queued are discarded. The samples that were delivered via the
+    /// connection are reclaimed when the receiver has released it or is gone, afterwards the
+    /// connection is re-established with the next connection update.
+    /// If the [`Service`](crate::service::Service) retains a history, it is replayed to the
+    /// receiver of the re-established connection.
+    Reconnect,
 }
 
 tiny_fn! {
@@ -72,6 +93,20 @@ impl Debug for DegradationCallback<'_> {
     }
 }
 
+tiny_fn! {
+    /// Called with the number of samples a port reclaimed from a receiver that disconnected
+    /// without consuming or releasing them.
+    pub struct SampleReclaimedCallback = Fn(number_of_reclaimed_samples: usize);
+}
+
+unsafe impl Send for SampleReclaimedCallback<'_> {}
+
+impl Debug for SampleReclaimedCallback<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "")
+    }
+}
+
 /// Defines a failure that can occur in
 /// [`Publisher::loan()`](crate::port::publisher::Publisher::loan()) and
 /// [`Publisher::loan_uninit()`](crate::port::publisher::Publisher::loan_uninit())
@@ -79,8 +114,15 @@ impl Debug for DegradationCallback<'_> {
 /// [`Publisher::send_copy()`](crate::port::publisher::Publisher::send_copy()).
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum LoanError {
-    /// The data segment does not have any more memory left
+    /// The data segment does not have any more memory left and cannot be grown any further
     OutOfMemory,
+    /// Every slot of the static data segment is in use, for instance because slow
+    /// subscribers still hold the samples. Becomes available again as soon as samples
+    /// are released. It is reported by every port with a static data segment, the
+    /// [`Publisher`](crate::port::publisher::Publisher) as well as the
+    /// [`Client`](crate::port::client::Client) and the
+    /// [`Server`](crate::port::server::Server) when loaning a request or a response.
+    SegmentFull,
     /// The maximum amount of data a user can borrow is
     /// defined in [`crate::config::Config`]. When this is exceeded those calls will fail.
     ExceedsMaxLoans,
@@ -114,6 +156,19 @@ pub enum SendError {
     ConnectionError(ConnectionFailure),
     /// An internal mechanisms failed and the data could not be delivered to all receivers.
     InternalError,
+    /// The maximum send rate of the [`Publisher`](crate::port::publisher::Publisher) is
+    /// exceeded and the data was discarded.
+    RateLimited,
+    /// The receive buffer of at least one receiver was still full when the timeout of a
+    /// blocking send expired.
+    Timeout,
+    /// The payload was not completely written before it was sent. Only detected when the
+    /// [`Publisher`](crate::port::publisher::Publisher) validates the initialization of its
+    /// samples.
+    UninitializedPayload,
+    /// The sample was loaned from another [`Publisher`](crate::port::publisher::Publisher)
+    /// than the one that shall send it.
+    SampleFromOtherPublisher,
 }
 
 impl From<LoanError> for SendError {
@@ -147,6 +202,22 @@ pub enum ReceiveError {
 
     /// Occurs when a receiver is unable to connect to a corresponding sender.
     ConnectionFailure(ConnectionFailure),
+
+    /// The received data was published by a process with a different byte order and the
+    /// receiver was configured to reject it.
+    ForeignEndianness,
+
+    /// The payload of the received data does not satisfy the alignment the receiver was
+    /// configured to assert.
+    MisalignedPayload,
+
+    /// All buffers of the provided [`BufferPool`](crate::port::buffer_pool::BufferPool) are
+    /// in use. The received data remains queued.
+    PoolExhausted,
+
+    /// The payload of the received data is larger than the buffers of the provided
+    /// [`BufferPool`](crate::port::buffer_pool::BufferPool). The received data was discarded.
+    ExceedsPoolBufferSize,
 }
 
 impl From<ConnectionFailure> for ReceiveError {
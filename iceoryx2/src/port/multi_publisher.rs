@@ -0,0 +1,134 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::multi_publisher::MultiPublisher;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let primary = node.service_builder(&"My/Primary/Service".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let replica = node.service_builder(&"My/Replica/Service".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let publisher = MultiPublisher::new(vec![
+//!     primary.publisher_builder().create()?,
+//!     replica.publisher_builder().create()?,
+//! ]);
+//!
+//! let number_of_recipients = publisher.send_copy(1234)?;
+//! println!("delivered to {:?} subscribers per service", number_of_recipients);
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use alloc::vec::Vec;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_log::fail;
+
+use crate::port::publisher::Publisher;
+use crate::service;
+
+use super::{LoanError, SendError};
+
+/// Failure that can be emitted when a [`MultiPublisher`] sends data.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MultiSendError {
+    /// The [`Publisher`] with the contained index was unable to loan a sample. No sample
+    /// was sent by any [`Publisher`].
+    LoanError {
+        /// The index of the [`Publisher`] that failed.
+        index: usize,
+        /// The failure that occurred.
+        error: LoanError,
+    },
+    /// At least one [`Publisher`] failed to send its sample. The report contains the send
+    /// result of every [`Publisher`] in the order they were provided.
+    PartialDelivery(Vec<Result<usize, SendError>>),
+}
+
+impl core::fmt::Display for MultiSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MultiSendError::{self:?}")
+    }
+}
+
+impl core::error::Error for MultiSendError {}
+
+/// Sends the same payload with multiple [`Publisher`]s of the same type, for instance to
+/// replicate data into several services. A sample is loaned on every [`Publisher`] before
+/// the first one is sent, so that an exhausted [`Publisher`] prevents the delivery to all
+/// services. Failures that occur while sending cannot be rolled back, therefore they are
+/// reported per [`Publisher`] with [`MultiSendError::PartialDelivery`].
+#[derive(Debug)]
+pub struct MultiPublisher<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend,
+    UserHeader: Debug + ZeroCopySend,
+> {
+    publishers: Vec<Publisher<Service, Payload, UserHeader>>,
+}
+
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + Clone,
+    UserHeader: Default + Debug + ZeroCopySend,
+> MultiPublisher<Service, Payload, UserHeader>
+{
+    /// Creates a new [`MultiPublisher`] that sends with all provided [`Publisher`]s.
+    pub fn new(publishers: Vec<Publisher<Service, Payload, UserHeader>>) -> Self {
+        Self { publishers }
+    }
+
+    /// Returns the [`Publisher`]s in the order they were provided.
+    pub fn publishers(&self) -> &[Publisher<Service, Payload, UserHeader>] {
+        &self.publishers
+    }
+
+    /// Copies the input value into a sample of every [`Publisher`] and sends them. On success
+    /// it returns for every [`Publisher`] the number of
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s that received the sample.
+    pub fn send_copy(&self, value: Payload) -> Result<Vec<usize>, MultiSendError> {
+        let msg = "Unable to send copy of payload with all publishers";
+
+        let mut samples = Vec::with_capacity(self.publishers.len());
+        for (index, publisher) in self.publishers.iter().enumerate() {
+            match publisher.loan_uninit() {
+                Ok(sample) => samples.push(sample),
+                Err(error) => {
+                    fail!(from self, with MultiSendError::LoanError { index, error },
+                        "{msg} since the publisher {index} was unable to loan a sample ({error:?}).");
+                }
+            }
+        }
+
+        let report: Vec<Result<usize, SendError>> = samples
+            .into_iter()
+            .map(|sample| sample.write_payload(value.clone()).send())
+            .collect();
+
+        if report.iter().any(|result| result.is_err()) {
+            fail!(from self, with MultiSendError::PartialDelivery(report),
+                "{msg} since at least one publisher failed to send the sample.");
+        }
+
+        Ok(report.into_iter().flatten().collect())
+    }
+}
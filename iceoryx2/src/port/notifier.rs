@@ -35,8 +35,10 @@
 //! # }
 //! ```
 
+use core::fmt::Debug;
 use core::time::Duration;
 
+use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -166,18 +168,18 @@ impl<Service: service::Service> ListenerConnections<Service> {
                     | iceoryx2_cal::event::NotifierCreateError::InitializationNotYetFinalized,
                 ) => (),
                 Err(iceoryx2_cal::event::NotifierCreateError::VersionMismatch) => {
-                    warn!(from self,
+                    warn!(from self.service_state.shared_node.log_origin(self),
                         "{} since a version mismatch was detected! All entities must use the same iceoryx2 version!",
                         msg);
                 }
                 Err(iceoryx2_cal::event::NotifierCreateError::InsufficientPermissions) => {
-                    warn!(from self, "{} since the permissions do not match. The service or the participants are maybe misconfigured.", msg);
+                    warn!(from self.service_state.shared_node.log_origin(self), "{} since the permissions do not match. The service or the participants are maybe misconfigured.", msg);
                 }
                 Err(iceoryx2_cal::event::NotifierCreateError::Interrupt) => {
-                    debug!(from self, "{} since an interrupt signal was received.", msg);
+                    debug!(from self.service_state.shared_node.log_origin(self), "{} since an interrupt signal was received.", msg);
                 }
                 Err(iceoryx2_cal::event::NotifierCreateError::InternalFailure) => {
-                    debug!(from self, "{} due to an internal failure.", msg);
+                    debug!(from self.service_state.shared_node.log_origin(self), "{} due to an internal failure.", msg);
                 }
             }
         }
@@ -249,7 +251,6 @@ impl<Service: service::Service> ListenerConnections<Service> {
 }
 
 /// Represents the sending endpoint of an event based communication.
-#[derive(Debug)]
 pub struct Notifier<Service: service::Service> {
     listener_connections: Service::ArcThreadSafetyPolicy<ListenerConnections<Service>>,
     default_event_id: EventId,
@@ -258,6 +259,22 @@ pub struct Notifier<Service: service::Service> {
     notifier_id: UniqueNotifierId,
     on_drop_notification: Option<EventId>,
     node_id: UniqueNodeId,
+    log_prefix: String,
+}
+
+impl<Service: service::Service> Debug for Notifier<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.log_prefix)?;
+        f.debug_struct("Notifier")
+            .field("listener_connections", &self.listener_connections)
+            .field("default_event_id", &self.default_event_id)
+            .field("event_id_max_value", &self.event_id_max_value)
+            .field("dynamic_notifier_handle", &self.dynamic_notifier_handle)
+            .field("notifier_id", &self.notifier_id)
+            .field("on_drop_notification", &self.on_drop_notification)
+            .field("node_id", &self.node_id)
+            .finish()
+    }
 }
 
 unsafe impl<Service: service::Service> Send for Notifier<Service> where
@@ -335,7 +352,7 @@ impl<Service: service::Service> Notifier<Service> {
         default_event_id: EventId,
     ) -> Result<Self, NotifierCreateError> {
         let msg = "Unable to create Notifier port";
-        let origin = "Notifier::new()";
+        let origin = service.shared_node.log_origin("Notifier::new()");
         let notifier_id = UniqueNotifierId::new();
 
         let listener_list = &service.dynamic_storage.get().event().listeners;
@@ -364,6 +381,7 @@ impl<Service: service::Service> Notifier<Service> {
             notifier_id,
             on_drop_notification: None,
             node_id,
+            log_prefix: service.shared_node.log_prefix().to_string(),
         };
 
         new_self
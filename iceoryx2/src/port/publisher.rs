@@ -101,20 +101,27 @@
 //! # }
 //! ```
 
+use core::alloc::Layout;
 use core::any::TypeId;
 use core::fmt::Debug;
+use core::time::Duration;
 use core::{marker::PhantomData, mem::MaybeUninit};
 
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
 use alloc::vec::Vec;
 
 use iceoryx2_bb_concurrency::atomic::Ordering;
-use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicUsize};
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64, AtomicUsize};
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary::cyclic_tagger::CyclicTagger;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::clock::{ClockType, Time, nanosleep};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
@@ -125,23 +132,31 @@ use iceoryx2_cal::zero_copy_connection::{
 use iceoryx2_log::{fail, warn};
 
 use crate::port::details::sender::*;
+use crate::port::unable_to_deliver_strategy::AtomicUnableToDeliverStrategy;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::prelude::UnableToDeliverStrategy;
 use crate::raw_sample::RawSampleMut;
 use crate::sample_mut::SampleMut;
 use crate::sample_mut_uninit::SampleMutUninit;
+use crate::sample_pool::SamplePool;
 use crate::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::data_segment_name;
-use crate::service::port_factory::publisher::{LocalPublisherConfig, PortFactoryPublisher};
+use crate::service::port_factory::publisher::{
+    LocalPublisherConfig, PortFactoryPublisher, PublisherConfigSnapshot,
+};
 use crate::service::static_config::message_type_details::TypeVariant;
 use crate::service::{self};
 
-use super::details::data_segment::{DataSegment, DataSegmentType};
+use super::details::data_segment::{DataSegment, DataSegmentCreateError, DataSegmentType};
 use super::details::segment_state::SegmentState;
+use super::details::slow_receiver_detector::SlowReceiverDetector;
+use super::details::token_bucket::TokenBucket;
+use super::details::write_tracker::WriteTracker;
 use super::{LoanError, SendError};
-use crate::identifiers::UniquePublisherId;
+use crate::identifiers::{SampleId, UniqueNodeId, UniquePublisherId, UniqueSubscriberId};
+use crate::node::NodeLogOrigin;
 
 /// Defines a failure that can occur when a [`Publisher`] is created with
 /// [`crate::service::port_factory::publisher::PortFactoryPublisher`].
@@ -157,6 +172,10 @@ pub enum PublisherCreateError {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The datasegment of the [`Publisher`] could not be bound to the NUMA node that was
+    /// requested with
+    /// [`PortFactoryPublisher::numa_node()`](crate::service::port_factory::publisher::PortFactoryPublisher::numa_node()).
+    UnableToBindToNumaNode,
 }
 
 impl core::fmt::Display for PublisherCreateError {
@@ -167,10 +186,169 @@ impl core::fmt::Display for PublisherCreateError {
 
 impl core::error::Error for PublisherCreateError {}
 
+/// Defines a failure that can occur in [`Publisher::shutdown()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PublisherShutdownError {
+    /// Not all [`Sample`](crate::sample::Sample)s that were delivered by the [`Publisher`]
+    /// were released by the [`Subscriber`](crate::port::subscriber::Subscriber)s before
+    /// the timeout was reached.
+    Timeout,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for PublisherShutdownError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PublisherShutdownError::{self:?}")
+    }
+}
+
+impl core::error::Error for PublisherShutdownError {}
+
+/// Defines a failure that can occur in [`Publisher::set_latched_value()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SetLatchedValueError {
+    /// The [`Service`](crate::service::Service) was created with a history size of zero,
+    /// therefore no value can be retained for late joining
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    HistoryNotEnabled,
+    /// A failure occurred while acquiring memory for the payload
+    LoanError(LoanError),
+}
+
+impl From<LoanError> for SetLatchedValueError {
+    fn from(value: LoanError) -> Self {
+        SetLatchedValueError::LoanError(value)
+    }
+}
+
+impl core::fmt::Display for SetLatchedValueError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SetLatchedValueError::{self:?}")
+    }
+}
+
+impl core::error::Error for SetLatchedValueError {}
+
+/// Defines a failure that can occur in [`Publisher::send_batch()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SendBatchError {
+    /// The sample with the contained index could not be sent. All samples before it were
+    /// sent, the remaining samples were not sent.
+    PartialDelivery {
+        /// The index of the sample that failed.
+        index: usize,
+        /// The sum of all deliveries of the samples that were sent before the failure.
+        number_of_deliveries: usize,
+        /// The failure that occurred.
+        error: SendError,
+    },
+}
+
+impl core::fmt::Display for SendBatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendBatchError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendBatchError {}
+
+/// Defines a failure that can occur in [`Publisher::loan_with_extended_header()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ExtendedUserHeaderLoanError {
+    /// The [`Service`](crate::service::Service) does not define an extended user header or
+    /// it was defined with a different type.
+    IncompatibleExtendedUserHeaderType,
+    /// A failure occurred while acquiring memory for the payload
+    LoanError(LoanError),
+}
+
+impl From<LoanError> for ExtendedUserHeaderLoanError {
+    fn from(value: LoanError) -> Self {
+        ExtendedUserHeaderLoanError::LoanError(value)
+    }
+}
+
+impl core::fmt::Display for ExtendedUserHeaderLoanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ExtendedUserHeaderLoanError::{self:?}")
+    }
+}
+
+impl core::error::Error for ExtendedUserHeaderLoanError {}
+
+/// Describes the data segment of a [`Publisher`]. Acquired via [`Publisher::segment_info()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentInfo {
+    /// The [`DataSegmentType`] of the [`Publisher`].
+    pub segment_type: DataSegmentType,
+    /// The number of shared memory segments the [`Publisher`] currently has allocated.
+    pub active_segments: usize,
+    /// The maximum number of shared memory segments the [`Publisher`] can allocate. When a
+    /// [`DataSegmentType::Dynamic`] data segment has reached it, loans that require a larger
+    /// segment fail.
+    pub max_segments: usize,
+}
+
+/// Describes the delivery statistics of the connection between a [`Publisher`] and a single
+/// [`Subscriber`](crate::port::subscriber::Subscriber). Acquired via
+/// [`Publisher::connection_stats()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionStat {
+    /// The [`UniqueSubscriberId`] of the connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub subscriber_id: UniqueSubscriberId,
+    /// The number of [`Sample`](crate::sample::Sample)s that were delivered via the connection.
+    pub delivered: u64,
+    /// The number of [`Sample`](crate::sample::Sample)s that were not delivered via the
+    /// connection since the [`Subscriber`](crate::port::subscriber::Subscriber)s buffer was full.
+    pub dropped: u64,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct OffsetAndSize {
     offset: u64,
     size: usize,
+    // address of the sample header in the data segment of this process
+    header: usize,
+}
+
+/// Preallocated storage of the last delivered payload that is compared with the next payload
+/// when duplicates are coalesced.
+#[derive(Debug)]
+struct LastSentPayload {
+    buffer: Vec<u8>,
+    offset: usize,
+    size: usize,
+    has_value: bool,
+}
+
+impl LastSentPayload {
+    fn new(size: usize, alignment: usize) -> Self {
+        let buffer = vec![0u8; size + alignment - 1];
+        let offset = buffer.as_ptr().align_offset(alignment);
+        Self {
+            buffer,
+            offset,
+            size,
+            has_value: false,
+        }
+    }
+
+    fn as_ptr(&self) -> *const u8 {
+        unsafe { self.buffer.as_ptr().add(self.offset) }
+    }
+
+    fn store(&mut self, payload: *const u8) {
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                payload,
+                self.buffer.as_mut_ptr().add(self.offset),
+                self.size,
+            )
+        };
+        self.has_value = true;
+    }
 }
 
 #[derive(Debug)]
@@ -180,10 +358,21 @@ pub(crate) struct PublisherSharedState<Service: service::Service> {
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
     is_active: AtomicBool,
+    next_sequence_number: AtomicU64,
+    send_rate_limiter: Option<UnsafeCell<TokenBucket>>,
+    slow_subscriber_detector: Option<UnsafeCell<SlowReceiverDetector>>,
+    last_sent_payload: Option<UnsafeCell<LastSentPayload>>,
 }
 
 impl<Service: service::Service> PublisherSharedState<Service> {
-    fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize) {
+    pub(crate) fn log_origin<'a, T: Debug + ?Sized>(
+        &'a self,
+        origin: &'a T,
+    ) -> NodeLogOrigin<'a, T> {
+        self.sender.shared_node.log_origin(origin)
+    }
+
+    fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize, header: *const u8) {
         match &self.history {
             None => (),
             Some(history) => {
@@ -192,8 +381,9 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                 match history.push_with_overflow(OffsetAndSize {
                     offset: offset.as_value(),
                     size: sample_size,
+                    header: header as usize,
                 }) {
-                    None => (),
+                    None => self.announce_number_of_resync_samples(history.len()),
                     Some(old) => self
                         .sender
                         .release_sample(PointerOffset::from_value(old.offset)),
@@ -202,11 +392,185 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         }
     }
 
+    /// Announces to every connected [`Subscriber`](crate::port::subscriber::Subscriber) how
+    /// many samples are re-delivered on a
+    /// [`Subscriber::request_resync()`](crate::port::subscriber::Subscriber::request_resync()).
+    /// Like the history of a new connection, it is limited by the buffer size of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    fn announce_number_of_resync_samples(&self, history_len: usize) {
+        self.sender.for_each_connection(|connection| {
+            connection.sender.set_number_of_resync_samples(
+                ChannelId::new(0),
+                history_len.min(connection.sender.buffer_size()) as u64,
+            );
+        });
+    }
+
+    pub(crate) fn acquire_sequence_number(&self) -> u64 {
+        self.next_sequence_number.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub(crate) fn is_paused(&self) -> bool {
+        self.sender
+            .service_state
+            .shared_node
+            .are_publishers_paused()
+    }
+
+    /// Acquires the permission to send from the rate limiter. When the maximum send rate is
+    /// exceeded and the [`UnableToDeliverStrategy`] is [`UnableToDeliverStrategy::Block`], it
+    /// returns the time until the next send slot becomes available. The caller must release
+    /// the lock before it waits and try again afterwards.
+    pub(crate) fn try_acquire_send_permit(&self) -> Result<Option<Duration>, SendError> {
+        let rate_limiter = match &self.send_rate_limiter {
+            None => return Ok(None),
+            Some(_) if self.is_paused() => return Ok(None),
+            Some(rate_limiter) => unsafe { &mut *rate_limiter.get() },
+        };
+
+        let msg = "Unable to acquire the permission to send";
+        let now = fail!(from self.log_origin(self), when Time::now_with_clock(ClockType::Monotonic),
+            with SendError::InternalError,
+            "{} since the monotonic clock could not be read.", msg);
+
+        match rate_limiter.try_acquire(now.as_duration()) {
+            Ok(()) => Ok(None),
+            Err(wait_time) => {
+                if self.sender.unable_to_deliver_strategy.load() != UnableToDeliverStrategy::Block {
+                    fail!(from self.log_origin(self), with SendError::RateLimited,
+                        "{} since the maximum send rate of {} messages per second is exceeded.",
+                        msg, rate_limiter.tokens_per_second());
+                }
+
+                Ok(Some(wait_time))
+            }
+        }
+    }
+
+    fn sample_layout(&self, number_of_elements: usize) -> Layout {
+        self.sender
+            .service_state
+            .static_config
+            .publish_subscribe()
+            .sample_layout(number_of_elements)
+    }
+
+    pub(crate) fn extended_user_header_ptr<ExtendedUserHeader: ZeroCopySend>(
+        &self,
+        header: *const Header,
+    ) -> Option<*mut ExtendedUserHeader> {
+        let static_config = self.sender.service_state.static_config.publish_subscribe();
+        if !static_config.has_extended_user_header_type::<ExtendedUserHeader>() {
+            return None;
+        }
+
+        let number_of_elements = unsafe { &*header }.number_of_elements() as usize;
+        static_config
+            .extended_user_header_ptr_from_header(header.cast(), number_of_elements)
+            .map(|ptr| ptr as *mut ExtendedUserHeader)
+    }
+
+    fn validates_initialization(&self) -> bool {
+        cfg!(debug_assertions) && self.config.validate_initialization
+    }
+
+    fn write_tracker(&self, number_of_elements: usize) -> Option<WriteTracker> {
+        self.validates_initialization()
+            .then(|| WriteTracker::new(number_of_elements))
+    }
+
+    /// Returns true when duplicates are coalesced and `payload` is equal to the last payload
+    /// that was delivered.
+    pub(crate) fn is_last_sent_payload(&self, payload: *const u8) -> bool {
+        let (is_equal, last_sent_payload) =
+            match (self.config.duplicate_comparator, &self.last_sent_payload) {
+                (Some(is_equal), Some(last_sent_payload)) => {
+                    (is_equal, unsafe { &*last_sent_payload.get() })
+                }
+                _ => return false,
+            };
+
+        last_sent_payload.has_value && is_equal(last_sent_payload.as_ptr(), payload)
+    }
+
+    /// Returns true when the adaptive drop is enabled and the receive buffer of at least one
+    /// connected [`Subscriber`](crate::port::subscriber::Subscriber) is filled above the
+    /// threshold.
+    pub(crate) fn exceeds_adaptive_drop_threshold(&self) -> bool {
+        let threshold = match self.config.adaptive_drop_threshold {
+            Some(threshold) => threshold as usize,
+            None => return false,
+        };
+
+        if let Err(e) = self.update_connections() {
+            warn!(from self.log_origin(self), "Unable to update all connections while checking the adaptive drop threshold ({:?}).", e);
+        }
+
+        let mut exceeds_threshold = false;
+        self.sender.for_each_connection(|connection| {
+            let pending_samples = connection
+                .sender
+                .number_of_pending_samples(ChannelId::new(0));
+            if pending_samples * 100 > threshold * connection.sender.buffer_size() {
+                exceeds_threshold = true;
+            }
+        });
+
+        exceeds_threshold
+    }
+
+    /// Stores a copy of `payload` as last delivered payload when duplicates are coalesced.
+    pub(crate) fn set_last_sent_payload(&self, payload: *const u8) {
+        if let Some(last_sent_payload) = &self.last_sent_payload {
+            unsafe { &mut *last_sent_payload.get() }.store(payload);
+        }
+    }
+
+    pub(crate) fn run_drop_on_discard(&self) -> bool {
+        self.config.run_drop_on_discard
+    }
+
+    fn has_history(&self) -> bool {
+        self.history.is_some()
+    }
+
+    fn for_each_history_entry<F: FnMut(*const u8)>(&self, mut callback: F) {
+        if let Some(history) = &self.history {
+            let history = unsafe { &*history.get() };
+            for i in 0..history.len() {
+                callback(unsafe { history.get_unchecked(i) }.header as *const u8);
+            }
+        }
+    }
+
+    fn set_latched_sample(&self, offset: PointerOffset, sample_size: usize, header: *const u8) {
+        if let Some(history) = &self.history {
+            let history = unsafe { &mut *history.get() };
+            while let Some(old) = history.pop() {
+                self.sender
+                    .release_sample(PointerOffset::from_value(old.offset));
+            }
+
+            self.sender.borrow_sample(offset);
+            history.push_with_overflow(OffsetAndSize {
+                offset: offset.as_value(),
+                size: sample_size,
+                header: header as usize,
+            });
+            self.announce_number_of_resync_samples(history.len());
+        }
+    }
+
     fn force_update_connections(&self) -> Result<(), ZeroCopyCreationError> {
+        let publisher_id = UniquePublisherId(UniqueSystemId::from(self.sender.sender_port_id));
         let mut result = Ok(());
         self.sender.start_update_connection_cycle();
         unsafe {
             (*self.subscriber_list_state.get()).for_each(|h, port| {
+                if !port.accepts_publisher(&publisher_id) {
+                    return CallbackProgression::Continue;
+                }
+
                 let inner_result = self.sender.update_connection(
                     h.index() as usize,
                     ReceiverDetails {
@@ -230,6 +594,7 @@ impl<Service: service::Service> PublisherSharedState<Service> {
     }
 
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        let reconnect_requested = self.sender.take_reconnect_request();
         if unsafe {
             self.sender
                 .service_state
@@ -238,14 +603,55 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                 .publish_subscribe()
                 .subscribers
                 .update_state(&mut *self.subscriber_list_state.get())
-        } {
-            fail!(from self, when self.force_update_connections(),
+        } || reconnect_requested
+        {
+            fail!(from self.log_origin(self), when self.force_update_connections(),
                 "Connections were updated only partially since at least one connection to a Subscriber port failed.");
         }
 
+        if self.history.is_some() {
+            self.sender.for_each_connection(|connection| {
+                if connection.sender.take_resync_request(ChannelId::new(0)) {
+                    self.deliver_sample_history(connection);
+                }
+            });
+        }
+
+        self.detect_slow_subscribers();
+
         Ok(())
     }
 
+    fn detect_slow_subscribers(&self) {
+        let detector = match &self.slow_subscriber_detector {
+            None => return,
+            Some(detector) => unsafe { &mut *detector.get() },
+        };
+
+        let now = match Time::now_with_clock(ClockType::Monotonic) {
+            Ok(now) => now.as_duration(),
+            Err(e) => {
+                warn!(from self.log_origin(self),
+                    "Unable to detect slow subscribers since the monotonic clock could not be read ({:?}).", e);
+                return;
+            }
+        };
+
+        self.sender.for_each_connection(|connection| {
+            let has_pending_samples = connection
+                .sender
+                .number_of_pending_samples(ChannelId::new(0))
+                > 0;
+            if detector.update(connection.receiver_port_id, has_pending_samples, now) {
+                warn!(from self.log_origin(self),
+                    "The subscriber {:?} did not consume all of its samples for more than {:?}.",
+                    UniqueSubscriberId(UniqueSystemId::from(connection.receiver_port_id)),
+                    detector.threshold());
+            }
+        });
+        detector.remove_receivers_not_updated_at(now);
+    }
+
     fn deliver_sample_history(&self, connection: &Connection<Service>) {
         match &self.history {
             None => (),
@@ -253,6 +659,10 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                 let history = unsafe { &mut *history.get() };
                 let buffer_size = connection.sender.buffer_size();
                 let history_start = history.len().saturating_sub(buffer_size);
+                connection.sender.set_number_of_resync_samples(
+                    ChannelId::new(0),
+                    (history.len() - history_start) as u64,
+                );
 
                 for i in history_start..history.len() {
                     let old_sample = unsafe { history.get_unchecked(i) };
@@ -271,7 +681,7 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                             }
                         }
                         Err(e) => {
-                            warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
+                            warn!(from self.log_origin(self), "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
                         }
                     }
                 }
@@ -279,28 +689,138 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         }
     }
 
+    fn number_of_samples_in_use(&self) -> usize {
+        if let Err(e) = self.update_connections() {
+            warn!(from self.log_origin(self), "Unable to update all connections while acquiring the number of samples in use ({:?}).", e);
+        }
+        self.sender.retrieve_returned_samples();
+
+        let samples_held_only_by_history = match &self.history {
+            None => 0,
+            Some(history) => {
+                let history = unsafe { &*history.get() };
+                (0..history.len())
+                    .filter(|i| {
+                        let sample = unsafe { history.get_unchecked(*i) };
+                        self.sender
+                            .reference_count(PointerOffset::from_value(sample.offset))
+                            == 1
+                    })
+                    .count()
+            }
+        };
+
+        self.sender
+            .number_of_used_samples()
+            .saturating_sub(self.sender.loan_counter.load(Ordering::Relaxed))
+            .saturating_sub(samples_held_only_by_history)
+    }
+
+    fn prepare_send(&self, msg: &str) -> Result<(), SendError> {
+        if !self.is_active.load(Ordering::Relaxed) {
+            fail!(from self.log_origin(self), with SendError::ConnectionBrokenSinceSenderNoLongerExists,
+                "{} since the corresponding publisher is already disconnected.", msg);
+        }
+
+        fail!(from self.log_origin(self), when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        Ok(())
+    }
+
+    /// Delivers the sample to all connected [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    /// When `accepts_node` is provided, only the [`Subscriber`](crate::port::subscriber::Subscriber)s
+    /// whose [`Node`](crate::node::Node) is accepted receive the sample and it is not added
+    /// to the history.
+    /// The sample is only stamped with the next sequence number and `was_sample_sent` is only
+    /// set when the sample is handed over, i.e. when the publishers are not paused.
     pub(crate) fn send_sample(
         &self,
         offset: PointerOffset,
         sample_size: usize,
+        header: &mut Header,
+        was_sample_sent: &mut bool,
+        blocking_timeout: Option<Duration>,
+        accepts_node: Option<&dyn Fn(&UniqueNodeId) -> bool>,
     ) -> Result<usize, SendError> {
-        let msg = "Unable to send sample";
-        if !self.is_active.load(Ordering::Relaxed) {
-            fail!(from self, with SendError::ConnectionBrokenSinceSenderNoLongerExists,
-                "{} since the corresponding publisher is already disconnected.", msg);
+        self.prepare_send("Unable to send sample")?;
+
+        if self.is_paused() {
+            return Ok(0);
         }
 
-        fail!(from self, when self.update_connections(),
-            "{} since the connections could not be updated.", msg);
+        header.set_sequence_number(self.acquire_sequence_number());
+        *was_sample_sent = true;
 
-        self.add_sample_to_history(offset, sample_size);
-        self.sender
-            .deliver_offset(offset, sample_size, ChannelId::new(0))
+        if accepts_node.is_none() {
+            self.add_sample_to_history(offset, sample_size, (header as *const Header).cast());
+        }
+        let accepts_receiver = |receiver_port_id: u128| match accepts_node {
+            None => true,
+            Some(accepts_node) => self
+                .node_of_subscriber(receiver_port_id)
+                .is_some_and(|node_id| accepts_node(&node_id)),
+        };
+        match blocking_timeout {
+            None => self.sender.deliver_offset_to_receivers(
+                offset,
+                sample_size,
+                ChannelId::new(0),
+                &accepts_receiver,
+            ),
+            Some(timeout) => self.sender.deliver_offset_with_timeout(
+                offset,
+                sample_size,
+                ChannelId::new(0),
+                timeout,
+                &accepts_receiver,
+            ),
+        }
+    }
+
+    fn node_of_subscriber(&self, subscriber_port_id: u128) -> Option<UniqueNodeId> {
+        let mut node_id = None;
+        unsafe {
+            (*self.subscriber_list_state.get()).for_each(|_, port| {
+                if port.subscriber_id.value() == subscriber_port_id {
+                    node_id = Some(port.node_id);
+                    CallbackProgression::Stop
+                } else {
+                    CallbackProgression::Continue
+                }
+            })
+        };
+
+        node_id
+    }
+}
+
+type PublisherSharedStateGuard<'a, Service> = <<Service as service::Service>::ArcThreadSafetyPolicy<
+    PublisherSharedState<Service>,
+> as ArcSyncPolicy<PublisherSharedState<Service>>>::LockGuard<'a>;
+
+/// Locks the [`PublisherSharedState`] as soon as the rate limiter permits the next send. The
+/// lock is released while waiting for the next send slot so that the other operations of the
+/// [`Publisher`] are not blocked.
+pub(crate) fn lock_with_send_permit<Service: service::Service>(
+    publisher_shared_state: &Service::ArcThreadSafetyPolicy<PublisherSharedState<Service>>,
+) -> Result<PublisherSharedStateGuard<'_, Service>, SendError> {
+    loop {
+        let guard = publisher_shared_state.lock();
+        let wait_time = match guard.try_acquire_send_permit()? {
+            None => return Ok(guard),
+            Some(wait_time) => wait_time,
+        };
+        let shared_node = guard.sender.shared_node.clone();
+        drop(guard);
+
+        fail!(from shared_node.log_origin("Publisher::lock_with_send_permit()"), when nanosleep(wait_time),
+            with SendError::InternalError,
+            "Unable to acquire the permission to send since the wait for the next send slot failed.");
     }
 }
 
 /// Sending endpoint of a publish-subscriber based communication.
-#[derive(Debug)]
 pub struct Publisher<
     Service: service::Service,
     Payload: Debug + ZeroCopySend + ?Sized + 'static,
@@ -309,10 +829,32 @@ pub struct Publisher<
     pub(crate) publisher_shared_state:
         Service::ArcThreadSafetyPolicy<PublisherSharedState<Service>>,
     dynamic_publisher_handle: Option<ContainerHandle>,
+    unable_to_deliver_strategy: Arc<AtomicUnableToDeliverStrategy>,
+    log_prefix: String,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
 
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: Debug + ZeroCopySend,
+> Debug for Publisher<Service, Payload, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}Publisher<{}, {}, {}> {{ publisher_shared_state: {:?}, dynamic_publisher_handle: {:?} }}",
+            self.log_prefix,
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<UserHeader>(),
+            self.publisher_shared_state,
+            self.dynamic_publisher_handle
+        )
+    }
+}
+
 unsafe impl<
     Service: service::Service,
     Payload: Debug + ZeroCopySend + ?Sized,
@@ -364,7 +906,6 @@ impl<
         publisher_factory: PortFactoryPublisher<Service, Payload, UserHeader>,
     ) -> Result<Self, PublisherCreateError> {
         let msg = "Unable to create Publisher port";
-        let origin = "Publisher::new()";
         let port_id = UniquePublisherId::new();
         let config = &publisher_factory.config;
         let static_config = publisher_factory
@@ -373,6 +914,7 @@ impl<
             .static_config
             .publish_subscribe();
         let service = &publisher_factory.factory.service;
+        let origin = service.shared_node.log_origin("Publisher::new()");
         let subscriber_list = &service
             .dynamic_storage
             .get()
@@ -390,9 +932,7 @@ impl<
         let data_segment_type =
             DataSegmentType::new_from_allocation_strategy(config.allocation_strategy);
 
-        let sample_layout = static_config
-            .message_type_details
-            .sample_layout(config.initial_max_slice_len);
+        let sample_layout = static_config.sample_layout(config.initial_max_slice_len);
 
         let max_slice_len = config.initial_max_slice_len;
         let max_number_of_segments =
@@ -404,34 +944,53 @@ impl<
             max_slice_len,
             node_id: *service.shared_node.id(),
             max_number_of_segments,
+            unable_to_deliver_strategy: config.unable_to_deliver_strategy as u8,
         };
-        let global_config = service.shared_node.config();
 
         let segment_name = data_segment_name(publisher_details.publisher_id.value());
         let data_segment = match data_segment_type {
             DataSegmentType::Static => DataSegment::create_static_segment(
                 &segment_name,
                 sample_layout,
-                global_config,
+                &service.shared_node,
                 number_of_samples,
+                config.numa_node,
             ),
             DataSegmentType::Dynamic => DataSegment::create_dynamic_segment(
                 &segment_name,
                 sample_layout,
-                global_config,
+                &service.shared_node,
                 number_of_samples,
                 config.allocation_strategy,
+                config.numa_node,
             ),
         };
 
-        let data_segment = fail!(from origin,
-                when data_segment,
-                with PublisherCreateError::UnableToCreateDataSegment,
-                "{} since the data segment could not be acquired.", msg);
+        let data_segment = match data_segment {
+            Ok(data_segment) => data_segment,
+            Err(DataSegmentCreateError::UnableToBindToNumaNode) => {
+                fail!(from origin, with PublisherCreateError::UnableToBindToNumaNode,
+                    "{} since the data segment could not be bound to the NUMA node.", msg);
+            }
+            Err(DataSegmentCreateError::SharedMemoryCreationFailed(e)) => {
+                fail!(from origin, with PublisherCreateError::UnableToCreateDataSegment,
+                    "{} since the data segment could not be acquired ({:?}).", msg, e);
+            }
+        };
+
+        let send_rate_limiter = match config.max_send_rate {
+            0 => None,
+            v => Some(UnsafeCell::new(TokenBucket::new(v))),
+        };
+
+        let unable_to_deliver_strategy = Arc::new(AtomicUnableToDeliverStrategy::new(
+            config.unable_to_deliver_strategy,
+        ));
 
         let publisher_shared_state =
             <Service as service::Service>::ArcThreadSafetyPolicy::new(PublisherSharedState {
                 is_active: AtomicBool::new(true),
+                next_sequence_number: AtomicU64::new(0),
                 sender: Sender {
                     data_segment,
                     segment_states: {
@@ -453,14 +1012,17 @@ impl<
                     number_of_samples,
                     max_number_of_segments,
                     degradation_callback: publisher_factory.degradation_callback,
+                    sample_reclaimed_callback: publisher_factory.sample_reclaimed_callback,
                     service_state: service.clone(),
                     tagger: CyclicTagger::new(),
                     loan_counter: AtomicUsize::new(0),
                     sender_max_borrowed_samples: config.max_loaned_samples,
-                    unable_to_deliver_strategy: config.unable_to_deliver_strategy,
+                    unable_to_deliver_strategy: unable_to_deliver_strategy.clone(),
                     message_type_details: static_config.message_type_details,
                     number_of_channels: 1,
                     initial_channel_state: CHANNEL_STATE_OPEN,
+                    reconnect_requested: AtomicBool::new(false),
+                    detached_connections: UnsafeCell::new(Vec::new()),
                 },
                 config: *config,
                 subscriber_list_state: UnsafeCell::new(unsafe { subscriber_list.get_state() }),
@@ -468,6 +1030,16 @@ impl<
                     true => None,
                     false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
                 },
+                send_rate_limiter,
+                slow_subscriber_detector: config
+                    .slow_subscriber_threshold
+                    .map(|threshold| UnsafeCell::new(SlowReceiverDetector::new(threshold))),
+                last_sent_payload: config.duplicate_comparator.map(|_| {
+                    UnsafeCell::new(LastSentPayload::new(
+                        static_config.message_type_details.payload.size,
+                        static_config.message_type_details.payload.alignment,
+                    ))
+                }),
             });
 
         let publisher_shared_state = match publisher_shared_state {
@@ -482,6 +1054,8 @@ impl<
         let mut new_self = Self {
             publisher_shared_state,
             dynamic_publisher_handle: None,
+            unable_to_deliver_strategy,
+            log_prefix: service.shared_node.log_prefix().to_string(),
             _payload: PhantomData,
             _user_header: PhantomData,
         };
@@ -518,6 +1092,13 @@ impl<
         Ok(new_self)
     }
 
+    pub(crate) fn log_origin<'a, T: Debug + ?Sized>(
+        &'a self,
+        origin: &'a T,
+    ) -> NodeLogOrigin<'a, T> {
+        NodeLogOrigin::new(&self.log_prefix, origin)
+    }
+
     /// Returns the [`UniquePublisherId`] of the [`Publisher`]
     pub fn id(&self) -> UniquePublisherId {
         UniquePublisherId(UniqueSystemId::from(
@@ -525,28 +1106,45 @@ impl<
         ))
     }
 
+    /// Returns a [`PublisherConfigSnapshot`] of the configuration the [`Publisher`] was created
+    /// with. It can be provided to
+    /// [`PortFactoryPublisher::from_config()`] to create further [`Publisher`]s with the same
+    /// settings. A later change via [`Publisher::set_unable_to_deliver_strategy()`] is not
+    /// reflected.
+    pub fn builder_config(&self) -> PublisherConfigSnapshot<Payload, UserHeader> {
+        PublisherConfigSnapshot {
+            config: self.publisher_shared_state.lock().config,
+            _payload: PhantomData,
+            _user_header: PhantomData,
+        }
+    }
+
     /// Returns the strategy the [`Publisher`] follows when a [`SampleMut`] cannot be delivered
     /// since the [`Subscriber`](crate::port::subscriber::Subscriber)s buffer is full.
     pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
-        self.publisher_shared_state
-            .lock()
-            .sender
-            .unable_to_deliver_strategy
+        self.unable_to_deliver_strategy.load()
     }
-}
 
-////////////////////////
-// BEGIN: typed API
-////////////////////////
-impl<
-    Service: service::Service,
-    Payload: Debug + ZeroCopySend + Sized,
-    UserHeader: Default + Debug + ZeroCopySend,
-> Publisher<Service, Payload, UserHeader>
-{
-    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it.
-    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
-    /// the data, otherwise a [`SendError`] describing the failure.
+    /// Sets the strategy the [`Publisher`] follows when a [`SampleMut`] cannot be delivered
+    /// since the [`Subscriber`](crate::port::subscriber::Subscriber)s buffer is full. When
+    /// the strategy is switched away from [`UnableToDeliverStrategy::Block`] while a send is
+    /// blocked, the send returns and the [`SampleMut`] is not delivered to the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s with a full buffer.
+    pub fn set_unable_to_deliver_strategy(&self, value: UnableToDeliverStrategy) {
+        self.unable_to_deliver_strategy.store(value);
+    }
+
+    /// Sends all `samples` in the given order under one acquisition of the internal lock of
+    /// the [`Publisher`], instead of acquiring it for every [`SampleMut::send()`]. On success
+    /// it returns the sum of all deliveries, meaning the number of
+    /// [`crate::port::subscriber::Subscriber`]s that received each sample added up. When
+    /// sending a sample fails, the remaining samples are not sent and
+    /// [`SendBatchError::PartialDelivery`] reports which sample failed and how many deliveries
+    /// happened before. A sample that was not loaned from this [`Publisher`] fails with
+    /// [`SendError::SampleFromOtherPublisher`].
+    /// When the [`Publisher`] is rate limited with
+    /// [`PortFactoryPublisher::max_send_rate()`](crate::service::port_factory::publisher::PortFactoryPublisher::max_send_rate())
+    /// and has to wait for the next send slot, the lock is released while waiting.
     ///
     /// # Example
     ///
@@ -560,24 +1158,104 @@ impl<
     /// #     .open_or_create()?;
     /// #
     /// # let publisher = service.publisher_builder()
+    /// #                        .max_loaned_samples(2)
     /// #                        .create()?;
     ///
-    /// publisher.send_copy(1234)?;
+    /// let samples = vec![
+    ///     publisher.loan_uninit()?.write_payload(1),
+    ///     publisher.loan_uninit()?.write_payload(2),
+    /// ];
+    /// publisher.send_batch(samples)?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send_copy(&self, value: Payload) -> Result<usize, SendError> {
-        let msg = "Unable to send copy of payload";
-        let sample = fail!(from self, when self.loan_uninit(),
-                                    "{} since the loan of a sample failed.", msg);
+    pub fn send_batch(
+        &self,
+        mut samples: Vec<SampleMut<Service, Payload, UserHeader>>,
+    ) -> Result<usize, SendBatchError> {
+        let id = self.id();
+        let mut publisher_shared_state = self.publisher_shared_state.lock();
+        let mut number_of_deliveries = 0;
+        let mut failure = None;
+        for (n, sample) in samples.iter_mut().enumerate() {
+            if sample.ptr.as_header_ref().publisher_id() != id {
+                failure = Some((n, SendError::SampleFromOtherPublisher));
+                break;
+            }
+
+            let mut permit = publisher_shared_state.try_acquire_send_permit();
+            while let Ok(Some(wait_time)) = permit {
+                // never wait for the next send slot while holding the lock
+                drop(publisher_shared_state);
+                let wait_result = nanosleep(wait_time);
+                publisher_shared_state = self.publisher_shared_state.lock();
+                permit = match wait_result {
+                    Ok(()) => publisher_shared_state.try_acquire_send_permit(),
+                    Err(_) => Err(SendError::InternalError),
+                };
+            }
+
+            match permit.and_then(|_| sample.send_with_locked_state(&publisher_shared_state)) {
+                Ok(number_of_recipients) => number_of_deliveries += number_of_recipients,
+                Err(e) => {
+                    failure = Some((n, e));
+                    break;
+                }
+            }
+        }
+        // the samples return their memory on drop which requires the lock
+        drop(publisher_shared_state);
+        drop(samples);
+
+        if let Some((index, error)) = failure {
+            fail!(from self, with SendBatchError::PartialDelivery { index, number_of_deliveries, error },
+                "Unable to send the whole batch of samples since sample {} could not be sent ({:?}). Only the first {} samples were sent.",
+                index, error, index);
+        }
 
-        sample.write_payload(value).send()
+        Ok(number_of_deliveries)
     }
 
-    /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`].
-    /// The user has to initialize the payload before it can be sent.
-    ///
-    /// On failure it returns [`LoanError`] describing the failure.
+    /// Returns the number of [`Sample`](crate::sample::Sample)s that were delivered by the
+    /// [`Publisher`] and are still held by a [`Subscriber`](crate::port::subscriber::Subscriber),
+    /// either in its receive buffer or as borrowed [`Sample`](crate::sample::Sample).
+    pub fn number_of_samples_in_use(&self) -> usize {
+        self.publisher_shared_state
+            .lock()
+            .number_of_samples_in_use()
+    }
+
+    /// Returns the number of [`SampleMut`]s that are currently loaned from the [`Publisher`]
+    /// and were neither sent nor dropped. A loan fails with [`LoanError::ExceedsMaxLoans`]
+    /// as soon as this number reaches the configured maximum of loaned samples.
+    pub fn loaned_samples(&self) -> usize {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .loan_counter
+            .load(Ordering::Relaxed)
+    }
+
+    /// Returns the [`SegmentInfo`] of the [`Publisher`]s data segment. Useful to detect when
+    /// a [`DataSegmentType::Dynamic`] data segment is about to reach its maximum number of
+    /// segments.
+    pub fn segment_info(&self) -> SegmentInfo {
+        let shared_state = self.publisher_shared_state.lock();
+        let data_segment = &shared_state.sender.data_segment;
+        let segment_type = data_segment.data_segment_type();
+
+        SegmentInfo {
+            segment_type,
+            active_segments: data_segment.number_of_active_segments(),
+            max_segments: DataSegment::<Service>::max_number_of_segments(segment_type) as usize,
+        }
+    }
+
+    /// Returns the [`ConnectionStat`] of every connection between the [`Publisher`] and a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber). The counters start at zero when
+    /// the connection is established, therefore a re-established connection starts over.
+    /// Useful to identify a single [`Subscriber`](crate::port::subscriber::Subscriber) that
+    /// does not keep up.
     ///
     /// # Example
     ///
@@ -590,11 +1268,377 @@ impl<
     /// #     .publish_subscribe::<u64>()
     /// #     .open_or_create()?;
     /// #
-    /// # let publisher = service.publisher_builder()
-    /// #                        .create()?;
+    /// let publisher = service.publisher_builder().create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    /// publisher.send_copy(1234)?;
     ///
-    /// let sample = publisher.loan_uninit()?;
-    /// let sample = sample.write_payload(42); // alternatively `sample.payload_mut()` can be use to access the `MaybeUninit<Payload>`
+    /// for stat in publisher.connection_stats() {
+    ///     println!("{:?}: delivered {}, dropped {}", stat.subscriber_id, stat.delivered, stat.dropped);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn connection_stats(&self) -> impl Iterator<Item = ConnectionStat> {
+        let publisher_shared_state = self.publisher_shared_state.lock();
+        if let Err(e) = publisher_shared_state.update_connections() {
+            warn!(from self, "Unable to update all connections while acquiring the connection statistics ({:?}).", e);
+        }
+
+        let mut stats = Vec::new();
+        publisher_shared_state
+            .sender
+            .for_each_connection(|connection| {
+                stats.push(ConnectionStat {
+                    subscriber_id: UniqueSubscriberId(UniqueSystemId::from(
+                        connection.receiver_port_id,
+                    )),
+                    delivered: connection.delivered.load(Ordering::Relaxed),
+                    dropped: connection.dropped.load(Ordering::Relaxed),
+                });
+            });
+
+        stats.into_iter()
+    }
+
+    /// Returns the [`UniqueSubscriberId`]s of all connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s whose receive buffer is full so that
+    /// a send with the [`UnableToDeliverStrategy::Block`] would currently block. Useful for a
+    /// supervisor to detect and handle a stalled
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) before a send hangs. When the
+    /// [`Service`](crate::service::Service) has safe overflow enabled, a send never blocks
+    /// and the list is always empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .enable_safe_overflow(false)
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder()
+    ///     .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+    ///     .create()?;
+    ///
+    /// for subscriber_id in publisher.blocking_subscribers() {
+    ///     println!("stalled subscriber: {:?}", subscriber_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocking_subscribers(&self) -> Vec<UniqueSubscriberId> {
+        let publisher_shared_state = self.publisher_shared_state.lock();
+        if let Err(e) = publisher_shared_state.update_connections() {
+            warn!(from self, "Unable to update all connections while acquiring the blocking subscribers ({:?}).", e);
+        }
+
+        let mut subscriber_ids = Vec::new();
+        publisher_shared_state
+            .sender
+            .for_each_connection(|connection| {
+                if connection.sender.would_block_on_send(ChannelId::new(0)) {
+                    subscriber_ids.push(UniqueSubscriberId(UniqueSystemId::from(
+                        connection.receiver_port_id,
+                    )));
+                }
+            });
+
+        subscriber_ids
+    }
+
+    /// Waits until all [`Sample`](crate::sample::Sample)s that were delivered by the
+    /// [`Publisher`] were released by the [`Subscriber`](crate::port::subscriber::Subscriber)s
+    /// so that the [`Publisher`] can be dropped gracefully. If the `timeout` is reached before,
+    /// [`PublisherShutdownError::Timeout`] is returned and
+    /// [`Publisher::number_of_samples_in_use()`] can be used to acquire the number of
+    /// [`Sample`](crate::sample::Sample)s that are still in use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().create()?;
+    /// publisher.send_copy(1234)?;
+    ///
+    /// publisher.shutdown(Duration::from_millis(100))?;
+    /// drop(publisher);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shutdown(&self, timeout: Duration) -> Result<(), PublisherShutdownError> {
+        let msg = "Unable to shutdown publisher";
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                with PublisherShutdownError::InternalFailure,
+                "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            let samples_in_use = self.number_of_samples_in_use();
+            if samples_in_use == 0 {
+                return Ok(());
+            }
+
+            let elapsed_time = fail!(from self, when adaptive_wait.wait(),
+                    with PublisherShutdownError::InternalFailure,
+                    "{} since the adaptive wait call failed.", msg);
+
+            if elapsed_time >= timeout {
+                fail!(from self, with PublisherShutdownError::Timeout,
+                    "{} since {} samples are still in use after {:?}.",
+                    msg, samples_in_use, timeout);
+            }
+        }
+    }
+}
+
+////////////////////////
+// BEGIN: typed API
+////////////////////////
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + Sized,
+    UserHeader: Default + Debug + ZeroCopySend,
+> Publisher<Service, Payload, UserHeader>
+{
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`SendError`] describing the failure.
+    /// When the [`Publisher`] was created with
+    /// [`PortFactoryPublisher::coalesce_duplicates()`] and `value` is equal to the last value
+    /// that reached at least one [`crate::port::subscriber::Subscriber`], nothing is delivered
+    /// and `Ok(0)` is returned. The same applies when the
+    /// [`Publisher`] was created with [`PortFactoryPublisher::adaptive_drop()`] and the receive
+    /// buffer of the slowest [`crate::port::subscriber::Subscriber`] exceeds the threshold.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    /// #                        .create()?;
+    ///
+    /// publisher.send_copy(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(&self, value: Payload) -> Result<usize, SendError> {
+        let msg = "Unable to send copy of payload";
+        let sample = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a sample failed.", msg);
+
+        sample.write_payload(value).send_filtered()
+    }
+
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it like
+    /// [`Publisher::send_copy()`]. Independent of the configured [`UnableToDeliverStrategy`],
+    /// it waits up to `timeout` until a full receive buffer of a
+    /// [`crate::port::subscriber::Subscriber`] has space again. When the timeout expires,
+    /// [`SendError::Timeout`] is returned and the data may have been delivered only to a
+    /// subset of the [`crate::port::subscriber::Subscriber`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    /// #                        .create()?;
+    ///
+    /// publisher.send_copy_blocking(1234, Duration::from_millis(10))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy_blocking(
+        &self,
+        value: Payload,
+        timeout: Duration,
+    ) -> Result<usize, SendError> {
+        let msg = "Unable to send copy of payload with a timeout";
+        let sample = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a sample failed.", msg);
+
+        sample.write_payload(value).send_impl(Some(timeout))
+    }
+
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that
+    /// received the data together with the [`SampleId`] of the sent sample. The receiving
+    /// side can acquire the same [`SampleId`] with [`crate::sample::Sample::id()`] to
+    /// correlate sent and received samples. In contrast to [`Publisher::send_copy()`], the
+    /// sample is always delivered, independent of
+    /// [`PortFactoryPublisher::coalesce_duplicates()`] and
+    /// [`PortFactoryPublisher::adaptive_drop()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    /// #                        .create()?;
+    ///
+    /// let (_, sample_id) = publisher.send_copy_tracked(1234)?;
+    /// println!("sent sample {:?}", sample_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy_tracked(&self, value: Payload) -> Result<(usize, SampleId), SendError> {
+        let msg = "Unable to send tracked copy of payload";
+        let sample = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a sample failed.", msg);
+
+        let (number_of_recipients, sequence_number) = sample
+            .write_payload(value)
+            .send_with_sequence_number_impl(None)?;
+
+        Ok((
+            number_of_recipients,
+            SampleId::new(self.id(), sequence_number),
+        ))
+    }
+
+    /// Calls `callback` for every payload that is currently retained in the history of the
+    /// [`Publisher`], ordered from the oldest to the newest. The history is only read and
+    /// the delivery to [`crate::port::subscriber::Subscriber`]s is not affected. When the
+    /// [`Service`](crate::service::Service) was created with a history size of zero, the
+    /// `callback` is never called. Useful for diagnostic tools that inspect the history
+    /// without connecting a [`crate::port::subscriber::Subscriber`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .history_size(2)
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    /// publisher.send_copy(1234)?;
+    ///
+    /// publisher.history(|payload| println!("retained: {}", payload));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn history<F: FnMut(&Payload)>(&self, mut callback: F) {
+        let publisher_shared_state = self.publisher_shared_state.lock();
+        publisher_shared_state.for_each_history_entry(|header| {
+            let payload = publisher_shared_state
+                .sender
+                .message_type_details
+                .payload_ptr_from_header(header);
+            callback(unsafe { &*payload.cast::<Payload>() });
+        });
+    }
+
+    /// Replaces the history of the [`Publisher`] with a copy of `value` without delivering it
+    /// to the currently connected [`crate::port::subscriber::Subscriber`]s. Only
+    /// [`crate::port::subscriber::Subscriber`]s that connect afterwards receive it as history.
+    /// Since the history is replaced under the same lock that is used to deliver it,
+    /// a concurrently connecting [`crate::port::subscriber::Subscriber`] receives either the old
+    /// or the new latched value. The latched value acquires its own sequence number, therefore
+    /// the currently connected [`crate::port::subscriber::Subscriber`]s observe a gap in the
+    /// sequence numbers of the live stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .history_size(1)
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// publisher.set_latched_value(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_latched_value(&self, value: Payload) -> Result<(), SetLatchedValueError> {
+        let msg = "Unable to set latched value";
+        if !self.publisher_shared_state.lock().has_history() {
+            fail!(from self, with SetLatchedValueError::HistoryNotEnabled,
+                "{} since the service was created without history.", msg);
+        }
+
+        let sample = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a sample failed.", msg);
+        let mut sample = sample.write_payload(value);
+        sample.was_sample_sent = true;
+
+        let publisher_shared_state = self.publisher_shared_state.lock();
+        sample
+            .ptr
+            .as_header_mut()
+            .set_sequence_number(publisher_shared_state.acquire_sequence_number());
+        publisher_shared_state.set_latched_sample(
+            sample.offset_to_chunk,
+            sample.sample_size,
+            (sample.ptr.as_header_ref() as *const Header).cast(),
+        );
+
+        Ok(())
+    }
+
+    /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`].
+    /// The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    /// #                        .create()?;
+    ///
+    /// let sample = publisher.loan_uninit()?;
+    /// let sample = sample.write_payload(42); // alternatively `sample.payload_mut()` can be use to access the `MaybeUninit<Payload>`
     ///
     /// sample.send()?;
     ///
@@ -605,9 +1649,32 @@ impl<
         &self,
     ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, LoanError> {
         let shared_state = self.publisher_shared_state.lock();
+        self.loan_uninit_with_locked_state(&shared_state)
+    }
+
+    /// Loans `number_of_samples` [`SampleMutUninit`]s while the shared state is locked only
+    /// once and appends them to `samples`. It stops at the first failed loan and returns the
+    /// failure, the samples that were loaned up to this point remain in `samples`.
+    pub(crate) fn loan_uninit_batch(
+        &self,
+        number_of_samples: usize,
+        samples: &mut Vec<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>>,
+    ) -> Result<(), LoanError> {
+        let shared_state = self.publisher_shared_state.lock();
+        for _ in 0..number_of_samples {
+            samples.push(self.loan_uninit_with_locked_state(&shared_state)?);
+        }
+
+        Ok(())
+    }
+
+    fn loan_uninit_with_locked_state(
+        &self,
+        shared_state: &PublisherSharedState<Service>,
+    ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, LoanError> {
         let chunk = shared_state
             .sender
-            .allocate(shared_state.sender.sample_layout(1))?;
+            .allocate(shared_state.sample_layout(1))?;
         let node_id = shared_state.sender.service_state.shared_node.id();
         let header_ptr = chunk.header as *mut Header;
         let user_header_ptr: *mut UserHeader = chunk.user_header.cast();
@@ -623,9 +1690,106 @@ impl<
                 sample,
                 chunk.offset,
                 chunk.size,
+                shared_state.write_tracker(1),
             ),
         )
     }
+
+    /// Loans/allocates a [`SampleMutUninit`] like [`Publisher::loan_uninit()`] and attaches
+    /// the extended user header, initialized with its default value, to it. The extended user
+    /// header must be defined in the [`Service`](crate::service::Service) with
+    /// [`Builder::extended_user_header()`](crate::service::builder::publish_subscribe::Builder::extended_user_header())
+    /// and can be accessed by the [`crate::port::subscriber::Subscriber`] with
+    /// [`Sample::extended_user_header()`](crate::sample::Sample::extended_user_header()).
+    ///
+    /// On failure it returns [`ExtendedUserHeaderLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .extended_user_header::<u32>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan_with_extended_header::<u32>()?;
+    /// if let Some(extended_user_header) = sample.extended_user_header_mut::<u32>() {
+    ///     *extended_user_header = 73;
+    /// }
+    /// sample.write_payload(42).send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_with_extended_header<ExtendedUserHeader: Default + Debug + ZeroCopySend>(
+        &self,
+    ) -> Result<
+        SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>,
+        ExtendedUserHeaderLoanError,
+    > {
+        let msg = "Unable to loan sample with extended user header";
+        if !self
+            .publisher_shared_state
+            .lock()
+            .sender
+            .service_state
+            .static_config
+            .publish_subscribe()
+            .has_extended_user_header_type::<ExtendedUserHeader>()
+        {
+            fail!(from self, with ExtendedUserHeaderLoanError::IncompatibleExtendedUserHeaderType,
+                "{} since the service does not define the extended user header type {}.",
+                msg, core::any::type_name::<ExtendedUserHeader>());
+        }
+
+        let mut sample = fail!(from self, when self.loan_uninit(),
+                                "{} since the loan of a sample failed.", msg);
+        sample.attach_extended_user_header::<ExtendedUserHeader>();
+
+        Ok(sample)
+    }
+
+    /// Loans `number_of_samples` [`SampleMutUninit`]s upfront and returns them as
+    /// [`SamplePool`] from which they can be acquired without an additional loan. The pool
+    /// counts towards the maximum number of loaned samples of the [`Publisher`].
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    /// #                        .create()?;
+    ///
+    /// let mut pool = publisher.preallocate(2)?;
+    /// pool.acquire()?.write_payload(42).send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn preallocate(
+        &self,
+        number_of_samples: usize,
+    ) -> Result<SamplePool<'_, Service, Payload, UserHeader>, LoanError> {
+        Ok(
+            fail!(from self, when SamplePool::new(self, number_of_samples),
+                "Unable to preallocate {} samples since the loan of a sample failed.", number_of_samples),
+        )
+    }
 }
 
 impl<
@@ -664,6 +1828,45 @@ impl<
     pub fn loan(&self) -> Result<SampleMut<Service, Payload, UserHeader>, LoanError> {
         Ok(self.loan_uninit()?.write_payload(Payload::default()))
     }
+
+    /// Loans a [`crate::sample_mut::SampleMut`] like [`Publisher::loan()`] but returns
+    /// [`None`] when the [`Publisher`] is exhausted, meaning that either
+    /// [`LoanError::ExceedsMaxLoans`], [`LoanError::SegmentFull`] or
+    /// [`LoanError::OutOfMemory`] occurred. Useful for
+    /// polling producers that just skip a cycle when no sample is available.
+    ///
+    /// All other failures are returned as [`LoanError`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// if let Some(mut sample) = publisher.try_loan()? {
+    ///     *sample.payload_mut() = 42;
+    ///     sample.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_loan(&self) -> Result<Option<SampleMut<Service, Payload, UserHeader>>, LoanError> {
+        match self.loan() {
+            Ok(sample) => Ok(Some(sample)),
+            Err(LoanError::ExceedsMaxLoans)
+            | Err(LoanError::SegmentFull)
+            | Err(LoanError::OutOfMemory) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
 }
 ////////////////////////
 // END: typed API
@@ -773,6 +1976,43 @@ impl<
         self.loan_slice_uninit_impl(slice_len, slice_len)
     }
 
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of
+    /// the [`Publisher`] and initializes all slice elements with the provided value.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    /// #                        .initial_max_slice_len(120)
+    /// #                        .create()?;
+    ///
+    /// let sample = publisher.loan_slice_filled(5, 0xff)?;
+    ///
+    /// sample.send()?;
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn loan_slice_filled(
+        &self,
+        slice_len: usize,
+        value: Payload,
+    ) -> Result<SampleMut<Service, [Payload], UserHeader>, LoanError>
+    where
+        Payload: Copy,
+    {
+        let sample = self.loan_slice_uninit(slice_len)?;
+        Ok(sample.write_from_fn(|_| value))
+    }
+
     fn loan_slice_uninit_impl(
         &self,
         slice_len: usize,
@@ -788,7 +2028,7 @@ impl<
                 slice_len, max_slice_len);
         }
 
-        let sample_layout = shared_state.sender.sample_layout(slice_len);
+        let sample_layout = shared_state.sample_layout(slice_len);
         let chunk = shared_state.sender.allocate(sample_layout)?;
         let user_header_ptr: *mut UserHeader = chunk.user_header.cast();
         let header_ptr = chunk.header as *mut Header;
@@ -813,11 +2053,66 @@ impl<
                 sample,
                 chunk.offset,
                 chunk.size,
+                shared_state.write_tracker(underlying_number_of_slice_elements),
             ),
         )
     }
 }
 
+impl<Service: service::Service, UserHeader: Default + Debug + ZeroCopySend>
+    Publisher<Service, [u8], UserHeader>
+{
+    /// Copies `len` bytes starting at `ptr` directly into a loaned slot and delivers it,
+    /// without exposing the intermediate [`SampleMutUninit`] to the user.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`SendError`] describing the failure.
+    ///
+    /// # Safety
+    ///
+    ///  * `ptr` must be valid for reads of `len` bytes
+    ///  * the memory `ptr` points to must be initialized and must not be modified concurrently
+    ///    during the call
+    ///  * the memory `ptr` points to must not overlap with the data segment of the
+    ///    [`Publisher`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    /// #                        .initial_max_slice_len(16)
+    /// #                        .create()?;
+    ///
+    /// let external_buffer = [1u8, 2, 3, 4];
+    /// unsafe { publisher.send_external_bytes(external_buffer.as_ptr(), external_buffer.len())? };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn send_external_bytes(
+        &self,
+        ptr: *const u8,
+        len: usize,
+    ) -> Result<usize, SendError> {
+        let msg = "Unable to send external bytes";
+        let mut sample = fail!(from self, when self.loan_slice_uninit(len),
+                                    "{} since the loan of a sample failed.", msg);
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(ptr, sample.payload_mut().as_mut_ptr().cast(), len);
+            sample.mark_all_as_written();
+            sample.assume_init()
+        }
+        .send()
+    }
+}
+
 impl<Service: service::Service> Publisher<Service, [CustomPayloadMarker], CustomHeaderMarker> {
     /// # Safety
     ///
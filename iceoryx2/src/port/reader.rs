@@ -140,7 +140,6 @@ impl core::fmt::Display for ReaderCreateError {
 impl core::error::Error for ReaderCreateError {}
 
 /// Reading endpoint of a blackboard based communication.
-#[derive(Debug)]
 pub struct Reader<
     Service: service::Service,
     KeyType: Send + Sync + Eq + Clone + Copy + Debug + 'static + Hash + ZeroCopySend,
@@ -149,6 +148,24 @@ pub struct Reader<
     reader_id: UniqueReaderId,
 }
 
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Copy + Debug + 'static + Hash + ZeroCopySend,
+> Debug for Reader<Service, KeyType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.shared_state.service_state.shared_node.log_prefix()
+        )?;
+        f.debug_struct("Reader")
+            .field("shared_state", &self.shared_state)
+            .field("reader_id", &self.reader_id)
+            .finish()
+    }
+}
+
 impl<
     Service: service::Service,
     KeyType: Send + Sync + Eq + Clone + Copy + Debug + 'static + Hash + ZeroCopySend,
@@ -157,7 +174,7 @@ impl<
     pub(crate) fn new(
         service: Arc<ServiceState<Service, BlackboardResources<Service>>>,
     ) -> Result<Self, ReaderCreateError> {
-        let origin = "Reader::new()";
+        let origin = service.shared_node.log_origin("Reader::new()");
         let msg = "Unable to create Reader port";
 
         let reader_id = UniqueReaderId::new();
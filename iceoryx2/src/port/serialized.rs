@@ -0,0 +1,199 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .serialized::<String>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher()?;
+//! let subscriber = service.subscriber()?;
+//!
+//! publisher.send(&"hello world".to_string())?;
+//!
+//! if let Some(message) = subscriber.receive()? {
+//!     println!("received {}", message);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_cal::serialize::Serialize as _;
+use iceoryx2_cal::serialize::postcard::Postcard;
+use iceoryx2_log::fail;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::port::publisher::Publisher;
+use crate::port::subscriber::Subscriber;
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service;
+
+use super::{ReceiveError, SendError};
+
+/// Failure that can be emitted when a [`SerializedPublisher`] sends a value.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerializedSendError {
+    /// The value could not be serialized.
+    SerializationFailure,
+    /// A failure occurred while sending the serialized value.
+    SendError(SendError),
+}
+
+impl From<SendError> for SerializedSendError {
+    fn from(value: SendError) -> Self {
+        SerializedSendError::SendError(value)
+    }
+}
+
+impl core::fmt::Display for SerializedSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SerializedSendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SerializedSendError {}
+
+/// Failure that can be emitted when a [`SerializedSubscriber`] receives a value.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerializedReceiveError {
+    /// The received bytes could not be deserialized into the value. The received data was
+    /// discarded.
+    DeserializationFailure,
+    /// A failure occurred while receiving the serialized value.
+    ReceiveError(ReceiveError),
+}
+
+impl From<ReceiveError> for SerializedReceiveError {
+    fn from(value: ReceiveError) -> Self {
+        SerializedReceiveError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for SerializedReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SerializedReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for SerializedReceiveError {}
+
+/// Sending endpoint of a serialized communication. Every value is serialized into a
+/// [`Sample`](crate::sample::Sample) with a `[u8]` payload.
+#[derive(Debug)]
+pub struct SerializedPublisher<Service: service::Service, T: Debug + Serialize + DeserializeOwned> {
+    publisher: Publisher<Service, [u8], ()>,
+    _data: PhantomData<T>,
+}
+
+impl<Service: service::Service, T: Debug + Serialize + DeserializeOwned>
+    SerializedPublisher<Service, T>
+{
+    pub(crate) fn new(publisher: Publisher<Service, [u8], ()>) -> Self {
+        Self {
+            publisher,
+            _data: PhantomData,
+        }
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`SerializedPublisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.publisher.id()
+    }
+
+    /// Serializes `value` into a [`Sample`](crate::sample::Sample) and delivers it. On
+    /// success it returns the number of [`SerializedSubscriber`]s that received the value.
+    pub fn send(&self, value: &T) -> Result<usize, SerializedSendError> {
+        let bytes = fail!(from self.publisher.log_origin(self), when Postcard::serialize(value),
+            with SerializedSendError::SerializationFailure,
+            "Unable to send {:?} since it could not be serialized.", value);
+
+        let sample = self
+            .publisher
+            .loan_slice_uninit(bytes.len())
+            .map_err(SendError::from)?;
+        Ok(sample.write_from_slice(&bytes).send()?)
+    }
+}
+
+impl<Service: service::Service, T: Debug + Serialize + DeserializeOwned> UpdateConnections
+    for SerializedPublisher<Service, T>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.publisher.update_connections()
+    }
+}
+
+/// Receiving endpoint of a serialized communication. Every received
+/// [`Sample`](crate::sample::Sample) is deserialized into a copy of the value and released
+/// right away.
+#[derive(Debug)]
+pub struct SerializedSubscriber<Service: service::Service, T: Debug + Serialize + DeserializeOwned>
+{
+    subscriber: Subscriber<Service, [u8], ()>,
+    _data: PhantomData<T>,
+}
+
+impl<Service: service::Service, T: Debug + Serialize + DeserializeOwned>
+    SerializedSubscriber<Service, T>
+{
+    pub(crate) fn new(subscriber: Subscriber<Service, [u8], ()>) -> Self {
+        Self {
+            subscriber,
+            _data: PhantomData,
+        }
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the [`SerializedSubscriber`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.subscriber.id()
+    }
+
+    /// Returns true when at least one value is pending.
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        self.subscriber.has_samples()
+    }
+
+    /// Receives the next value and deserializes it. Returns [`None`] when no value was
+    /// pending.
+    pub fn receive(&self) -> Result<Option<T>, SerializedReceiveError> {
+        let sample = match self.subscriber.receive()? {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        let value = fail!(from self.subscriber.log_origin(self), when Postcard::deserialize(sample.payload()),
+            with SerializedReceiveError::DeserializationFailure,
+            "Unable to receive value since the received {} bytes could not be deserialized.",
+            sample.payload().len());
+
+        Ok(Some(value))
+    }
+}
+
+impl<Service: service::Service, T: Debug + Serialize + DeserializeOwned> UpdateConnections
+    for SerializedSubscriber<Service, T>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.subscriber.update_connections()
+    }
+}
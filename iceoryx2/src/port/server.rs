@@ -73,6 +73,8 @@
 //! # }
 //! ```
 
+use crate::node::NodeLogOrigin;
+use crate::port::unable_to_deliver_strategy::AtomicUnableToDeliverStrategy;
 use crate::port::update_connections::UpdateConnections;
 use crate::prelude::UnableToDeliverStrategy;
 use crate::service::NoResource;
@@ -89,10 +91,11 @@ use crate::{
         port_factory::server::{PortFactoryServer, ServerCreateError},
     },
 };
+use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 use core::{fmt::Debug, marker::PhantomData};
-use iceoryx2_bb_concurrency::atomic::AtomicUsize;
 use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicUsize};
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_container::slotmap::SlotMap;
 use iceoryx2_bb_container::vector::polymorphic_vec::*;
@@ -148,7 +151,16 @@ impl<Service: service::Service> Drop for SharedServerState<Service> {
 }
 
 impl<Service: service::Service> SharedServerState<Service> {
+    pub(crate) fn log_origin<'a, T: Debug + ?Sized>(
+        &'a self,
+        origin: &'a T,
+    ) -> NodeLogOrigin<'a, T> {
+        self.service_state.shared_node.log_origin(origin)
+    }
+
     pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        let reconnect_requested = self.request_receiver.take_reconnect_request()
+            | self.response_sender.take_reconnect_request();
         if unsafe {
             self.request_receiver
                 .service_state
@@ -157,8 +169,9 @@ impl<Service: service::Service> SharedServerState<Service> {
                 .request_response()
                 .clients
                 .update_state(&mut *self.client_list_state.get())
-        } {
-            fail!(from self,
+        } || reconnect_requested
+        {
+            fail!(from self.log_origin(self),
                   when self.force_update_connections(),
                   "Connections were updated only partially since at least one connection to a client failed.");
         }
@@ -213,7 +226,6 @@ impl<Service: service::Service> SharedServerState<Service> {
 /// [`Client`](crate::port::client::Client) and responds with
 /// [`Response`](crate::response::Response) by using an
 /// [`ActiveRequest`].
-#[derive(Debug)]
 pub struct Server<
     Service: service::Service,
     RequestPayload: Debug + ZeroCopySend + ?Sized,
@@ -228,6 +240,32 @@ pub struct Server<
     _request_header: PhantomData<RequestHeader>,
     _response_payload: PhantomData<ResponsePayload>,
     _response_header: PhantomData<ResponseHeader>,
+    log_prefix: String,
+}
+
+impl<
+    Service: service::Service,
+    RequestPayload: Debug + ZeroCopySend + ?Sized,
+    RequestHeader: Debug + ZeroCopySend,
+    ResponsePayload: Debug + ZeroCopySend + ?Sized,
+    ResponseHeader: Debug + ZeroCopySend,
+> Debug for Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}Server<{}, {}, {}, {}, {}> {{ shared_state: {:?}, max_loaned_responses_per_request: {:?}, enable_fire_and_forget: {:?} }}",
+            self.log_prefix,
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<RequestHeader>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseHeader>(),
+            self.shared_state,
+            self.max_loaned_responses_per_request,
+            self.enable_fire_and_forget,
+        )
+    }
 }
 
 unsafe impl<
@@ -286,9 +324,9 @@ impl<
         >,
     ) -> Result<Self, ServerCreateError> {
         let msg = "Failed to create Server port";
-        let origin = "Server::new()";
-        let server_id = UniqueServerId::new();
         let service = &server_factory.factory.service;
+        let origin = service.shared_node.log_origin("Server::new()");
+        let server_id = UniqueServerId::new();
         let static_config = server_factory.factory.static_config();
         let number_of_requests_per_client =
             unsafe { service.static_config.messaging_pattern.request_response() }
@@ -342,13 +380,13 @@ impl<
             } else {
                 None
             },
-            degradation_callback: server_factory.request_degradation_callback,
+            degradation_callback: UnsafeCell::new(server_factory.request_degradation_callback),
             number_of_channels: 1,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
             initial_channel_state: CHANNEL_STATE_OPEN,
+            reconnect_requested: AtomicBool::new(false),
         };
 
-        let global_config = service.shared_node.config();
         let data_segment_type = DataSegmentType::new_from_allocation_strategy(
             server_factory.config.allocation_strategy,
         );
@@ -362,15 +400,17 @@ impl<
             DataSegmentType::Static => DataSegment::<Service>::create_static_segment(
                 &segment_name,
                 sample_layout,
-                global_config,
+                &service.shared_node,
                 number_of_responses,
+                None,
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
                 sample_layout,
-                global_config,
+                &service.shared_node,
                 number_of_responses,
                 server_factory.config.allocation_strategy,
+                None,
             ),
         };
 
@@ -404,13 +444,18 @@ impl<
             number_of_samples: number_of_responses,
             max_number_of_segments,
             degradation_callback: server_factory.response_degradation_callback,
+            sample_reclaimed_callback: None,
             service_state: service.clone(),
             tagger: CyclicTagger::new(),
             loan_counter: AtomicUsize::new(0),
-            unable_to_deliver_strategy: server_factory.config.unable_to_deliver_strategy,
+            unable_to_deliver_strategy: Arc::new(AtomicUnableToDeliverStrategy::new(
+                server_factory.config.unable_to_deliver_strategy,
+            )),
             message_type_details: static_config.response_message_type_details,
             number_of_channels: number_of_requests_per_client,
             initial_channel_state: CHANNEL_STATE_CLOSED,
+            reconnect_requested: AtomicBool::new(false),
+            detached_connections: UnsafeCell::new(alloc::vec::Vec::new()),
         };
 
         let shared_state = Service::ArcThreadSafetyPolicy::new(SharedServerState {
@@ -439,6 +484,7 @@ impl<
                 .request_response()
                 .enable_fire_and_forget_requests,
             shared_state,
+            log_prefix: service.shared_node.log_prefix().to_string(),
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -511,6 +557,7 @@ impl<
             .lock()
             .response_sender
             .unable_to_deliver_strategy
+            .load()
     }
 
     fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
@@ -0,0 +1,133 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let signal = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .signal()
+//!     .open_or_create()?;
+//!
+//! let publisher = signal.publisher()?;
+//! let subscriber = signal.subscriber()?;
+//!
+//! publisher.notify()?;
+//! publisher.notify()?;
+//!
+//! if subscriber.receive_signal()?.is_some() {
+//!     // all pending signals are coalesced into a single one
+//!     println!("received {} signals", subscriber.coalesced_signals());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_concurrency::atomic::{AtomicUsize, Ordering};
+
+use crate::identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::port::publisher::Publisher;
+use crate::port::subscriber::Subscriber;
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service;
+
+use super::{ReceiveError, SendError};
+
+/// Sending endpoint of a signal based communication. Every notification is a
+/// [`Sample`](crate::sample::Sample) without payload.
+#[derive(Debug)]
+pub struct SignalPublisher<Service: service::Service> {
+    publisher: Publisher<Service, (), ()>,
+}
+
+impl<Service: service::Service> SignalPublisher<Service> {
+    pub(crate) fn new(publisher: Publisher<Service, (), ()>) -> Self {
+        Self { publisher }
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`SignalPublisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.publisher.id()
+    }
+
+    /// Notifies all connected [`SignalSubscriber`]s. On success it returns the number of
+    /// [`SignalSubscriber`]s that received the signal.
+    pub fn notify(&self) -> Result<usize, SendError> {
+        self.publisher.send_copy(())
+    }
+}
+
+impl<Service: service::Service> UpdateConnections for SignalPublisher<Service> {
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.publisher.update_connections()
+    }
+}
+
+/// Receiving endpoint of a signal based communication. All signals that are pending when
+/// [`SignalSubscriber::receive_signal()`] is called are coalesced into a single one.
+#[derive(Debug)]
+pub struct SignalSubscriber<Service: service::Service> {
+    subscriber: Subscriber<Service, (), ()>,
+    coalesced_signals: AtomicUsize,
+}
+
+impl<Service: service::Service> SignalSubscriber<Service> {
+    pub(crate) fn new(subscriber: Subscriber<Service, (), ()>) -> Self {
+        Self {
+            subscriber,
+            coalesced_signals: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the [`SignalSubscriber`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.subscriber.id()
+    }
+
+    /// Returns true when at least one signal is pending.
+    pub fn has_signals(&self) -> Result<bool, ConnectionFailure> {
+        self.subscriber.has_samples()
+    }
+
+    /// Receives all pending signals and coalesces them into one. Returns [`None`] when no
+    /// signal was pending. The number of coalesced signals can be acquired with
+    /// [`SignalSubscriber::coalesced_signals()`].
+    pub fn receive_signal(&self) -> Result<Option<()>, ReceiveError> {
+        let mut number_of_signals = 0;
+        while self.subscriber.receive()?.is_some() {
+            number_of_signals += 1;
+        }
+
+        if number_of_signals == 0 {
+            return Ok(None);
+        }
+
+        self.coalesced_signals
+            .store(number_of_signals, Ordering::Relaxed);
+        Ok(Some(()))
+    }
+
+    /// Returns the number of signals that were coalesced in the last successful
+    /// [`SignalSubscriber::receive_signal()`] call.
+    pub fn coalesced_signals(&self) -> usize {
+        self.coalesced_signals.load(Ordering::Relaxed)
+    }
+}
+
+impl<Service: service::Service> UpdateConnections for SignalSubscriber<Service> {
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.subscriber.update_connections()
+    }
+}
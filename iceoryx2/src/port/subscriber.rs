@@ -35,37 +35,47 @@ use core::any::TypeId;
 use core::fmt::Debug;
 use core::marker::PhantomData;
 
-use iceoryx2_bb_concurrency::atomic::Ordering;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64, Ordering};
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
-use iceoryx2_bb_container::slotmap::SlotMap;
+use iceoryx2_bb_container::slotmap::{SlotMap, SlotMapKey};
 use iceoryx2_bb_container::vector::polymorphic_vec::*;
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_elementary::cyclic_tagger::CyclicTagger;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::shm_allocator::PointerOffset;
 use iceoryx2_cal::zero_copy_connection::{CHANNEL_STATE_OPEN, ChannelId};
 use iceoryx2_log::{fail, warn};
 
+use crate::node::NodeLogOrigin;
+use crate::port::buffer_pool::{BufferPool, PooledBuffer};
+use crate::port::dispatch::{Dispatchable, EnumVisitor};
 use crate::port::update_connections::UpdateConnections;
+use crate::port::{DegradationAction, DegradationCallback};
 use crate::service::builder::CustomPayloadMarker;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::subscriber::SubscriberConfig;
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::service::{NoResource, ServiceState};
-use crate::{raw_sample::RawSample, sample::Sample, service};
+use crate::{raw_sample::RawSample, sample::Sample, sample_guard::SampleGuard, service};
 
 use super::ReceiveError;
 use super::details::chunk::Chunk;
 use super::details::chunk_details::ChunkDetails;
 use super::details::receiver::*;
 use super::update_connections::ConnectionFailure;
-use crate::identifiers::UniqueSubscriberId;
+use crate::identifiers::{SampleId, UniquePublisherId, UniqueSubscriberId};
 
+use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 
 /// Describes the failures when a new [`Subscriber`] is created via the
@@ -93,26 +103,271 @@ impl core::fmt::Display for SubscriberCreateError {
 
 impl core::error::Error for SubscriberCreateError {}
 
+/// Describes the failures when a [`Subscriber`] requests a re-delivery of the history with
+/// [`Subscriber::request_resync()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ResyncError {
+    /// The [`Service`](crate::service::Service) was created without a history, so there is
+    /// nothing that could be re-delivered.
+    HistoryNotEnabled,
+    /// The connections to the [`Publisher`](crate::port::publisher::Publisher)s could not be
+    /// established.
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl core::fmt::Display for ResyncError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ResyncError::{self:?}")
+    }
+}
+
+impl core::error::Error for ResyncError {}
+
+#[derive(Debug, Clone, Copy)]
+struct LastSequenceNumber {
+    publisher_id: u128,
+    value: u64,
+}
+
+#[derive(Debug)]
+struct PendingRelease {
+    details: ChunkDetails,
+    sequence_number: u64,
+}
+
+#[derive(Debug)]
+struct SharedSampleRef {
+    connection_key: SlotMapKey,
+    offset: PointerOffset,
+    count: usize,
+}
+
 #[derive(Debug)]
 pub(crate) struct SubscriberSharedState<Service: service::Service> {
     pub(crate) receiver: Receiver<Service>,
     pub(crate) publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    last_sequence_numbers:
+        PolymorphicVec<'static, UnsafeCell<Option<LastSequenceNumber>>, HeapAllocator>,
+    reject_foreign_endian: bool,
+    payload_alignment: Option<Alignment>,
+    connect_only_to: Option<UniquePublisherId>,
+    payload_size_stats: Option<UnsafeCell<PayloadSizeStatsTracker>>,
+    drop_expired: bool,
+    expired_samples: AtomicU64,
+    shared_sample_refs: UnsafeCell<Vec<SharedSampleRef>>,
+    manual_release: bool,
+    pending_releases: UnsafeCell<Vec<PendingRelease>>,
+}
+
+impl<Service: service::Service> SubscriberSharedState<Service> {
+    /// Returns true when expired samples shall be dropped and the sample with the provided
+    /// header is expired.
+    fn is_expired(&self, header: &Header) -> bool {
+        if !self.drop_expired {
+            return false;
+        }
+
+        let expiry = match header.expiry() {
+            Some(expiry) => expiry,
+            None => return false,
+        };
+
+        match Time::now_with_clock(ClockType::Monotonic) {
+            Ok(now) => now.as_duration() >= expiry,
+            Err(e) => {
+                warn!(from self.receiver.service_state.shared_node.log_origin(self),
+                    "Unable to verify the expiry of the received sample since the monotonic clock could not be read ({:?}). The sample is treated as not expired.", e);
+                false
+            }
+        }
+    }
+
+    /// Registers an additional [`Sample`] that refers to the slot of the provided chunk. Must
+    /// be called while the lock of the [`SubscriberSharedState`] is held.
+    pub(crate) fn acquire_shared_sample_ref(&self, details: &ChunkDetails) {
+        let refs = unsafe { &mut *self.shared_sample_refs.get() };
+        match refs
+            .iter_mut()
+            .find(|r| r.connection_key == details.connection_key && r.offset == details.offset)
+        {
+            Some(r) => r.count += 1,
+            None => refs.push(SharedSampleRef {
+                connection_key: details.connection_key,
+                offset: details.offset,
+                count: 2,
+            }),
+        }
+    }
+
+    /// Removes a [`Sample`] that refers to the slot of the provided chunk and returns true
+    /// when the slot is still referenced by another [`Sample`] and must not be released yet.
+    /// Must be called while the lock of the [`SubscriberSharedState`] is held.
+    pub(crate) fn release_shared_sample_ref(&self, details: &ChunkDetails) -> bool {
+        let refs = unsafe { &mut *self.shared_sample_refs.get() };
+        match refs
+            .iter()
+            .position(|r| r.connection_key == details.connection_key && r.offset == details.offset)
+        {
+            Some(index) => {
+                refs[index].count -= 1;
+                if refs[index].count == 1 {
+                    refs.swap_remove(index);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Defers the release of the slot of a dropped [`Sample`] until
+    /// [`Subscriber::release_all()`] or [`Subscriber::release_up_to()`] is called and returns
+    /// true, when the [`Subscriber`] was created with manual release. Otherwise, it returns
+    /// false and the slot must be released right away. Must be called while the lock of the
+    /// [`SubscriberSharedState`] is held.
+    pub(crate) fn defer_release(&self, details: &ChunkDetails, sequence_number: u64) -> bool {
+        if !self.manual_release {
+            return false;
+        }
+
+        unsafe { &mut *self.pending_releases.get() }.push(PendingRelease {
+            details: details.clone(),
+            sequence_number,
+        });
+        true
+    }
+
+    fn release_pending<F: Fn(&PendingRelease) -> bool>(&self, predicate: F) -> usize {
+        let pending_releases = unsafe { &mut *self.pending_releases.get() };
+        let number_of_pending_releases = pending_releases.len();
+        pending_releases.retain(|pending| {
+            if !predicate(pending) {
+                return true;
+            }
+
+            self.receiver
+                .release_offset(&pending.details, ChannelId::new(0));
+            false
+        });
+
+        number_of_pending_releases - pending_releases.len()
+    }
+
+    /// Stores the sequence number of the received sample and returns true when there is a gap
+    /// to the previously received sample of the same publisher.
+    fn update_last_sequence_number(&self, details: &ChunkDetails, header: &Header) -> bool {
+        let last_sequence_number =
+            unsafe { &mut *self.last_sequence_numbers[details.connection_key.value()].get() };
+
+        let has_gap = match last_sequence_number {
+            Some(last) if last.publisher_id == details.origin => {
+                header.sequence_number() > last.value.saturating_add(1)
+            }
+            _ => false,
+        };
+
+        *last_sequence_number = Some(LastSequenceNumber {
+            publisher_id: details.origin,
+            value: header.sequence_number(),
+        });
+
+        has_gap
+    }
+
+    fn last_sequence_number(&self, publisher_id: u128) -> Option<u64> {
+        self.last_sequence_numbers
+            .iter()
+            .filter_map(|entry| unsafe { *entry.get() })
+            .find(|entry| entry.publisher_id == publisher_id)
+            .map(|entry| entry.value)
+    }
+}
+
+/// The payload size statistics of all [`Sample`]s a [`Subscriber`] has received. Acquired
+/// via [`Subscriber::payload_size_stats()`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PayloadSizeStats {
+    /// The smallest received payload size in bytes.
+    pub min: usize,
+    /// The largest received payload size in bytes.
+    pub max: usize,
+    /// The average received payload size in bytes.
+    pub avg: f64,
+    /// The number of received [`Sample`]s.
+    pub count: u64,
+}
+
+#[derive(Debug, Default)]
+struct PayloadSizeStatsTracker {
+    min: usize,
+    max: usize,
+    sum: u128,
+    count: u64,
+}
+
+impl PayloadSizeStatsTracker {
+    fn record(&mut self, payload_size: usize) {
+        if self.count == 0 {
+            self.min = payload_size;
+            self.max = payload_size;
+        } else {
+            self.min = self.min.min(payload_size);
+            self.max = self.max.max(payload_size);
+        }
+        self.sum += payload_size as u128;
+        self.count += 1;
+    }
+
+    fn stats(&self) -> PayloadSizeStats {
+        PayloadSizeStats {
+            min: self.min,
+            max: self.max,
+            avg: if self.count == 0 {
+                0.0
+            } else {
+                self.sum as f64 / self.count as f64
+            },
+            count: self.count,
+        }
+    }
 }
 
 /// The receiving endpoint of a publish-subscribe communication.
-#[derive(Debug)]
 pub struct Subscriber<
     Service: service::Service,
     Payload: Debug + ZeroCopySend + ?Sized + 'static,
     UserHeader: Debug + ZeroCopySend,
 > {
     dynamic_subscriber_handle: Option<ContainerHandle>,
-    subscriber_shared_state: Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
+    pub(crate) subscriber_shared_state:
+        Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
+    log_prefix: String,
+    window: UnsafeCell<Vec<Sample<Service, Payload, UserHeader>>>,
+    window_size: usize,
 
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
 
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: Debug + ZeroCopySend,
+> Debug for Subscriber<Service, Payload, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}Subscriber<{}, {}, {}> {{ dynamic_subscriber_handle: {:?}, subscriber_shared_state: {:?} }}",
+            self.log_prefix,
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<UserHeader>(),
+            self.dynamic_subscriber_handle,
+            self.subscriber_shared_state
+        )
+    }
+}
+
 unsafe impl<
     Service: service::Service,
     Payload: Debug + ZeroCopySend + ?Sized,
@@ -165,12 +420,18 @@ impl<
         config: SubscriberConfig,
     ) -> Result<Self, SubscriberCreateError> {
         let msg = "Failed to create Subscriber port";
-        let origin = "Subscriber::new()";
+        let origin = service.shared_node.log_origin("Subscriber::new()");
         let subscriber_id = UniqueSubscriberId::new();
 
         let publisher_list = &service.dynamic_storage.get().publish_subscribe().publishers;
 
+        if config.keep_latest_only && !static_config.enable_safe_overflow {
+            warn!(from origin,
+                "The subscriber keeps only the latest sample but the service has safe overflow disabled, so queued samples are not replaced by newer ones.");
+        }
+
         let buffer_size = match config.buffer_size {
+            _ if config.keep_latest_only => 1,
             Some(buffer_size) => {
                 if static_config.subscriber_max_buffer_size < buffer_size {
                     fail!(from origin, with SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService,
@@ -215,11 +476,31 @@ impl<
                     )
                     .expect("Heap allocator provides memory."),
                 )),
-                degradation_callback: config.degradation_callback,
+                degradation_callback: UnsafeCell::new(config.degradation_callback),
                 number_of_channels: 1,
                 connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
                 initial_channel_state: CHANNEL_STATE_OPEN,
+                reconnect_requested: AtomicBool::new(false),
+            },
+            last_sequence_numbers: PolymorphicVec::from_fn(
+                HeapAllocator::global(),
+                number_of_connections,
+                |_| UnsafeCell::new(None),
+            )
+            .expect("Heap allocator provides memory."),
+            reject_foreign_endian: config.reject_foreign_endian,
+            payload_alignment: config.payload_alignment,
+            connect_only_to: config.connect_only_to,
+            payload_size_stats: if config.track_payload_size_stats {
+                Some(UnsafeCell::new(PayloadSizeStatsTracker::default()))
+            } else {
+                None
             },
+            drop_expired: config.drop_expired,
+            expired_samples: AtomicU64::new(0),
+            shared_sample_refs: UnsafeCell::new(Vec::new()),
+            manual_release: config.manual_release,
+            pending_releases: UnsafeCell::new(Vec::new()),
         });
 
         let subscriber_shared_state = match subscriber_shared_state {
@@ -234,6 +515,13 @@ impl<
         let mut new_self = Self {
             subscriber_shared_state,
             dynamic_subscriber_handle: None,
+            log_prefix: service.shared_node.log_prefix().to_string(),
+            window: UnsafeCell::new(Vec::new()),
+            window_size: config.retain_window.min(
+                static_config
+                    .subscriber_max_borrowed_samples
+                    .saturating_sub(1),
+            ),
             _payload: PhantomData,
             _user_header: PhantomData,
         };
@@ -255,6 +543,8 @@ impl<
                 subscriber_id,
                 buffer_size,
                 node_id: *service.shared_node.id(),
+                overflowed_samples: 0,
+                connect_only_to: config.connect_only_to,
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -280,6 +570,13 @@ impl<
         let mut result = Ok(());
         unsafe {
             (*subscriber_shared_state.publisher_list_state.get()).for_each(|h, details| {
+                if subscriber_shared_state
+                    .connect_only_to
+                    .is_some_and(|publisher_id| publisher_id != details.publisher_id)
+                {
+                    return CallbackProgression::Continue;
+                }
+
                 let inner_result = subscriber_shared_state.receiver.update_connection(
                     h.index() as usize,
                     SenderDetails {
@@ -304,6 +601,13 @@ impl<
         result
     }
 
+    pub(crate) fn log_origin<'a, T: Debug + ?Sized>(
+        &'a self,
+        origin: &'a T,
+    ) -> NodeLogOrigin<'a, T> {
+        NodeLogOrigin::new(&self.log_prefix, origin)
+    }
+
     /// Returns the [`UniqueSubscriberId`] of the [`Subscriber`]
     pub fn id(&self) -> UniqueSubscriberId {
         UniqueSubscriberId(UniqueSystemId::from(
@@ -314,6 +618,172 @@ impl<
         ))
     }
 
+    /// Returns the sequence number of the last [`Sample`] that was received from the
+    /// [`Publisher`](crate::port::publisher::Publisher) with the provided
+    /// [`UniquePublisherId`]. If no [`Sample`] was received from it so far, [`None`] is returned.
+    /// It can be used to detect gaps by comparing it with [`Sample::sequence_number()`].
+    pub fn last_sequence_number(&self, publisher_id: UniquePublisherId) -> Option<u64> {
+        self.subscriber_shared_state
+            .lock()
+            .last_sequence_number(publisher_id.value())
+    }
+
+    /// Returns the number of [`Sample`]s that were discarded on receive since their expiry
+    /// passed. It stays zero unless the [`Subscriber`] was created with
+    /// [`PortFactorySubscriber::drop_expired()`](crate::service::port_factory::subscriber::PortFactorySubscriber::drop_expired).
+    pub fn expired_samples(&self) -> u64 {
+        self.subscriber_shared_state
+            .lock()
+            .expired_samples
+            .load(Ordering::Relaxed)
+    }
+
+    /// Returns the most recently received [`Sample`]s, ordered from the oldest to the newest,
+    /// when the [`Subscriber`] was created with
+    /// [`PortFactorySubscriber::retain_window()`](crate::service::port_factory::subscriber::PortFactorySubscriber::retain_window),
+    /// otherwise an empty slice.
+    pub fn window(&mut self) -> &[Sample<Service, Payload, UserHeader>] {
+        self.window.get_mut()
+    }
+
+    /// Releases the slots of all dropped [`Sample`]s when the [`Subscriber`] was created with
+    /// [`PortFactorySubscriber::manual_release()`](crate::service::port_factory::subscriber::PortFactorySubscriber::manual_release)
+    /// and returns how many slots were released. [`Sample`]s that are still alive are not
+    /// affected.
+    pub fn release_all(&self) -> usize {
+        self.subscriber_shared_state
+            .lock()
+            .release_pending(|_| true)
+    }
+
+    /// Releases the slots of all dropped [`Sample`]s of the
+    /// [`Publisher`](crate::port::publisher::Publisher) that sent the [`Sample`] with the
+    /// provided [`SampleId`], up to and including it, and returns how many slots were
+    /// released. It has only an effect when the [`Subscriber`] was created with
+    /// [`PortFactorySubscriber::manual_release()`](crate::service::port_factory::subscriber::PortFactorySubscriber::manual_release).
+    pub fn release_up_to(&self, sample_id: SampleId) -> usize {
+        let publisher_id = sample_id.publisher_id().value();
+        self.subscriber_shared_state
+            .lock()
+            .release_pending(|pending| {
+                pending.details.origin == publisher_id
+                    && pending.sequence_number <= sample_id.sequence_number()
+            })
+    }
+
+    /// Asks every connected [`Publisher`](crate::port::publisher::Publisher) to re-deliver
+    /// its history to this [`Subscriber`] and returns the number of samples the
+    /// [`Publisher`](crate::port::publisher::Publisher)s queue for the re-delivery. Like the
+    /// history of a new connection, the number is limited by the buffer size of the
+    /// [`Subscriber`].
+    ///
+    /// # Important
+    ///
+    /// The request is not served immediately. A
+    /// [`Publisher`](crate::port::publisher::Publisher) re-delivers its history only when it
+    /// updates its connections, on its next send or on an explicit
+    /// [`Publisher::update_connections()`](crate::port::update_connections::UpdateConnections::update_connections()).
+    /// A [`Publisher`](crate::port::publisher::Publisher) that neither sends nor updates its
+    /// connections never replays its history.
+    pub fn request_resync(&self) -> Result<usize, ResyncError> {
+        let msg = "Unable to request a resync";
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        if subscriber_shared_state
+            .receiver
+            .service_state
+            .static_config
+            .publish_subscribe()
+            .history_size
+            == 0
+        {
+            fail!(from self, with ResyncError::HistoryNotEnabled,
+                "{msg} since the service has no history.");
+        }
+        drop(subscriber_shared_state);
+
+        if let Err(e) = self.update_connections() {
+            fail!(from self, with ResyncError::ConnectionFailure(e),
+                "{msg} since the connections to the publishers could not be updated ({e:?}).");
+        }
+
+        Ok(self
+            .subscriber_shared_state
+            .lock()
+            .receiver
+            .request_resync(ChannelId::new(0)))
+    }
+
+    /// Returns the [`UniquePublisherId`]s of all
+    /// [`Publisher`](crate::port::publisher::Publisher)s this [`Subscriber`] is currently
+    /// connected to, including the ones that were re-established after a
+    /// [`DegradationAction::Reconnect`].
+    pub fn connected_publishers(&self) -> impl Iterator<Item = UniquePublisherId> {
+        if let Err(e) = self.update_connections() {
+            warn!(from self, "Unable to update all connections while acquiring the connected publishers ({:?}).", e);
+        }
+
+        let mut publishers = Vec::new();
+        self.subscriber_shared_state
+            .lock()
+            .receiver
+            .for_each_active_connection(|connection| {
+                publishers.push(UniquePublisherId(UniqueSystemId::from(
+                    connection.sender_port_id,
+                )));
+            });
+
+        publishers.into_iter()
+    }
+
+    fn retain_in_window(&self, sample: &Sample<Service, Payload, UserHeader>) {
+        if self.window_size == 0 {
+            return;
+        }
+
+        let retained_sample = sample.clone_ref();
+        // the slid out sample is dropped after the lock was released since its drop acquires
+        // the lock as well
+        let _slid_out_sample = {
+            let _subscriber_shared_state = self.subscriber_shared_state.lock();
+            let window = unsafe { &mut *self.window.get() };
+            window.push(retained_sample);
+            (window.len() > self.window_size).then(|| window.remove(0))
+        };
+    }
+
+    /// Returns the payload size statistics of all received [`Sample`]s when the [`Subscriber`]
+    /// was created with
+    /// [`PortFactorySubscriber::track_payload_size_stats()`](crate::service::port_factory::subscriber::PortFactorySubscriber::track_payload_size_stats),
+    /// otherwise [`None`].
+    pub fn payload_size_stats(&self) -> Option<PayloadSizeStats> {
+        self.subscriber_shared_state
+            .lock()
+            .payload_size_stats
+            .as_ref()
+            .map(|tracker| unsafe { &*tracker.get() }.stats())
+    }
+
+    /// Replaces the degradation callback of the [`Subscriber`] that was defined with
+    /// [`PortFactorySubscriber::set_degradation_callback()`](crate::service::port_factory::subscriber::PortFactorySubscriber::set_degradation_callback).
+    /// It is called whenever the [`Subscriber`] is unable to connect to a
+    /// [`Publisher`](crate::port::publisher::Publisher). When [`None`] is provided, the
+    /// default behavior of warning about the degradation is restored. The callback is replaced
+    /// under the same lock that is held while connections are updated, so an ongoing
+    /// degradation check either completes with the old or starts with the new callback.
+    ///
+    /// The callback must not call this method on its own [`Subscriber`].
+    pub fn set_degradation_callback<
+        F: Fn(&service::static_config::StaticConfig, u128, u128) -> DegradationAction + 'static,
+    >(
+        &self,
+        callback: Option<F>,
+    ) {
+        self.subscriber_shared_state
+            .lock()
+            .receiver
+            .set_degradation_callback(callback.map(DegradationCallback::new));
+    }
+
     /// Returns the internal buffer size of the [`Subscriber`].
     pub fn buffer_size(&self) -> usize {
         self.subscriber_shared_state.lock().receiver.buffer_size
@@ -323,21 +793,192 @@ impl<
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
-        Ok(self
-            .subscriber_shared_state
+        Ok(self.has_buffered_samples())
+    }
+
+    /// Discards all samples that are currently queued, releases them back to their
+    /// [`Publisher`](crate::port::publisher::Publisher)s and returns how many were discarded.
+    /// Afterwards the buffer of the [`Subscriber`] is empty. Samples that were already
+    /// received are not affected.
+    pub fn clear(&self) -> usize {
+        if let Err(e) = self.update_connections() {
+            warn!(from self, "Only the samples of connected publishers are discarded since not all connections could be established ({:?}).", e);
+        }
+
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let mut number_of_discarded_samples = 0;
+        loop {
+            match subscriber_shared_state.receiver.receive(ChannelId::new(0)) {
+                Ok(Some((details, chunk))) => {
+                    subscriber_shared_state.update_last_sequence_number(&details, unsafe {
+                        &*(chunk.header as *const Header)
+                    });
+                    subscriber_shared_state
+                        .receiver
+                        .release_offset(&details, ChannelId::new(0));
+                    number_of_discarded_samples += 1;
+                }
+                Ok(None) => return number_of_discarded_samples,
+                Err(e) => {
+                    warn!(from self, "Stopped to discard samples after {} samples since the next sample could not be acquired ({:?}).",
+                        number_of_discarded_samples, e);
+                    return number_of_discarded_samples;
+                }
+            }
+        }
+    }
+
+    fn has_buffered_samples(&self) -> bool {
+        self.subscriber_shared_state
             .lock()
             .receiver
-            .has_samples(ChannelId::new(0)))
+            .has_samples(ChannelId::new(0))
+    }
+
+    /// Receives the next payload and copies it into a free buffer of the provided
+    /// [`BufferPool`]. The underlying shared memory is released immediately so that no
+    /// [`Sample`] has to be held. If no sample could be received [`None`] is returned.
+    /// When all buffers of the [`BufferPool`] are in use, [`ReceiveError::PoolExhausted`] is
+    /// returned and the sample remains queued. If the payload does not fit into a buffer it
+    /// is discarded and [`ReceiveError::ExceedsPoolBufferSize`] is returned.
+    pub fn receive_into_pool<'pool>(
+        &self,
+        pool: &'pool BufferPool,
+    ) -> Result<Option<PooledBuffer<'pool>>, ReceiveError> {
+        let mut buffer = match pool.acquire() {
+            Some(buffer) => buffer,
+            None => {
+                if self.has_samples()? {
+                    fail!(from self, with ReceiveError::PoolExhausted,
+                        "Unable to receive sample into pool since all {} buffers are in use.", pool.number_of_buffers());
+                }
+                return Ok(None);
+            }
+        };
+
+        let (details, chunk, _) = match self.receive_impl()? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let header = unsafe { &*(chunk.header as *const Header) };
+        let payload_size = subscriber_shared_state
+            .receiver
+            .message_type_details
+            .payload
+            .size
+            * header.number_of_elements() as usize;
+
+        if buffer.capacity() < payload_size {
+            subscriber_shared_state
+                .receiver
+                .release_offset(&details, ChannelId::new(0));
+            fail!(from self, with ReceiveError::ExceedsPoolBufferSize,
+                "Unable to receive sample into pool since the payload size of {} bytes exceeds the buffer size of {} bytes.",
+                payload_size, buffer.capacity());
+        }
+
+        buffer.copy_from(unsafe {
+            core::slice::from_raw_parts(chunk.payload as *const u8, payload_size)
+        });
+        subscriber_shared_state
+            .receiver
+            .release_offset(&details, ChannelId::new(0));
+
+        Ok(Some(buffer))
     }
 
-    fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+    fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk, bool)>, ReceiveError> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
 
-        self.subscriber_shared_state
-            .lock()
-            .receiver
-            .receive(ChannelId::new(0))
+        self.receive_with_locked_state(&self.subscriber_shared_state.lock())
+    }
+
+    fn take_all_impl(&self) -> Result<Vec<(ChunkDetails, Chunk, bool)>, ReceiveError> {
+        fail!(from self, when self.update_connections(),
+                "Some samples are not being received since not all connections to publishers could be established.");
+
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let mut received = Vec::new();
+        loop {
+            match self.receive_with_locked_state(&subscriber_shared_state) {
+                Ok(Some(v)) => received.push(v),
+                Ok(None) => return Ok(received),
+                // the remaining samples stay queued until borrowed samples are released
+                Err(ReceiveError::ExceedsMaxBorrows) if !received.is_empty() => {
+                    return Ok(received);
+                }
+                Err(e) => {
+                    for (details, _, _) in &received {
+                        subscriber_shared_state
+                            .receiver
+                            .release_offset(details, ChannelId::new(0));
+                    }
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    fn receive_with_locked_state(
+        &self,
+        subscriber_shared_state: &SubscriberSharedState<Service>,
+    ) -> Result<Option<(ChunkDetails, Chunk, bool)>, ReceiveError> {
+        let (details, chunk) = loop {
+            let (details, chunk) = match subscriber_shared_state
+                .receiver
+                .receive(ChannelId::new(0))?
+            {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+
+            if subscriber_shared_state.is_expired(unsafe { &*(chunk.header as *const Header) }) {
+                subscriber_shared_state
+                    .receiver
+                    .release_offset(&details, ChannelId::new(0));
+                subscriber_shared_state
+                    .expired_samples
+                    .fetch_add(1, Ordering::Relaxed);
+                continue;
+            }
+
+            break (details, chunk);
+        };
+
+        let header = unsafe { &*(chunk.header as *const Header) };
+        if subscriber_shared_state.reject_foreign_endian && !header.is_native_endian() {
+            subscriber_shared_state
+                .receiver
+                .release_offset(&details, ChannelId::new(0));
+            fail!(from self, with ReceiveError::ForeignEndianness,
+                "Unable to receive sample since it was published by a process with a different byte order.");
+        }
+
+        if let Some(alignment) = subscriber_shared_state.payload_alignment {
+            if (chunk.payload as usize) % alignment.value() != 0 {
+                subscriber_shared_state
+                    .receiver
+                    .release_offset(&details, ChannelId::new(0));
+                fail!(from self, with ReceiveError::MisalignedPayload,
+                    "Unable to receive sample since its payload is not aligned to {}.", alignment.value());
+            }
+        }
+
+        if let Some(payload_size_stats) = &subscriber_shared_state.payload_size_stats {
+            let payload_size = subscriber_shared_state
+                .receiver
+                .message_type_details
+                .payload
+                .size
+                * header.number_of_elements() as usize;
+            unsafe { &mut *payload_size_stats.get() }.record(payload_size);
+        }
+
+        let has_gap = subscriber_shared_state.update_last_sequence_number(&details, header);
+        Ok(Some((details, chunk, has_gap)))
     }
 }
 
@@ -349,6 +990,7 @@ impl<
 {
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
         let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let reconnect_requested = subscriber_shared_state.receiver.take_reconnect_request();
         if unsafe {
             subscriber_shared_state
                 .receiver
@@ -358,7 +1000,8 @@ impl<
                 .publish_subscribe()
                 .publishers
                 .update_state(&mut *subscriber_shared_state.publisher_list_state.get())
-        } {
+        } || reconnect_requested
+        {
             fail!(from self, when self.force_update_connections(&subscriber_shared_state),
                 "Connections were updated only partially since at least one connection to a publisher failed.");
         }
@@ -372,10 +1015,47 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
 {
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    ///
+    /// Every call establishes the connections to [`crate::port::publisher::Publisher`]s that
+    /// appeared since the last call and removes the ones of [`crate::port::publisher::Publisher`]s
+    /// that are gone. A [`crate::port::publisher::Publisher`] that is recreated, for instance
+    /// after a restart of its process, is therefore picked up without any further action.
     pub fn receive(&self) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, chunk)| Sample {
+        Ok(self
+            .receive_impl()?
+            .map(|(details, chunk, has_gap)| self.create_sample(details, chunk, has_gap)))
+    }
+
+    /// Receives all [`crate::sample::Sample`]s that are currently queued, ordered from the
+    /// oldest to the newest, and leaves the buffer of the [`Subscriber`] empty. All
+    /// [`crate::sample::Sample`]s are taken in one critical section, so no concurrent
+    /// [`Subscriber::receive()`] call on the same [`Subscriber`] can interleave.
+    ///
+    /// At most as many [`crate::sample::Sample`]s are taken as can be borrowed in parallel,
+    /// see [`crate::service::builder::publish_subscribe::Builder::subscriber_max_borrowed_samples()`].
+    /// When the limit is reached, the [`crate::sample::Sample`]s taken so far are returned and
+    /// the remaining ones stay queued. If not a single [`crate::sample::Sample`] can be
+    /// borrowed, [`ReceiveError::ExceedsMaxBorrows`] is returned. If any other failure occurs,
+    /// the [`crate::sample::Sample`]s that were already taken are released and
+    /// [`ReceiveError`] is returned.
+    pub fn take_all(&self) -> Result<Vec<Sample<Service, Payload, UserHeader>>, ReceiveError> {
+        Ok(self
+            .take_all_impl()?
+            .into_iter()
+            .map(|(details, chunk, has_gap)| self.create_sample(details, chunk, has_gap))
+            .collect())
+    }
+
+    fn create_sample(
+        &self,
+        details: ChunkDetails,
+        chunk: Chunk,
+        has_gap: bool,
+    ) -> Sample<Service, Payload, UserHeader> {
+        let sample = Sample {
             subscriber_shared_state: self.subscriber_shared_state.clone(),
             details,
+            was_preceded_by_overflow: has_gap,
             ptr: unsafe {
                 RawSample::new_unchecked(
                     chunk.header.cast(),
@@ -383,7 +1063,68 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
                     chunk.payload.cast(),
                 )
             },
-        }))
+        };
+        self.retain_in_window(&sample);
+        sample
+    }
+
+    /// Receives the next [`crate::sample::Sample`] whose user header satisfies `predicate`.
+    /// All received [`crate::sample::Sample`]s that do not satisfy it are discarded and
+    /// their memory is returned to the [`crate::port::publisher::Publisher`]. If the
+    /// buffer runs empty before a matching [`crate::sample::Sample`] is found, [`None`] is
+    /// returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn receive_filtered<F: Fn(&UserHeader) -> bool>(
+        &self,
+        predicate: F,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
+        while let Some(sample) = self.receive()? {
+            if predicate(sample.user_header()) {
+                return Ok(Some(sample));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Receives a [`crate::sample::Sample`] like [`Subscriber::receive()`] but returns it as
+    /// [`SampleGuard`] that makes the point where its slot is released explicit with
+    /// [`SampleGuard::release()`]. The slot is also released when the [`SampleGuard`] goes
+    /// out of scope. Useful when a [`crate::sample::Sample`] is processed over several steps
+    /// before deciding when it is released.
+    pub fn receive_borrowed(
+        &self,
+    ) -> Result<Option<SampleGuard<Service, Payload, UserHeader>>, ReceiveError> {
+        Ok(self.receive()?.map(Sample::into_guard))
+    }
+
+    /// Receives a [`crate::sample::Sample`] like [`Subscriber::receive()`] and additionally
+    /// returns `true` when more [`crate::sample::Sample`]s are queued after it. The flag is a
+    /// momentary snapshot, [`crate::sample::Sample`]s that arrive later are not considered.
+    /// Useful to detect the end of the currently queued batch.
+    pub fn receive_with_more(
+        &self,
+    ) -> Result<Option<(Sample<Service, Payload, UserHeader>, bool)>, ReceiveError> {
+        Ok(self
+            .receive()?
+            .map(|sample| (sample, self.has_buffered_samples())))
+    }
+
+    /// Receives a [`crate::sample::Sample`] and routes it with [`Dispatchable::dispatch()`] to
+    /// the handler of its variant in `visitor`. Returns `true` when a
+    /// [`crate::sample::Sample`] was received and dispatched and `false` when no
+    /// [`crate::sample::Sample`] was available. If a failure occurs [`ReceiveError`] is
+    /// returned.
+    pub fn receive_dispatch<V: EnumVisitor>(&self, visitor: &mut V) -> Result<bool, ReceiveError>
+    where
+        Payload: Dispatchable<UserHeader, Visitor = V>,
+    {
+        match self.receive()? {
+            Some(sample) => {
+                sample.payload().dispatch(sample.user_header(), visitor);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
     }
 }
 
@@ -395,13 +1136,14 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
     pub fn receive(&self) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
-        Ok(self.receive_impl()?.map(|(details, chunk)| {
+        Ok(self.receive_impl()?.map(|(details, chunk, has_gap)| {
             let header_ptr = chunk.header as *const Header;
             let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
 
-            Sample {
+            let sample = Sample {
                 subscriber_shared_state: self.subscriber_shared_state.clone(),
                 details,
+                was_preceded_by_overflow: has_gap,
                 ptr: unsafe {
                     RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
                         header_ptr,
@@ -412,9 +1154,41 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
                         ),
                     )
                 },
-            }
+            };
+            self.retain_in_window(&sample);
+            sample
         }))
     }
+
+    /// Receives the next [`crate::sample::Sample`] whose user header satisfies `predicate`.
+    /// All received [`crate::sample::Sample`]s that do not satisfy it are discarded and
+    /// their memory is returned to the [`crate::port::publisher::Publisher`]. If the
+    /// buffer runs empty before a matching [`crate::sample::Sample`] is found, [`None`] is
+    /// returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn receive_filtered<F: Fn(&UserHeader) -> bool>(
+        &self,
+        predicate: F,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
+        while let Some(sample) = self.receive()? {
+            if predicate(sample.user_header()) {
+                return Ok(Some(sample));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Receives a [`crate::sample::Sample`] like [`Subscriber::receive()`] and additionally
+    /// returns `true` when more [`crate::sample::Sample`]s are queued after it. The flag is a
+    /// momentary snapshot, [`crate::sample::Sample`]s that arrive later are not considered.
+    /// Useful to detect the end of the currently queued batch.
+    pub fn receive_with_more(
+        &self,
+    ) -> Result<Option<(Sample<Service, [Payload], UserHeader>, bool)>, ReceiveError> {
+        Ok(self
+            .receive()?
+            .map(|sample| (sample, self.has_buffered_samples())))
+    }
 }
 
 impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
@@ -433,7 +1207,7 @@ impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
     pub unsafe fn receive_custom_payload(
         &self,
     ) -> Result<Option<Sample<Service, [CustomPayloadMarker], UserHeader>>, ReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, chunk)| {
+        Ok(self.receive_impl()?.map(|(details, chunk, has_gap)| {
             let header_ptr = chunk.header as *const Header;
             let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
             let number_of_bytes = number_of_elements as usize
@@ -442,6 +1216,7 @@ impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
             Sample {
                 subscriber_shared_state: self.subscriber_shared_state.clone(),
                 details,
+                was_preceded_by_overflow: has_gap,
                 ptr: unsafe {
                     RawSample::<Header, UserHeader, [CustomPayloadMarker]>::new_slice_unchecked(
                         header_ptr,
@@ -0,0 +1,79 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+
+use iceoryx2_cal::zero_copy_connection::ZeroCopyCreationError;
+
+use crate::identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::port::SendError;
+use crate::port::publisher::Publisher;
+use crate::port::subscriber::Subscriber;
+use crate::port::update_connections::ConnectionFailure;
+
+/// Handles the connection from the [`Publisher`] to the provided subscriber as if it were
+/// corrupted. Returns [`SendError::ConnectionCorrupted`] when the degradation callback of the
+/// [`Publisher`] returns [`DegradationAction::Fail`](crate::port::DegradationAction::Fail).
+///
+/// # Safety
+///
+///  * only for internal testing purposes
+///
+pub unsafe fn __internal_publisher_corrupt_connection<
+    S: crate::service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: Debug + ZeroCopySend,
+>(
+    publisher: &Publisher<S, Payload, UserHeader>,
+    subscriber_id: UniqueSubscriberId,
+) -> Result<(), SendError> {
+    let shared_state = publisher.publisher_shared_state.lock();
+    match shared_state
+        .sender
+        .get_connection_id_of(subscriber_id.value())
+    {
+        Some(connection_id) => shared_state
+            .sender
+            .handle_corrupted_connection(connection_id, "Injected corruption of the connection:"),
+        None => Ok(()),
+    }
+}
+
+/// Handles a failed connection attempt of the [`Subscriber`] to the provided publisher as if
+/// the connection could not be established. Returns the [`ConnectionFailure`] when the
+/// degradation callback of the [`Subscriber`] returns
+/// [`DegradationAction::Fail`](crate::port::DegradationAction::Fail).
+///
+/// # Safety
+///
+///  * only for internal testing purposes
+///
+pub unsafe fn __internal_subscriber_fail_connection<
+    S: crate::service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: Debug + ZeroCopySend,
+>(
+    subscriber: &Subscriber<S, Payload, UserHeader>,
+    publisher_id: UniquePublisherId,
+) -> Result<(), ConnectionFailure> {
+    subscriber
+        .subscriber_shared_state
+        .lock()
+        .receiver
+        .handle_failed_connection(
+            publisher_id.value(),
+            ConnectionFailure::FailedToEstablishConnection(ZeroCopyCreationError::InternalError),
+        )
+}
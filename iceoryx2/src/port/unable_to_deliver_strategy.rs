@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2_bb_concurrency::atomic::{AtomicU8, Ordering};
 use serde::{Deserialize, Serialize, de::Visitor};
 
 /// Defines the strategy a sender shall pursue when the buffer of the receiver is full
@@ -23,6 +24,15 @@ pub enum UnableToDeliverStrategy {
     DiscardSample,
 }
 
+impl UnableToDeliverStrategy {
+    pub(crate) fn from_raw(value: u8) -> Self {
+        match value {
+            v if v == UnableToDeliverStrategy::Block as u8 => UnableToDeliverStrategy::Block,
+            _ => UnableToDeliverStrategy::DiscardSample,
+        }
+    }
+}
+
 impl Serialize for UnableToDeliverStrategy {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -63,3 +73,22 @@ impl<'de> Deserialize<'de> for UnableToDeliverStrategy {
         deserializer.deserialize_str(UnableToDeliverStrategyVisitor)
     }
 }
+
+/// Stores an [`UnableToDeliverStrategy`] that can be changed while a sender is blocked in
+/// a delivery.
+#[derive(Debug)]
+pub(crate) struct AtomicUnableToDeliverStrategy(AtomicU8);
+
+impl AtomicUnableToDeliverStrategy {
+    pub(crate) fn new(value: UnableToDeliverStrategy) -> Self {
+        Self(AtomicU8::new(value as u8))
+    }
+
+    pub(crate) fn load(&self) -> UnableToDeliverStrategy {
+        UnableToDeliverStrategy::from_raw(self.0.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn store(&self, value: UnableToDeliverStrategy) {
+        self.0.store(value as u8, Ordering::Relaxed);
+    }
+}
@@ -116,7 +116,6 @@ impl core::fmt::Display for WriterCreateError {
 impl core::error::Error for WriterCreateError {}
 
 /// Producing endpoint of a blackboard based communication.
-#[derive(Debug)]
 pub struct Writer<
     Service: service::Service,
     KeyType: Send + Sync + Eq + Clone + Copy + Debug + 'static + Hash + ZeroCopySend,
@@ -125,6 +124,24 @@ pub struct Writer<
     writer_id: UniqueWriterId,
 }
 
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Copy + Debug + 'static + Hash + ZeroCopySend,
+> Debug for Writer<Service, KeyType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.shared_state.service_state.shared_node.log_prefix()
+        )?;
+        f.debug_struct("Writer")
+            .field("shared_state", &self.shared_state)
+            .field("writer_id", &self.writer_id)
+            .finish()
+    }
+}
+
 impl<
     Service: service::Service,
     KeyType: Send + Sync + Eq + Clone + Copy + Debug + 'static + Hash + ZeroCopySend,
@@ -133,7 +150,7 @@ impl<
     pub(crate) fn new(
         service: Arc<ServiceState<Service, BlackboardResources<Service>>>,
     ) -> Result<Self, WriterCreateError> {
-        let origin = "Writer::new()";
+        let origin = service.shared_node.log_origin("Writer::new()");
         let msg = "Unable to create Writer port";
 
         let writer_id = UniqueWriterId::new();
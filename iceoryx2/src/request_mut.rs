@@ -102,7 +102,7 @@ impl<
         if !unsafe { &mut *client_shared_state.available_channel_ids.get() }
             .push(self.header().channel_id)
         {
-            fatal_panic!(from self,
+            fatal_panic!(from client_shared_state.log_origin(self),
                     "This should never happen! The channel id could not be returned.");
         }
 
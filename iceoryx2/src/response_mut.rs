@@ -334,7 +334,7 @@ impl<
         let msg = "Unable to send response";
 
         let shared_state = self.shared_state.lock();
-        fail!(from self, when shared_state.update_connections(),
+        fail!(from shared_state.log_origin(&self), when shared_state.update_connections(),
             "{} since the connections could not be updated.", msg);
 
         if self.connection_id != INVALID_CONNECTION_ID {
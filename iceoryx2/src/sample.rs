@@ -32,15 +32,19 @@
 
 use core::{fmt::Debug, ops::Deref};
 
+use alloc::boxed::Box;
+
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
 
-use crate::identifiers::UniquePublisherId;
+use crate::identifiers::{SampleId, UniquePublisherId};
+use crate::owned_sample::OwnedSample;
 use crate::port::details::chunk_details::ChunkDetails;
 use crate::port::subscriber::SubscriberSharedState;
 use crate::raw_sample::RawSample;
+use crate::sample_guard::SampleGuard;
 use crate::service::header::publish_subscribe::Header;
 
 /// It stores the payload and is acquired by the [`Subscriber`](crate::port::subscriber::Subscriber) whenever
@@ -55,6 +59,7 @@ pub struct Sample<
     pub(crate) subscriber_shared_state:
         Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
     pub(crate) details: ChunkDetails,
+    pub(crate) was_preceded_by_overflow: bool,
 }
 
 unsafe impl<
@@ -105,8 +110,15 @@ impl<
 > Drop for Sample<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        self.subscriber_shared_state
-            .lock()
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        if subscriber_shared_state.release_shared_sample_ref(&self.details)
+            || subscriber_shared_state
+                .defer_release(&self.details, self.ptr.as_header_ref().sequence_number())
+        {
+            return;
+        }
+
+        subscriber_shared_state
             .receiver
             .release_offset(&self.details, ChannelId::new(0));
     }
@@ -133,8 +145,207 @@ impl<
         self.ptr.as_header_ref()
     }
 
+    /// Returns a reference to the extended user header of the [`Sample`]. If the
+    /// [`Publisher`](crate::port::publisher::Publisher) did not attach one or
+    /// `ExtendedUserHeader` does not match the type defined in the
+    /// [`Service`](crate::service::Service), it returns [`None`].
+    /// See [`Header::has_extended_user_header()`].
+    pub fn extended_user_header<ExtendedUserHeader: ZeroCopySend>(
+        &self,
+    ) -> Option<&ExtendedUserHeader> {
+        let header = self.header();
+        if !header.has_extended_user_header() {
+            return None;
+        }
+
+        let static_config = *self
+            .subscriber_shared_state
+            .lock()
+            .receiver
+            .service_state
+            .static_config
+            .publish_subscribe();
+        if !static_config.has_extended_user_header_type::<ExtendedUserHeader>() {
+            return None;
+        }
+
+        static_config
+            .extended_user_header_ptr_from_header(
+                (header as *const Header).cast(),
+                header.number_of_elements() as usize,
+            )
+            .map(|ptr| unsafe { &*(ptr as *const ExtendedUserHeader) })
+    }
+
     /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
     pub fn origin(&self) -> UniquePublisherId {
         UniquePublisherId(UniqueSystemId::from(self.details.origin))
     }
+
+    /// Returns the sequence number the [`Publisher`](crate::port::publisher::Publisher) stamped
+    /// on the [`Sample`] when it was sent. See [`Header::sequence_number()`].
+    pub fn sequence_number(&self) -> u64 {
+        self.header().sequence_number()
+    }
+
+    /// Returns the [`SampleId`] that identifies the [`Sample`] system-wide. It is equal to
+    /// the [`SampleId`] returned by
+    /// [`Publisher::send_copy_tracked()`](crate::port::publisher::Publisher::send_copy_tracked())
+    /// and can be used to correlate sent and received [`Sample`]s.
+    pub fn id(&self) -> SampleId {
+        SampleId::new(self.origin(), self.sequence_number())
+    }
+
+    /// Returns true when the [`Sample`] was published by a process with the same byte order.
+    /// See [`Header::is_native_endian()`].
+    pub fn is_native_endian(&self) -> bool {
+        self.header().is_native_endian()
+    }
+
+    /// Returns true when the [`Sample`] was sent with
+    /// [`SampleMut::send_pinned()`](crate::sample_mut::SampleMut::send_pinned()) and its slot
+    /// is therefore not recycled by the [`Publisher`](crate::port::publisher::Publisher) until
+    /// it is unpinned. See [`Header::is_pinned()`].
+    pub fn is_pinned(&self) -> bool {
+        self.header().is_pinned()
+    }
+
+    /// Returns true when the [`Subscriber`](crate::port::subscriber::Subscriber) detected a gap
+    /// in the [`Header::sequence_number()`] between this [`Sample`] and the previously received
+    /// [`Sample`] of the same [`Publisher`](crate::port::publisher::Publisher). This happens
+    /// when the receive buffer overflowed and older [`Sample`]s were evicted before they
+    /// could be consumed.
+    pub fn was_preceded_by_overflow(&self) -> bool {
+        self.was_preceded_by_overflow
+    }
+
+    /// Creates another [`Sample`] that refers to the same slot in the shared memory without
+    /// copying the payload. The slot is returned to the
+    /// [`Publisher`](crate::port::publisher::Publisher) when the last [`Sample`] that refers
+    /// to it goes out of scope. Every [`Sample`] created this way counts towards the borrowed
+    /// samples of the [`Subscriber`](crate::port::subscriber::Subscriber) only once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #   .publish_subscribe::<u64>()
+    /// #   .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    /// # publisher.send_copy(1234)?;
+    ///
+    /// if let Some(sample) = subscriber.receive()? {
+    ///     let other_sample = sample.clone_ref();
+    ///     drop(sample);
+    ///     println!("still readable: {:?}", *other_sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clone_ref(&self) -> Self {
+        self.subscriber_shared_state
+            .lock()
+            .acquire_shared_sample_ref(&self.details);
+
+        Self {
+            ptr: self.ptr,
+            subscriber_shared_state: self.subscriber_shared_state.clone(),
+            details: self.details.clone(),
+            was_preceded_by_overflow: self.was_preceded_by_overflow,
+        }
+    }
+
+    /// Converts the [`Sample`] into a [`SampleGuard`] that keeps its slot in the shared memory
+    /// reserved until [`SampleGuard::release()`] is called or it goes out of scope. Useful to
+    /// hold a [`Sample`] across an `.await` point.
+    pub fn into_guard(self) -> SampleGuard<Service, Payload, UserHeader> {
+        SampleGuard::new(self)
+    }
+}
+
+impl<
+    Service: crate::service::Service,
+    Payload: Debug + Clone + ZeroCopySend,
+    UserHeader: Debug + Clone + ZeroCopySend,
+> Sample<Service, Payload, UserHeader>
+{
+    /// Copies the payload, user header and header of the [`Sample`] into heap memory and
+    /// releases the [`Sample`] so that its slot in the shared memory can be reused by the
+    /// [`Publisher`](crate::port::publisher::Publisher) right away. The returned
+    /// [`OwnedSample`] can outlive the [`Subscriber`](crate::port::subscriber::Subscriber).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #   .publish_subscribe::<u64>()
+    /// #   .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    /// # publisher.send_copy(1234)?;
+    ///
+    /// if let Some(sample) = subscriber.receive()? {
+    ///     let owned_sample = sample.into_owned();
+    ///     drop(subscriber);
+    ///     println!("received: {:?}", *owned_sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_owned(self) -> OwnedSample<Payload, UserHeader> {
+        OwnedSample {
+            header: *self.header(),
+            user_header: self.user_header().clone(),
+            payload: Box::new(self.payload().clone()),
+        }
+    }
+}
+
+impl<
+    Service: crate::service::Service,
+    Payload: Debug + Clone + ZeroCopySend,
+    UserHeader: Debug + Clone + ZeroCopySend,
+> Sample<Service, [Payload], UserHeader>
+{
+    /// Copies the slice payload, user header and header of the [`Sample`] into heap memory
+    /// and releases the [`Sample`] so that its slot in the shared memory can be reused by the
+    /// [`Publisher`](crate::port::publisher::Publisher) right away. The returned
+    /// [`OwnedSample`] can outlive the [`Subscriber`](crate::port::subscriber::Subscriber).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #   .publish_subscribe::<[u64]>()
+    /// #   .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(3).create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    /// # let sample = publisher.loan_slice_uninit(3)?;
+    /// # sample.write_from_slice(&[1, 2, 3]).send()?;
+    ///
+    /// if let Some(sample) = subscriber.receive()? {
+    ///     let owned_sample = sample.into_owned();
+    ///     drop(subscriber);
+    ///     println!("received: {:?}", &*owned_sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_owned(self) -> OwnedSample<[Payload], UserHeader> {
+        OwnedSample {
+            header: *self.header(),
+            user_header: self.user_header().clone(),
+            payload: self.payload().into(),
+        }
+    }
 }
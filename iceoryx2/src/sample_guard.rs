@@ -0,0 +1,121 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc_threadsafe::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .publish_subscribe::<u64>()
+//! #   .open_or_create()?;
+//! # let subscriber = service.subscriber_builder().create()?;
+//!
+//! async fn process(_value: u64) {}
+//!
+//! if let Some(sample) = subscriber.receive()? {
+//!     let guard = sample.into_guard();
+//!     let _task = async move {
+//!         process(*guard).await;
+//!         // the slot in the shared memory is reserved until here
+//!         guard.release();
+//!     };
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt::Debug, ops::Deref};
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+
+use crate::port::subscriber::SubscriberSharedState;
+use crate::sample::Sample;
+
+/// Keeps a received [`Sample`] and its slot in the shared memory of the
+/// [`Publisher`](crate::port::publisher::Publisher) reserved, for instance across an
+/// `.await` point. Acquired via [`Sample::into_guard()`]. The slot is returned with
+/// [`SampleGuard::release()`] or when the [`SampleGuard`] goes out of scope.
+///
+/// The [`SampleGuard`] is [`Send`] and [`Sync`] when the [`Service`](crate::service::Service)
+/// is thread-safe, like `ipc_threadsafe::Service`, so that it can be held by futures that are
+/// moved between threads.
+///
+/// **Attention:** While the [`SampleGuard`] is alive, the slot cannot be reused by the
+/// [`Publisher`](crate::port::publisher::Publisher) and the guarded [`Sample`] counts towards
+/// the borrowed samples of the [`Subscriber`](crate::port::subscriber::Subscriber). Holding
+/// guards for too long may therefore exhaust the [`Publisher`](crate::port::publisher::Publisher)
+/// or block the [`Subscriber`](crate::port::subscriber::Subscriber) from receiving.
+pub struct SampleGuard<
+    Service: crate::service::Service,
+    Payload: Debug + ?Sized + ZeroCopySend,
+    UserHeader: ZeroCopySend,
+> {
+    sample: Sample<Service, Payload, UserHeader>,
+}
+
+unsafe impl<
+    Service: crate::service::Service,
+    Payload: Debug + ZeroCopySend + Sync + ?Sized,
+    UserHeader: ZeroCopySend + Sync,
+> Sync for SampleGuard<Service, Payload, UserHeader>
+where
+    Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>: Send + Sync,
+{
+}
+
+impl<
+    Service: crate::service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: ZeroCopySend,
+> Debug for SampleGuard<Service, Payload, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SampleGuard {{ sample: {:?} }}", self.sample)
+    }
+}
+
+impl<
+    Service: crate::service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: ZeroCopySend,
+> Deref for SampleGuard<Service, Payload, UserHeader>
+{
+    type Target = Payload;
+    fn deref(&self) -> &Self::Target {
+        self.sample.payload()
+    }
+}
+
+impl<
+    Service: crate::service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: ZeroCopySend,
+> SampleGuard<Service, Payload, UserHeader>
+{
+    pub(crate) fn new(sample: Sample<Service, Payload, UserHeader>) -> Self {
+        Self { sample }
+    }
+
+    /// Returns a reference to the guarded [`Sample`].
+    pub fn sample(&self) -> &Sample<Service, Payload, UserHeader> {
+        &self.sample
+    }
+
+    /// Returns the slot of the guarded [`Sample`] to the
+    /// [`Publisher`](crate::port::publisher::Publisher) so that it can be reused.
+    pub fn release(self) {
+        drop(self)
+    }
+}
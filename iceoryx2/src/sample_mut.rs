@@ -64,15 +64,27 @@
 //! ```
 
 use crate::{
-    port::SendError, port::publisher::PublisherSharedState, raw_sample::RawSampleMut,
+    identifiers::UniqueNodeId,
+    port::SendError,
+    port::details::write_tracker::WriteTracker,
+    port::publisher::{PublisherSharedState, lock_with_send_permit},
+    raw_sample::RawSampleMut,
+    sample_mut_uninit::SampleMutUninit,
     service::header::publish_subscribe::Header,
 };
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::serialize::Serialize as _;
+use iceoryx2_cal::serialize::postcard::Postcard;
 use iceoryx2_cal::shared_memory::*;
+use iceoryx2_log::fail;
 
 use core::fmt::{Debug, Formatter};
+use core::mem::MaybeUninit;
 use core::ops::{Deref, DerefMut};
+use core::time::Duration;
+use serde::Serialize;
 
 /// Acquired by a [`crate::port::publisher::Publisher`] via
 ///  * [`crate::port::publisher::Publisher::loan()`],
@@ -91,6 +103,9 @@ pub struct SampleMut<
     pub(crate) ptr: RawSampleMut<Header, UserHeader, Payload>,
     pub(crate) offset_to_chunk: PointerOffset,
     pub(crate) sample_size: usize,
+    pub(crate) was_sample_sent: bool,
+    pub(crate) number_of_pushed_elements: usize,
+    pub(crate) write_tracker: Option<WriteTracker>,
 }
 
 unsafe impl<
@@ -122,7 +137,7 @@ impl<
 > DerefMut for SampleMut<Service, Payload, UserHeader>
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        self.ptr.as_payload_mut()
+        self.payload_mut()
     }
 }
 
@@ -152,11 +167,158 @@ impl<
     UserHeader: ZeroCopySend,
 > Drop for SampleMut<Service, Payload, UserHeader>
 {
+    fn drop(&mut self) {
+        let publisher_shared_state = self.publisher_shared_state.lock();
+        if !self.was_sample_sent && publisher_shared_state.run_drop_on_discard() {
+            // a `MaybeUninit` payload is never dropped, therefore only initialized payloads
+            // are affected
+            unsafe { core::ptr::drop_in_place(self.ptr.as_payload_mut()) };
+        }
+
+        publisher_shared_state
+            .sender
+            .return_loaned_sample(self.offset_to_chunk);
+    }
+}
+
+/// Acquired with [`SampleMut::send_pinned()`]. As long as it exists, the slot of the sent
+/// [`SampleMut`] is not recycled by the [`crate::port::publisher::Publisher`]. The slot is
+/// unpinned with [`PinnedSampleHandle::unpin()`] or when the [`PinnedSampleHandle`] goes out
+/// of scope.
+pub struct PinnedSampleHandle<Service: crate::service::Service> {
+    publisher_shared_state: Service::ArcThreadSafetyPolicy<PublisherSharedState<Service>>,
+    offset: PointerOffset,
+    number_of_recipients: usize,
+}
+
+unsafe impl<Service: crate::service::Service> Send for PinnedSampleHandle<Service> where
+    Service::ArcThreadSafetyPolicy<PublisherSharedState<Service>>: Send + Sync
+{
+}
+
+impl<Service: crate::service::Service> Debug for PinnedSampleHandle<Service> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "PinnedSampleHandle<{}> {{ offset: {:?}, number_of_recipients: {} }}",
+            core::any::type_name::<Service>(),
+            self.offset,
+            self.number_of_recipients
+        )
+    }
+}
+
+impl<Service: crate::service::Service> Drop for PinnedSampleHandle<Service> {
     fn drop(&mut self) {
         self.publisher_shared_state
             .lock()
             .sender
-            .return_loaned_sample(self.offset_to_chunk);
+            .release_sample(self.offset);
+    }
+}
+
+impl<Service: crate::service::Service> PinnedSampleHandle<Service> {
+    /// Returns the number of [`crate::port::subscriber::Subscriber`]s that received the
+    /// pinned sample.
+    pub fn number_of_recipients(&self) -> usize {
+        self.number_of_recipients
+    }
+
+    /// Unpins the slot so that the [`crate::port::publisher::Publisher`] can reuse it as soon
+    /// as no [`crate::port::subscriber::Subscriber`] holds the sample anymore.
+    pub fn unpin(self) {
+        drop(self)
+    }
+}
+
+/// Failure that can occur when a value is added to a slice [`SampleMut`] with
+/// [`SampleMut::push()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushError {
+    /// The loaned slice has no capacity left to store another element.
+    Full,
+}
+
+impl core::fmt::Display for PushError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PushError::{self:?}")
+    }
+}
+
+impl core::error::Error for PushError {}
+
+/// Failure that can occur when a value is serialized into a byte slice [`SampleMut`] with
+/// [`SampleMut::write_serialized()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializeError {
+    /// The value could not be serialized.
+    SerializationFailure,
+    /// The serialized value is larger than the loaned slice.
+    ExceedsSliceLength,
+}
+
+impl core::fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SerializeError::{self:?}")
+    }
+}
+
+impl core::error::Error for SerializeError {}
+
+impl<Service: crate::service::Service, UserHeader: ZeroCopySend>
+    SampleMut<Service, [u8], UserHeader>
+{
+    /// Serializes `value` with the serializer of the
+    /// [`SerializedPublisher`](crate::port::serialized::SerializedPublisher) into the
+    /// loaned slice and returns the number of written bytes. Only the written bytes are
+    /// delivered with [`SampleMut::send()`]. When the serialized value does not fit into the
+    /// loaned slice, [`SerializeError::ExceedsSliceLength`] is returned and the payload
+    /// remains untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(64).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice(64)?;
+    /// let number_of_bytes = sample.write_serialized(&(123u64, 4.56f32))?;
+    /// assert_eq!(sample.payload().len(), number_of_bytes);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_serialized<T: Serialize + Debug>(
+        &mut self,
+        value: &T,
+    ) -> Result<usize, SerializeError> {
+        let bytes = fail!(from self.publisher_shared_state.lock().log_origin(self), when Postcard::serialize(value),
+            with SerializeError::SerializationFailure,
+            "Unable to write {:?} since it could not be serialized.", value);
+
+        let slice_len = self.ptr.as_payload_ref().len();
+        if slice_len < bytes.len() {
+            fail!(from self.publisher_shared_state.lock().log_origin(self), with SerializeError::ExceedsSliceLength,
+                "Unable to write {:?} since the {} serialized bytes exceed the loaned slice of {} bytes.",
+                value, bytes.len(), slice_len);
+        }
+
+        self.ptr.as_payload_mut()[..bytes.len()].copy_from_slice(&bytes);
+        self.number_of_pushed_elements = bytes.len();
+        self.ptr
+            .as_header_mut()
+            .set_number_of_elements(bytes.len() as u64);
+
+        Ok(bytes.len())
     }
 }
 
@@ -191,6 +353,36 @@ impl<
         self.ptr.as_header_ref()
     }
 
+    /// Returns the number of bytes of the payload region of the sample in the data segment.
+    /// It includes the padding the allocator added and can therefore exceed the size of the
+    /// payload, for instance `len * size_of::<T>()` of a slice. Useful when framed data shall
+    /// be written directly into the sample.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let sample = publisher.loan()?;
+    /// assert!(sample.payload_capacity_bytes() >= core::mem::size_of::<u64>());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn payload_capacity_bytes(&self) -> usize {
+        let header = (self.ptr.as_header_ref() as *const Header) as usize;
+        let payload = (self.ptr.as_payload_ref() as *const M).cast::<u8>() as usize;
+        header + self.sample_size - payload
+    }
+
     /// Returns a reference to the user_header of the sample.
     ///
     /// # Example
@@ -243,6 +435,100 @@ impl<
         self.ptr.as_user_header_mut()
     }
 
+    /// Returns a reference to the extended user header of the sample. If the sample was not
+    /// loaned with
+    /// [`Publisher::loan_with_extended_header()`](crate::port::publisher::Publisher::loan_with_extended_header())
+    /// or `ExtendedUserHeader` does not match the type defined in the
+    /// [`Service`](crate::service::Service), it returns [`None`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .extended_user_header::<u32>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let sample = publisher.loan_with_extended_header::<u32>()?.write_payload(42);
+    /// println!("Sample extended user header {:?}", sample.extended_user_header::<u32>());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extended_user_header<ExtendedUserHeader: ZeroCopySend>(
+        &self,
+    ) -> Option<&ExtendedUserHeader> {
+        self.extended_user_header_ptr::<ExtendedUserHeader>()
+            .map(|ptr| unsafe { &*ptr })
+    }
+
+    /// Returns a mutable reference to the extended user header of the sample. If the sample
+    /// was not loaned with
+    /// [`Publisher::loan_with_extended_header()`](crate::port::publisher::Publisher::loan_with_extended_header())
+    /// or `ExtendedUserHeader` does not match the type defined in the
+    /// [`Service`](crate::service::Service), it returns [`None`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .extended_user_header::<u32>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan_with_extended_header::<u32>()?.write_payload(42);
+    /// if let Some(extended_user_header) = sample.extended_user_header_mut::<u32>() {
+    ///     *extended_user_header = 123;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extended_user_header_mut<ExtendedUserHeader: ZeroCopySend>(
+        &mut self,
+    ) -> Option<&mut ExtendedUserHeader> {
+        self.extended_user_header_ptr::<ExtendedUserHeader>()
+            .map(|ptr| unsafe { &mut *ptr })
+    }
+
+    fn extended_user_header_ptr<ExtendedUserHeader: ZeroCopySend>(
+        &self,
+    ) -> Option<*mut ExtendedUserHeader> {
+        if !self.header().has_extended_user_header() {
+            return None;
+        }
+
+        self.publisher_shared_state
+            .lock()
+            .extended_user_header_ptr::<ExtendedUserHeader>(self.ptr.as_header_ref())
+    }
+
+    pub(crate) fn attach_extended_user_header<ExtendedUserHeader: Default + ZeroCopySend>(
+        &mut self,
+    ) {
+        let ptr = self
+            .publisher_shared_state
+            .lock()
+            .extended_user_header_ptr::<ExtendedUserHeader>(self.ptr.as_header_ref());
+
+        if let Some(ptr) = ptr {
+            unsafe { ptr.write(ExtendedUserHeader::default()) };
+            self.ptr.as_header_mut().set_has_extended_user_header(true);
+        }
+    }
+
     /// Returns a reference to the payload of the sample.
     ///
     /// # Notes
@@ -303,12 +589,29 @@ impl<
         self.ptr.as_payload_mut()
     }
 
+    /// Labels the whole payload as written for a [`crate::port::publisher::Publisher`] that
+    /// validates the initialization of its samples. Must only be called by APIs that
+    /// initialize every element of the payload.
+    pub(crate) fn mark_all_as_written(&mut self) {
+        if let Some(write_tracker) = &mut self.write_tracker {
+            write_tracker.mark_all_as_written();
+        }
+    }
+
     /// Send a previously loaned [`crate::port::publisher::Publisher::loan_uninit()`] or
     /// [`crate::port::publisher::Publisher::loan()`] [`SampleMut`] to all connected
     /// [`crate::port::subscriber::Subscriber`]s of the service.
     ///
     /// On success the number of [`crate::port::subscriber::Subscriber`]s that received
     /// the data is returned, otherwise a [`SendError`] describing the failure.
+    /// When the publishers of the [`Node`](crate::node::Node) are paused with
+    /// [`Node::pause_publishers()`](crate::node::Node::pause_publishers()) nothing is delivered
+    /// and `Ok(0)` is returned.
+    /// When the publisher was created with a
+    /// [`PortFactoryPublisher::max_send_rate()`](crate::service::port_factory::publisher::PortFactoryPublisher::max_send_rate())
+    /// the send either blocks until the rate allows it or fails with
+    /// [`SendError::RateLimited`], depending on the
+    /// [`UnableToDeliverStrategy`](crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy).
     ///
     /// # Example
     ///
@@ -332,8 +635,450 @@ impl<
     /// # }
     /// ```
     pub fn send(self) -> Result<usize, SendError> {
-        self.publisher_shared_state
-            .lock()
-            .send_sample(self.offset_to_chunk, self.sample_size)
+        self.send_impl(None)
+    }
+
+    /// Sends the [`SampleMut`]. When a `blocking_timeout` is provided, it waits, independent
+    /// of the [`UnableToDeliverStrategy`](crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy),
+    /// up to the timeout for a full receive buffer.
+    pub(crate) fn send_impl(self, blocking_timeout: Option<Duration>) -> Result<usize, SendError> {
+        self.send_with_sequence_number_impl(blocking_timeout)
+            .map(|(number_of_recipients, _)| number_of_recipients)
+    }
+
+    /// Sends the [`SampleMut`] like [`SampleMut::send_impl()`] and returns additionally the
+    /// sequence number that was stamped on the sample.
+    pub(crate) fn send_with_sequence_number_impl(
+        mut self,
+        blocking_timeout: Option<Duration>,
+    ) -> Result<(usize, u64), SendError> {
+        self.verify_payload_is_written(None)?;
+        let publisher_shared_state = lock_with_send_permit(&self.publisher_shared_state)?;
+        Self::deliver(
+            &publisher_shared_state,
+            &mut self.ptr,
+            &mut self.was_sample_sent,
+            self.offset_to_chunk,
+            self.sample_size,
+            blocking_timeout,
+            None,
+        )
+    }
+
+    /// Sends the [`SampleMut`] like [`SampleMut::send()`] but discards it and returns `Ok(0)`
+    /// when the receive buffer of a [`crate::port::subscriber::Subscriber`] exceeds the
+    /// adaptive drop threshold or when its payload is equal to the last delivered payload of
+    /// the [`crate::port::publisher::Publisher`]. The checks, the delivery and the update
+    /// of the last delivered payload happen under one lock acquisition.
+    pub(crate) fn send_filtered(mut self) -> Result<usize, SendError> {
+        self.verify_payload_is_written(None)?;
+        let publisher_shared_state = lock_with_send_permit(&self.publisher_shared_state)?;
+        let payload = (self.ptr.as_payload_ref() as *const M).cast::<u8>();
+
+        if publisher_shared_state.exceeds_adaptive_drop_threshold()
+            || publisher_shared_state.is_last_sent_payload(payload)
+        {
+            drop(publisher_shared_state);
+            return Ok(0);
+        }
+
+        let (number_of_recipients, _) = Self::deliver(
+            &publisher_shared_state,
+            &mut self.ptr,
+            &mut self.was_sample_sent,
+            self.offset_to_chunk,
+            self.sample_size,
+            None,
+            None,
+        )?;
+
+        if number_of_recipients > 0 {
+            publisher_shared_state.set_last_sent_payload(payload);
+        }
+
+        Ok(number_of_recipients)
+    }
+
+    /// Sends the [`SampleMut`] like [`SampleMut::send_impl()`] but uses the already locked
+    /// `publisher_shared_state` of the [`crate::port::publisher::Publisher`] so that multiple
+    /// samples can be delivered under one lock acquisition. The [`SampleMut`] must not be
+    /// dropped while the lock is held.
+    pub(crate) fn send_with_locked_state(
+        &mut self,
+        publisher_shared_state: &PublisherSharedState<Service>,
+    ) -> Result<usize, SendError> {
+        self.verify_payload_is_written(Some(publisher_shared_state))?;
+        Self::deliver(
+            publisher_shared_state,
+            &mut self.ptr,
+            &mut self.was_sample_sent,
+            self.offset_to_chunk,
+            self.sample_size,
+            None,
+            None,
+        )
+        .map(|(number_of_recipients, _)| number_of_recipients)
+    }
+
+    /// Verifies that every payload element was written. When the caller already holds the lock
+    /// of the [`PublisherSharedState`] it must be provided, otherwise it is acquired to log the
+    /// failure.
+    fn verify_payload_is_written(
+        &self,
+        locked_publisher_shared_state: Option<&PublisherSharedState<Service>>,
+    ) -> Result<(), SendError> {
+        if let Some(n) = self
+            .write_tracker
+            .as_ref()
+            .and_then(|write_tracker| write_tracker.first_unwritten_element())
+        {
+            let guard;
+            let publisher_shared_state = match locked_publisher_shared_state {
+                Some(publisher_shared_state) => publisher_shared_state,
+                None => {
+                    guard = self.publisher_shared_state.lock();
+                    &*guard
+                }
+            };
+            fail!(from publisher_shared_state.log_origin(self), with SendError::UninitializedPayload,
+                "Unable to send the sample since the payload element {} was never written.", n);
+        }
+
+        Ok(())
+    }
+
+    fn deliver(
+        publisher_shared_state: &PublisherSharedState<Service>,
+        ptr: &mut RawSampleMut<Header, UserHeader, M>,
+        was_sample_sent: &mut bool,
+        offset_to_chunk: PointerOffset,
+        sample_size: usize,
+        blocking_timeout: Option<Duration>,
+        accepts_node: Option<&dyn Fn(&UniqueNodeId) -> bool>,
+    ) -> Result<(usize, u64), SendError> {
+        let number_of_recipients = publisher_shared_state.send_sample(
+            offset_to_chunk,
+            sample_size,
+            ptr.as_header_mut(),
+            was_sample_sent,
+            blocking_timeout,
+            accepts_node,
+        )?;
+
+        Ok((number_of_recipients, ptr.as_header_ref().sequence_number()))
+    }
+
+    /// Sends the [`SampleMut`] exactly like [`SampleMut::send()`] but pins its slot in the
+    /// data segment of the [`crate::port::publisher::Publisher`]. A pinned slot is not
+    /// recycled, even after all [`crate::port::subscriber::Subscriber`]s released the
+    /// received [`Sample`](crate::sample::Sample), until the returned [`PinnedSampleHandle`]
+    /// is unpinned with [`PinnedSampleHandle::unpin()`] or goes out of scope. The received
+    /// [`Sample`](crate::sample::Sample) reports [`Sample::is_pinned()`](crate::sample::Sample::is_pinned()).
+    ///
+    /// **Attention:** Every pinned slot permanently reduces the number of samples the
+    /// [`crate::port::publisher::Publisher`] can loan and deliver. Pinning samples without
+    /// unpinning them, for instance by leaking the [`PinnedSampleHandle`] with
+    /// [`core::mem::forget()`], leaks the slots for the lifetime of the
+    /// [`crate::port::publisher::Publisher`] and eventually exhausts it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// let pinned = sample.send_pinned()?;
+    /// // the slot stays valid until here
+    /// pinned.unpin();
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_pinned(mut self) -> Result<PinnedSampleHandle<Service>, SendError> {
+        self.ptr.as_header_mut().set_is_pinned(true);
+        let publisher_shared_state = self.publisher_shared_state.clone();
+        let offset = self.offset_to_chunk;
+        publisher_shared_state.lock().sender.borrow_sample(offset);
+
+        match self.send() {
+            Ok(number_of_recipients) => Ok(PinnedSampleHandle {
+                publisher_shared_state,
+                offset,
+                number_of_recipients,
+            }),
+            Err(e) => {
+                publisher_shared_state.lock().sender.release_sample(offset);
+                Err(e)
+            }
+        }
+    }
+
+    /// Sends the [`SampleMut`] exactly like [`SampleMut::send()`] but stamps an expiry time
+    /// into its [`Header`] that lies `ttl` in the future of the monotonic clock.
+    /// [`crate::port::subscriber::Subscriber`]s that were created with
+    /// [`PortFactorySubscriber::drop_expired()`](crate::service::port_factory::subscriber::PortFactorySubscriber::drop_expired())
+    /// discard the [`Sample`](crate::sample::Sample) when it is received after it expired.
+    ///
+    /// On success the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data is returned, otherwise a [`SendError`] describing the failure. If the
+    /// monotonic clock cannot be read, [`SendError::InternalError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// sample.send_with_ttl(Duration::from_millis(100))?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_with_ttl(mut self, ttl: Duration) -> Result<usize, SendError> {
+        let now = fail!(from self.publisher_shared_state.lock().log_origin("SampleMut::send_with_ttl()"),
+                when Time::now_with_clock(ClockType::Monotonic),
+                with SendError::InternalError,
+                "Unable to send the sample with a time to live of {:?} since the monotonic clock could not be read.", ttl);
+
+        self.ptr
+            .as_header_mut()
+            .set_expiry(now.as_duration().saturating_add(ttl));
+        self.send()
+    }
+
+    /// Sends the [`SampleMut`] exactly like [`SampleMut::send()`] and additionally measures,
+    /// with the monotonic clock, how long the send took. It can be used to detect when a send
+    /// exceeds the time budget of a real-time loop, for instance due to a page fault or a
+    /// blocking [`UnableToDeliverStrategy`](crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy).
+    ///
+    /// On success the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data is returned together with the measured [`Duration`], otherwise a [`SendError`]
+    /// describing the failure. If the monotonic clock cannot be read,
+    /// [`SendError::InternalError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// let (_number_of_recipients, send_duration) = sample.send_timed()?;
+    /// if send_duration > Duration::from_micros(100) {
+    ///     println!("send exceeded its budget: {:?}", send_duration);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_timed(self) -> Result<(usize, Duration), SendError> {
+        let msg = "Unable to measure the duration of the send";
+        let publisher_shared_state = self.publisher_shared_state.clone();
+        let start = fail!(from publisher_shared_state.lock().log_origin("SampleMut::send_timed()"),
+                when Time::now_with_clock(ClockType::Monotonic),
+                with SendError::InternalError,
+                "{} since the monotonic clock could not be read.", msg);
+
+        let number_of_recipients = self.send()?;
+
+        let send_duration = fail!(from publisher_shared_state.lock().log_origin("SampleMut::send_timed()"),
+                when start.elapsed(),
+                with SendError::InternalError,
+                "{} since the elapsed time could not be acquired.", msg);
+
+        Ok((number_of_recipients, send_duration))
+    }
+
+    /// Send a previously loaned [`SampleMut`] only to the connected
+    /// [`crate::port::subscriber::Subscriber`]s that were created by one of the provided
+    /// [`Node`](crate::node::Node)s. An empty set of [`UniqueNodeId`]s delivers to no one.
+    /// The [`SampleMut`] acquires the next sequence number like with [`SampleMut::send()`] but
+    /// it is not added to the history of the [`crate::port::publisher::Publisher`], so that a
+    /// late joining [`crate::port::subscriber::Subscriber`] of another
+    /// [`Node`](crate::node::Node) never receives it.
+    ///
+    /// On success the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data is returned, otherwise a [`SendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// sample.send_to_nodes(&[*node.id()])?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_to_nodes(mut self, node_ids: &[UniqueNodeId]) -> Result<usize, SendError> {
+        self.verify_payload_is_written(None)?;
+        let publisher_shared_state = lock_with_send_permit(&self.publisher_shared_state)?;
+        Self::deliver(
+            &publisher_shared_state,
+            &mut self.ptr,
+            &mut self.was_sample_sent,
+            self.offset_to_chunk,
+            self.sample_size,
+            None,
+            Some(&|node_id: &UniqueNodeId| node_ids.contains(node_id)),
+        )
+        .map(|(number_of_recipients, _)| number_of_recipients)
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader: ZeroCopySend>
+    SampleMut<Service, Payload, UserHeader>
+{
+    /// Drops the current payload and converts the [`SampleMut`] back into a
+    /// [`SampleMutUninit`] so that the payload can be written anew. The loaned slot and the
+    /// headers are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let sample = publisher.loan_uninit()?.write_payload(1234);
+    /// let sample = sample.into_uninit().write_payload(5678);
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_uninit(mut self) -> SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader> {
+        unsafe { core::ptr::drop_in_place(self.ptr.as_payload_mut()) };
+
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        let mut uninitialized_sample: SampleMut<Service, MaybeUninit<Payload>, UserHeader> =
+            unsafe { core::mem::transmute_copy(&self) };
+        core::mem::forget(self);
+        if let Some(write_tracker) = &mut uninitialized_sample.write_tracker {
+            write_tracker.mark_all_as_unwritten();
+        }
+        SampleMutUninit::from_sample_mut(uninitialized_sample)
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader: ZeroCopySend>
+    SampleMut<Service, [Payload], UserHeader>
+{
+    /// Returns how many more elements can be added with [`SampleMut::push()`] before the
+    /// loaned slice is full.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice(4)?;
+    /// sample.push(1)?;
+    /// assert_eq!(sample.remaining_capacity(), 3);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remaining_capacity(&self) -> usize {
+        self.ptr.as_payload_ref().len() - self.number_of_pushed_elements
+    }
+
+    /// Writes `value` into the next element of the loaned slice. As soon as the first element
+    /// is pushed, only the pushed prefix of the slice is delivered with [`SampleMut::send()`].
+    /// When the slice is full, [`PushError::Full`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice(16)?;
+    /// sample.push(12)?;
+    /// sample.push(34)?;
+    ///
+    /// // delivers a slice with the two elements 12 and 34
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn push(&mut self, value: Payload) -> Result<(), PushError> {
+        if self.remaining_capacity() == 0 {
+            fail!(from self.publisher_shared_state.lock().log_origin(self), with PushError::Full,
+                "Unable to push value since the loaned slice of {} elements is already full.",
+                self.number_of_pushed_elements);
+        }
+
+        self.ptr.as_payload_mut()[self.number_of_pushed_elements] = value;
+        self.number_of_pushed_elements += 1;
+        self.ptr
+            .as_header_mut()
+            .set_number_of_elements(self.number_of_pushed_elements as u64);
+
+        Ok(())
     }
 }
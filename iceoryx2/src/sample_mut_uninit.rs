@@ -96,8 +96,8 @@ use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::shm_allocator::PointerOffset;
 
 use crate::{
-    port::publisher::PublisherSharedState, raw_sample::RawSampleMut, sample_mut::SampleMut,
-    service::header::publish_subscribe::Header,
+    port::details::write_tracker::WriteTracker, port::publisher::PublisherSharedState,
+    raw_sample::RawSampleMut, sample_mut::SampleMut, service::header::publish_subscribe::Header,
 };
 
 /// Acquired by a [`crate::port::publisher::Publisher`] via
@@ -132,6 +132,14 @@ impl<
     UserHeader: ZeroCopySend,
 > SampleMutUninit<Service, Payload, UserHeader>
 {
+    pub(crate) fn from_sample_mut(sample: SampleMut<Service, Payload, UserHeader>) -> Self {
+        Self { sample }
+    }
+
+    pub(crate) fn mark_all_as_written(&mut self) {
+        self.sample.mark_all_as_written();
+    }
+
     /// Returns a reference to the [`Header`] of the [`SampleMutUninit`].
     ///
     /// # Example
@@ -209,6 +217,79 @@ impl<
         self.sample.user_header_mut()
     }
 
+    /// Returns a reference to the extended user header of the sample. If the sample was not
+    /// loaned with
+    /// [`Publisher::loan_with_extended_header()`](crate::port::publisher::Publisher::loan_with_extended_header())
+    /// or `ExtendedUserHeader` does not match the type defined in the
+    /// [`Service`](crate::service::Service), it returns [`None`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .extended_user_header::<u32>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let sample = publisher.loan_with_extended_header::<u32>()?;
+    /// println!("Sample extended user header {:?}", sample.extended_user_header::<u32>());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extended_user_header<ExtendedUserHeader: ZeroCopySend>(
+        &self,
+    ) -> Option<&ExtendedUserHeader> {
+        self.sample.extended_user_header()
+    }
+
+    /// Returns a mutable reference to the extended user header of the sample. If the sample
+    /// was not loaned with
+    /// [`Publisher::loan_with_extended_header()`](crate::port::publisher::Publisher::loan_with_extended_header())
+    /// or `ExtendedUserHeader` does not match the type defined in the
+    /// [`Service`](crate::service::Service), it returns [`None`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .extended_user_header::<u32>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan_with_extended_header::<u32>()?;
+    /// if let Some(extended_user_header) = sample.extended_user_header_mut::<u32>() {
+    ///     *extended_user_header = 123;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extended_user_header_mut<ExtendedUserHeader: ZeroCopySend>(
+        &mut self,
+    ) -> Option<&mut ExtendedUserHeader> {
+        self.sample.extended_user_header_mut()
+    }
+
+    pub(crate) fn attach_extended_user_header<ExtendedUserHeader: Default + ZeroCopySend>(
+        &mut self,
+    ) {
+        self.sample
+            .attach_extended_user_header::<ExtendedUserHeader>()
+    }
+
     /// Returns a reference to the payload of the sample.
     ///
     /// # Notes
@@ -244,6 +325,11 @@ impl<
     /// # Notes
     ///
     /// The generic parameter `Payload` is packed into a [`core::mem::MaybeUninit<Payload>`].
+    /// Writes via the returned reference are not tracked by a
+    /// [`crate::port::publisher::Publisher`] that validates the initialization of its samples,
+    /// see
+    /// [`PortFactoryPublisher::validate_initialization()`](crate::service::port_factory::publisher::PortFactoryPublisher::validate_initialization()).
+    /// Use [`SampleMutUninit::write_payload()`] or [`SampleMutUninit::write_element()`] instead.
     ///
     /// # Example
     ///
@@ -277,6 +363,7 @@ impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader
         ptr: RawSampleMut<Header, UserHeader, MaybeUninit<Payload>>,
         offset_to_chunk: PointerOffset,
         sample_size: usize,
+        write_tracker: Option<WriteTracker>,
     ) -> Self {
         Self {
             sample: SampleMut {
@@ -284,6 +371,9 @@ impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader
                 ptr,
                 offset_to_chunk,
                 sample_size,
+                was_sample_sent: false,
+                number_of_pushed_elements: 0,
+                write_tracker,
             },
         }
     }
@@ -313,6 +403,7 @@ impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader
     /// ```
     pub fn write_payload(mut self, value: Payload) -> SampleMut<Service, Payload, UserHeader> {
         self.payload_mut().write(value);
+        self.sample.mark_all_as_written();
         unsafe { self.assume_init() }
     }
 
@@ -361,6 +452,7 @@ impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader
         ptr: RawSampleMut<Header, UserHeader, [MaybeUninit<Payload>]>,
         offset_to_chunk: PointerOffset,
         sample_size: usize,
+        write_tracker: Option<WriteTracker>,
     ) -> Self {
         Self {
             sample: SampleMut {
@@ -368,6 +460,9 @@ impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader
                 ptr,
                 offset_to_chunk,
                 sample_size,
+                was_sample_sent: false,
+                number_of_pushed_elements: 0,
+                write_tracker,
             },
         }
     }
@@ -415,6 +510,46 @@ impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader
         initialized_sample
     }
 
+    /// Writes `value` into the element at `index` of the payload. In contrast to the access
+    /// via [`SampleMutUninit::payload_mut()`], the write is tracked by a
+    /// [`crate::port::publisher::Publisher`] that validates the initialization of its samples,
+    /// see
+    /// [`PortFactoryPublisher::validate_initialization()`](crate::service::port_factory::publisher::PortFactoryPublisher::validate_initialization()).
+    ///
+    /// # Panics
+    ///
+    /// When `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice_uninit(2)?;
+    /// sample.write_element(0, 12);
+    /// sample.write_element(1, 34);
+    /// let sample = unsafe { sample.assume_init() };
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_element(&mut self, index: usize, value: Payload) {
+        self.sample.ptr.as_payload_mut()[index].write(value);
+        if let Some(write_tracker) = &mut self.sample.write_tracker {
+            write_tracker.mark_as_written(index);
+        }
+    }
+
     /// Writes the payload to the sample and labels the sample as initialized
     ///
     /// # Example
@@ -446,6 +581,7 @@ impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader
         for (i, element) in self.payload_mut().iter_mut().enumerate() {
             element.write(initializer(i));
         }
+        self.sample.mark_all_as_written();
 
         // SAFETY: this is safe since the payload was initialized on the line above
         unsafe { self.assume_init() }
@@ -489,6 +625,7 @@ impl<
         self.payload_mut().copy_from_slice(unsafe {
             core::mem::transmute::<&[Payload], &[MaybeUninit<Payload>]>(value)
         });
+        self.sample.mark_all_as_written();
         unsafe { self.assume_init() }
     }
 }
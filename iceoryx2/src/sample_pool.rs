@@ -0,0 +1,149 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//! #
+//! let publisher = service.publisher_builder().max_loaned_samples(4).create()?;
+//! let mut pool = publisher.preallocate(4)?;
+//!
+//! for n in 0..16 {
+//!     let sample = pool.acquire()?;
+//!     sample.write_payload(n).send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
+use crate::port::LoanError;
+use crate::port::publisher::Publisher;
+use crate::sample_mut_uninit::SampleMutUninit;
+use crate::service;
+
+/// A pool of pre-loaned [`SampleMutUninit`]s of a [`Publisher`], created with
+/// [`Publisher::preallocate()`]. Every [`SamplePool::acquire()`] hands out one of the
+/// pre-loaned samples. When [`SampleMutUninit`]s were sent and the pool ran empty, it loans
+/// up to its capacity of new samples in one batch. All samples that were not acquired are
+/// returned when the [`SamplePool`] goes out of scope.
+pub struct SamplePool<
+    'publisher,
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + Sized + 'static,
+    UserHeader: Default + Debug + ZeroCopySend,
+> {
+    publisher: &'publisher Publisher<Service, Payload, UserHeader>,
+    capacity: usize,
+    samples: Vec<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>>,
+}
+
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + Sized,
+    UserHeader: Default + Debug + ZeroCopySend,
+> Debug for SamplePool<'_, Service, Payload, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SamplePool<{}, {}, {}> {{ capacity: {}, available: {} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<UserHeader>(),
+            self.capacity,
+            self.samples.len()
+        )
+    }
+}
+
+impl<
+    'publisher,
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + Sized,
+    UserHeader: Default + Debug + ZeroCopySend,
+> SamplePool<'publisher, Service, Payload, UserHeader>
+{
+    pub(crate) fn new(
+        publisher: &'publisher Publisher<Service, Payload, UserHeader>,
+        capacity: usize,
+    ) -> Result<Self, LoanError> {
+        let mut new_self = Self {
+            publisher,
+            capacity,
+            samples: Vec::with_capacity(capacity),
+        };
+
+        new_self.refill()?;
+
+        Ok(new_self)
+    }
+
+    /// Returns the number of samples the [`SamplePool`] keeps pre-loaned.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of pre-loaned samples that can be acquired without loaning new ones.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns true when no pre-loaned sample is available.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Acquires a [`SampleMutUninit`] from the [`SamplePool`]. When the pool is empty, it is
+    /// refilled in one batch with as many samples as the [`Publisher`] can loan, up to its
+    /// capacity. A refill that loaned at least one sample before it failed still serves the
+    /// acquisition.
+    /// On failure it returns [`LoanError`] describing the failure.
+    pub fn acquire(
+        &mut self,
+    ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, LoanError> {
+        if self.samples.is_empty() {
+            let refill_result = self.refill();
+            if self.samples.is_empty() {
+                refill_result?;
+            }
+        }
+
+        match self.samples.pop() {
+            Some(sample) => Ok(sample),
+            None => self.publisher.loan_uninit(),
+        }
+    }
+
+    fn refill(&mut self) -> Result<(), LoanError> {
+        let number_of_missing_samples = self.capacity - self.samples.len();
+        if number_of_missing_samples == 0 {
+            return Ok(());
+        }
+
+        self.publisher
+            .loan_uninit_batch(number_of_missing_samples, &mut self.samples)
+    }
+}
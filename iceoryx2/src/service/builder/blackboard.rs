@@ -414,7 +414,7 @@ impl<
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
                 if !(self.config_details().type_details == config.blackboard().type_details) {
-                    fail!(from self, with ServiceAvailabilityState::IncompatibleKeys,
+                    fail!(from self.base.log_origin(self), with ServiceAvailabilityState::IncompatibleKeys,
                         "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
                         error_msg, &config.blackboard().type_details , self.config_details().type_details);
                 }
@@ -430,7 +430,7 @@ impl<
         match self.base.service_config.messaging_pattern {
             MessagingPattern::Blackboard(ref v) => v,
             _ => {
-                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
+                fatal_panic!(from self.base.log_origin(self), "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
             }
         }
     }
@@ -439,7 +439,7 @@ impl<
         match self.base.service_config.messaging_pattern {
             MessagingPattern::Blackboard(ref mut v) => v,
             _ => {
-                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
+                fatal_panic!(from self.base.log_origin(self), "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
             }
         }
     }
@@ -517,7 +517,7 @@ impl<
     ) -> Self {
         let key_mem = match KeyMemory::try_from(&key) {
             Err(_) => {
-                fatal_panic!(from self,
+                fatal_panic!(from self.builder.base.log_origin(self),
                     "This should never happen! Calling add() with a key type that has an invalid layout.")
             }
             Ok(mem) => mem,
@@ -552,7 +552,9 @@ impl<
 
     /// Validates configuration and overrides the invalid setting with meaningful values.
     fn adjust_configuration_to_meaningful_values(&mut self) {
+        let shared_node = self.builder.base.shared_node.clone();
         let origin = format!("{self:?}");
+        let origin = shared_node.log_origin(&origin);
         let settings = self.builder.base.service_config.blackboard_mut();
 
         if settings.max_readers == 0 {
@@ -594,7 +596,7 @@ impl<
 
         match self.builder.is_service_available(msg)? {
             Some(_) => {
-                fail!(from self, with BlackboardCreateError::AlreadyExists, "{} since the service already exists.", msg);
+                fail!(from self.builder.base.log_origin(self), with BlackboardCreateError::AlreadyExists, "{} since the service already exists.", msg);
             }
             None => {
                 let service_tag = self
@@ -606,23 +608,27 @@ impl<
                 let static_config = match self.builder.base.create_static_config_storage() {
                     Ok(c) => c,
                     Err(StaticStorageCreateError::AlreadyExists) => {
-                        fail!(from self, with BlackboardCreateError::AlreadyExists,
+                        fail!(from self.builder.base.log_origin(self), with BlackboardCreateError::AlreadyExists,
                            "{} since the service already exists.", msg);
                     }
                     Err(StaticStorageCreateError::Creation) => {
-                        fail!(from self, with BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                        fail!(from self.builder.base.log_origin(self), with BlackboardCreateError::IsBeingCreatedByAnotherInstance,
                             "{} since the service is being created by another instance.", msg);
                     }
                     Err(StaticStorageCreateError::InsufficientPermissions) => {
-                        fail!(from self, with BlackboardCreateError::InsufficientPermissions,
+                        fail!(from self.builder.base.log_origin(self), with BlackboardCreateError::InsufficientPermissions,
                             "{} since the static service information could not be created due to insufficient permissions.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with BlackboardCreateError::InternalFailure,
+                        fail!(from self.builder.base.log_origin(self), with BlackboardCreateError::InternalFailure,
                             "{} since the static service information could not be created due to an internal failure ({:?}).", msg, e);
                     }
                 };
 
+                self.builder
+                    .base
+                    .update_creation_epoch(msg, BlackboardCreateError::InternalFailure)?;
+
                 let blackboard_config = self.builder.base.service_config.blackboard();
 
                 // create dynamic config
@@ -638,17 +644,17 @@ impl<
                 ) {
                     Ok(dynamic_config) => dynamic_config,
                     Err(DynamicStorageCreateError::AlreadyExists) => {
-                        fail!(from self, with BlackboardCreateError::ServiceInCorruptedState,
+                        fail!(from self.builder.base.log_origin(self), with BlackboardCreateError::ServiceInCorruptedState,
                             "{} since the dynamic config of a previous instance of the service still exists.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with BlackboardCreateError::InternalFailure,
+                        fail!(from self.builder.base.log_origin(self), with BlackboardCreateError::InternalFailure,
                             "{} since the dynamic service segment could not be created ({:?}).", msg, e);
                     }
                 };
 
                 self.builder.base.service_config.attributes = attributes.0.clone();
-                let service_config = fail!(from self,
+                let service_config = fail!(from self.builder.base.log_origin(self),
                             when ServiceType::ConfigSerializer::serialize(&self.builder.base.service_config),
                             with BlackboardCreateError::ServiceInCorruptedState,
                             "{} since the configuration could not be serialized.", msg);
@@ -660,7 +666,7 @@ impl<
                     blackboard_data_config::<ServiceType>(self.builder.base.shared_node.config());
                 let mut payload_size = 0;
                 if self.builder.internals.is_empty() {
-                    fail!(from self,  with BlackboardCreateError::NoEntriesProvided,
+                    fail!(from self.builder.base.log_origin(self),  with BlackboardCreateError::NoEntriesProvided,
                         "{} without entries. At least one key-value pair is required.", msg);
                 }
                 for i in &self.builder.internals {
@@ -678,7 +684,7 @@ impl<
                 {
                     Ok(v) => v,
                     Err(_) => {
-                        fail!(from self, with BlackboardCreateError::ServiceInCorruptedState,
+                        fail!(from self.builder.base.log_origin(self), with BlackboardCreateError::ServiceInCorruptedState,
                             "{} since the blackboard payload data segment could not be created. This could indicate a corrupted system.",
                             msg);
                     }
@@ -705,7 +711,7 @@ impl<
                     >>::__internal_set_type_name_in_config(&mut mgmt_config, mgmt_name)
                 };
 
-                let mgmt_storage = fail!(from self, when
+                let mgmt_storage = fail!(from self.builder.base.log_origin(self), when
                     <ServiceType::BlackboardMgmt<Mgmt> as DynamicStorage<Mgmt,
                     >>::Builder::new(&name)
                         .config(&mgmt_config)
@@ -721,7 +727,7 @@ impl<
                                 {
                                     Ok(m) => m,
                                     Err(_) => {
-                                        error!(from self, "Writing the value to the blackboard data segment failed.");
+                                        error!(from self.builder.base.log_origin(self), "Writing the value to the blackboard data segment failed.");
                                         return false
                                     }
                                 };
@@ -729,13 +735,13 @@ impl<
                                 // write offset to value in payload_shm to entries vector
                                 let res = entry.entries.push(Entry{type_details: self.builder.internals[i].value_type_details, offset: AtomicU64::new(mem.offset.offset() as u64)});
                                 if res.is_err() {
-                                    error!(from self, "Writing the value offset to the blackboard management segment failed.");
+                                    error!(from self.builder.base.log_origin(self), "Writing the value offset to the blackboard management segment failed.");
                                     return false
                                 }
                                 // write offset index to map
                                 let res = unsafe {entry.map.__internal_insert(self.builder.internals[i].key, entry.entries.len() - 1, &self.builder.key_eq_func)};
                                 if res.is_err() {
-                                    error!(from self, "Inserting the key-value pair into the blackboard management segment failed.");
+                                    error!(from self.builder.base.log_origin(self), "Inserting the key-value pair into the blackboard management segment failed.");
                                     return false
                                 }
                             }
@@ -745,7 +751,7 @@ impl<
                             msg);
 
                 // only unlock the static details when the service is successfully created
-                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                let unlocked_static_details = fail!(from self.builder.base.log_origin(self), when static_config.unlock(service_config.as_slice()),
                             with BlackboardCreateError::ServiceInCorruptedState,
                             "{} since the configuration could not be written to the static storage.", msg);
 
@@ -765,6 +771,7 @@ impl<
                             data: payload_shm,
                             key_eq_func: Arc::new(self.builder.key_eq_func),
                         },
+                        true,
                     ),
                 ))
             }
@@ -798,7 +805,7 @@ impl<ServiceType: service::Service> Creator<CustomKeyMarker, ServiceType> {
     ) -> Self {
         let key_type_details = match self.builder.override_key_type {
             None => {
-                fatal_panic!(from self, "The key type details were not set when __internal_add was called!")
+                fatal_panic!(from self.builder.base.log_origin(self), "The key type details were not set when __internal_add was called!")
             }
             Some(details) => details,
         };
@@ -808,12 +815,14 @@ impl<ServiceType: service::Service> Creator<CustomKeyMarker, ServiceType> {
         ) {
             Ok(layout) => layout,
             Err(_) => {
-                fatal_panic!(from self, "This should never happen! Key size/alignment is invalid!")
+                fatal_panic!(from self.builder.base.log_origin(self), "This should never happen! Key size/alignment is invalid!")
             }
         };
         let key_mem = match unsafe { KeyMemory::try_from_ptr(key, key_layout) } {
             Ok(mem) => mem,
-            Err(_) => fatal_panic!(from self, "The key type has the wrong size/alignment!"),
+            Err(_) => {
+                fatal_panic!(from self.builder.base.log_origin(self), "The key type has the wrong size/alignment!")
+            }
         };
 
         let value_writer = Box::new(move |raw_memory_ptr: *mut u8| unsafe {
@@ -890,7 +899,7 @@ impl<
 
         let existing_attributes = existing_settings.attributes();
         if let Err(incompatible_key) = verifier.verify_requirements(existing_attributes) {
-            fail!(from self, with BlackboardOpenError::IncompatibleAttributes,
+            fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::IncompatibleAttributes,
                 "{} due to incompatible service attribute key \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
                 msg, incompatible_key, verifier, existing_attributes);
         }
@@ -899,7 +908,7 @@ impl<
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::Blackboard(v) => v,
             p => {
-                fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::IncompatibleMessagingPattern,
                 "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Blackboard is required.", msg, p);
             }
         };
@@ -907,7 +916,7 @@ impl<
         if self.builder.verify_max_readers
             && existing_settings.max_readers < required_settings.max_readers
         {
-            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders,
+            fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders,
                                 "{} since the service supports only {} readers but a support of {} readers was requested.",
                                 msg, existing_settings.max_readers, required_settings.max_readers);
         }
@@ -915,7 +924,7 @@ impl<
         if self.builder.verify_max_nodes
             && existing_settings.max_nodes < required_settings.max_nodes
         {
-            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes,
+            fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes,
                                 "{} since the service supports only {} nodes but {} are required.",
                                 msg, existing_settings.max_nodes, required_settings.max_nodes);
         }
@@ -950,7 +959,7 @@ impl<
         loop {
             match self.builder.is_service_available(msg)? {
                 None => {
-                    fail!(from self, with BlackboardOpenError::DoesNotExist, "{} since the service does not exist.", msg);
+                    fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::DoesNotExist, "{} since the service does not exist.", msg);
                 }
                 Some((static_config, static_storage)) => {
                     let blackboard_static_config =
@@ -964,28 +973,28 @@ impl<
                     let dynamic_config = match self.builder.base.open_dynamic_config_storage() {
                         Ok(v) => v,
                         Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
-                            fail!(from self, with BlackboardOpenError::IsMarkedForDestruction,
+                            fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::IsMarkedForDestruction,
                                 "{} since the service is marked for destruction.", msg);
                         }
                         Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
-                            fail!(from self, with BlackboardOpenError::ExceedsMaxNumberOfNodes,
+                            fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::ExceedsMaxNumberOfNodes,
                                 "{} since it would exceed the maximum number of supported nodes.", msg);
                         }
                         Err(OpenDynamicStorageFailure::DynamicStorageOpenError(
                             DynamicStorageOpenError::DoesNotExist,
                         )) => {
-                            fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                            fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::ServiceInCorruptedState,
                                 "{} since the dynamic segment of the service is missing.", msg);
                         }
                         Err(e) => {
                             if self.builder.is_service_available(msg)?.is_none() {
-                                fail!(from self, with BlackboardOpenError::DoesNotExist, "{}, since the service does not exist.", msg);
+                                fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::DoesNotExist, "{}, since the service does not exist.", msg);
                             }
 
                             service_open_retry_count += 1;
 
                             if RETRY_LIMIT < service_open_retry_count {
-                                fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                                fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::ServiceInCorruptedState,
                                     "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
                                     msg, e);
                             }
@@ -1018,7 +1027,7 @@ impl<
                             &mut mgmt_config, mgmt_name
                         )
                     };
-                    let mgmt_storage = fail!(from self, when
+                    let mgmt_storage = fail!(from self.builder.base.log_origin(self), when
                         <ServiceType::BlackboardMgmt<Mgmt> as DynamicStorage<Mgmt>
                         >::Builder::new(&name)
                             .config(&mgmt_config)
@@ -1040,7 +1049,7 @@ impl<
                     {
                         Ok(v) => v,
                         Err(_) => {
-                            fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                            fail!(from self.builder.base.log_origin(self), with BlackboardOpenError::ServiceInCorruptedState,
                                 "{} since the blackboard payload data segment could not be opened. This could indicate a corrupted system.",
                                 msg);
                         }
@@ -1061,6 +1070,7 @@ impl<
                                 data: payload_shm,
                                 key_eq_func: Arc::new(self.builder.key_eq_func),
                             },
+                            false,
                         ),
                     ));
                 }
@@ -222,7 +222,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         match self.base.service_config.messaging_pattern {
             MessagingPattern::Event(ref mut v) => v,
             _ => {
-                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Event builder!");
+                fatal_panic!(from self.base.log_origin(self), "This should never happen! Accessing wrong messaging pattern in Event builder!");
             }
         }
     }
@@ -349,7 +349,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         let mut retry_count = 0;
         loop {
             if RETRY_LIMIT < retry_count {
-                fail!(from self,
+                fail!(from self.base.log_origin(self),
                       with EventOpenOrCreateError::SystemInFlux,
                       "{} since an instance is creating and removing the same service repeatedly.",
                       msg);
@@ -391,7 +391,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         loop {
             match self.base.is_service_available(msg)? {
                 None => {
-                    fail!(from self, with EventOpenError::DoesNotExist,
+                    fail!(from self.base.log_origin(self), with EventOpenError::DoesNotExist,
                         "{} since the event does not exist.", msg);
                 }
                 Some((static_config, static_storage)) => {
@@ -405,29 +405,29 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     let dynamic_config = match self.base.open_dynamic_config_storage() {
                         Ok(v) => v,
                         Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
-                            fail!(from self, with EventOpenError::IsMarkedForDestruction,
+                            fail!(from self.base.log_origin(self), with EventOpenError::IsMarkedForDestruction,
                                 "{} since the service is marked for destruction.", msg);
                         }
                         Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
-                            fail!(from self, with EventOpenError::ExceedsMaxNumberOfNodes,
+                            fail!(from self.base.log_origin(self), with EventOpenError::ExceedsMaxNumberOfNodes,
                                 "{} since it would exceed the maximum number of supported nodes.", msg);
                         }
                         Err(OpenDynamicStorageFailure::DynamicStorageOpenError(
                             DynamicStorageOpenError::DoesNotExist,
                         )) => {
-                            fail!(from self, with EventOpenError::ServiceInCorruptedState,
+                            fail!(from self.base.log_origin(self), with EventOpenError::ServiceInCorruptedState,
                                 "{} since the dynamic segment of the service is missing.", msg);
                         }
                         Err(e) => {
                             if self.base.is_service_available(msg)?.is_none() {
-                                fail!(from self, with EventOpenError::DoesNotExist,
+                                fail!(from self.base.log_origin(self), with EventOpenError::DoesNotExist,
                                     "{} since the event does not exist.", msg);
                             }
 
                             service_open_retry_count += 1;
 
                             if RETRY_LIMIT < service_open_retry_count {
-                                fail!(from self, with EventOpenError::ServiceInCorruptedState,
+                                fail!(from self.base.log_origin(self), with EventOpenError::ServiceInCorruptedState,
                                 "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
                                 msg, e);
                             }
@@ -449,6 +449,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                         dynamic_config,
                         static_storage,
                         NoResource,
+                        false,
                     )));
                 }
             }
@@ -485,7 +486,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 if let RelocatableOption::Some(ref mut deadline) =
                     self.base.service_config.event_mut().deadline
                 {
-                    let now = fail!(from self, when Time::now(),
+                    let now = fail!(from self.base.log_origin(self), when Time::now(),
                                 with EventCreateError::InternalFailure,
                                 "{} since the current system time could not be acquired.", msg);
 
@@ -495,23 +496,26 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 let static_config = match self.base.create_static_config_storage() {
                     Ok(c) => c,
                     Err(StaticStorageCreateError::AlreadyExists) => {
-                        fail!(from self, with EventCreateError::AlreadyExists,
+                        fail!(from self.base.log_origin(self), with EventCreateError::AlreadyExists,
                            "{} since the service already exists.", msg);
                     }
                     Err(StaticStorageCreateError::Creation) => {
-                        fail!(from self, with EventCreateError::IsBeingCreatedByAnotherInstance,
+                        fail!(from self.base.log_origin(self), with EventCreateError::IsBeingCreatedByAnotherInstance,
                             "{} since the service is being created by another instance.", msg);
                     }
                     Err(StaticStorageCreateError::InsufficientPermissions) => {
-                        fail!(from self, with EventCreateError::InsufficientPermissions,
+                        fail!(from self.base.log_origin(self), with EventCreateError::InsufficientPermissions,
                             "{} since the static service information could not be created due to insufficient permissions.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with EventCreateError::InternalFailure,
+                        fail!(from self.base.log_origin(self), with EventCreateError::InternalFailure,
                             "{} since the static service information could not be created ({:?}).", msg, e);
                     }
                 };
 
+                self.base
+                    .update_creation_epoch(msg, EventCreateError::InternalFailure)?;
+
                 let event_config = self.base.service_config.event();
 
                 let dynamic_config_setting = DynamicConfigSettings {
@@ -526,23 +530,23 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 ) {
                     Ok(dynamic_config) => dynamic_config,
                     Err(DynamicStorageCreateError::AlreadyExists) => {
-                        fail!(from self, with EventCreateError::ServiceInCorruptedState,
+                        fail!(from self.base.log_origin(self), with EventCreateError::ServiceInCorruptedState,
                             "{} since there exist an old dynamic config from a previous instance of the service.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with EventCreateError::InternalFailure,
+                        fail!(from self.base.log_origin(self), with EventCreateError::InternalFailure,
                             "{} since the dynamic service segment could not be created ({:?}).", msg, e);
                     }
                 };
 
                 self.base.service_config.attributes = attributes.0.clone();
 
-                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                let service_config = fail!(from self.base.log_origin(self), when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                                             with EventCreateError::ServiceInCorruptedState,
                                             "{} since the configuration could not be serialized.", msg);
 
                 // only unlock the static details when the service is successfully created
-                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                let unlocked_static_details = fail!(from self.base.log_origin(self), when static_config.unlock(service_config.as_slice()),
                             with EventCreateError::ServiceInCorruptedState,
                             "{} since the configuration could not be written to the static storage.", msg);
 
@@ -557,17 +561,20 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     dynamic_config,
                     unlocked_static_details,
                     NoResource,
+                    true,
                 )))
             }
             Some(_) => {
-                fail!(from self, with EventCreateError::AlreadyExists,
+                fail!(from self.base.log_origin(self), with EventCreateError::AlreadyExists,
                     "{} since the service already exists.", msg);
             }
         }
     }
 
     fn adjust_attributes_to_meaningful_values(&mut self) {
+        let shared_node = self.base.shared_node.clone();
         let origin = format!("{self:?}");
+        let origin = shared_node.log_origin(&origin);
         let settings = self.base.service_config.event_mut();
 
         if settings.max_notifiers == 0 {
@@ -596,7 +603,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         let existing_attributes = existing_settings.attributes();
         if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
         {
-            fail!(from self, with EventOpenError::IncompatibleAttributes,
+            fail!(from self.base.log_origin(self), with EventOpenError::IncompatibleAttributes,
                 "{} due to incompatible service attribute key {}. The following attributes {:?} are required but the service has the attributes {:?}.",
                 msg, incompatible_key, required_attributes, existing_attributes);
         }
@@ -605,7 +612,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::Event(v) => v,
             p => {
-                fail!(from self, with EventOpenError::IncompatibleMessagingPattern,
+                fail!(from self.base.log_origin(self), with EventOpenError::IncompatibleMessagingPattern,
                 "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Event is required.", msg, p);
             }
         };
@@ -613,7 +620,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         if self.verify_max_notifiers
             && existing_settings.max_notifiers < required_settings.max_notifiers
         {
-            fail!(from self, with EventOpenError::DoesNotSupportRequestedAmountOfNotifiers,
+            fail!(from self.base.log_origin(self), with EventOpenError::DoesNotSupportRequestedAmountOfNotifiers,
                 "{} since the event supports only {} notifiers but a support of {} notifiers was requested.",
                 msg, existing_settings.max_notifiers, required_settings.max_notifiers);
         }
@@ -621,7 +628,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         if self.verify_max_listeners
             && existing_settings.max_listeners < required_settings.max_listeners
         {
-            fail!(from self, with EventOpenError::DoesNotSupportRequestedAmountOfListeners,
+            fail!(from self.base.log_origin(self), with EventOpenError::DoesNotSupportRequestedAmountOfListeners,
                 "{} since the event supports only {} listeners but a support of {} listeners was requested.",
                 msg, existing_settings.max_notifiers, required_settings.max_listeners);
         }
@@ -629,13 +636,13 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         if self.verify_event_id_max_value
             && existing_settings.event_id_max_value < required_settings.event_id_max_value
         {
-            fail!(from self, with EventOpenError::DoesNotSupportRequestedMaxEventId,
+            fail!(from self.base.log_origin(self), with EventOpenError::DoesNotSupportRequestedMaxEventId,
                 "{} since the event supports only EventIds with a value of at most {} a support of {} was requested.",
                 msg, existing_settings.event_id_max_value, required_settings.event_id_max_value);
         }
 
         if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
-            fail!(from self, with EventOpenError::DoesNotSupportRequestedAmountOfNodes,
+            fail!(from self.base.log_origin(self), with EventOpenError::DoesNotSupportRequestedAmountOfNodes,
                 "{} since the event supports only {} nodes but {} are required.",
                 msg, existing_settings.max_nodes, required_settings.max_nodes);
         }
@@ -643,7 +650,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         if self.verify_notifier_created_event
             && existing_settings.notifier_created_event != required_settings.notifier_created_event
         {
-            fail!(from self, with EventOpenError::IncompatibleNotifierCreatedEvent,
+            fail!(from self.base.log_origin(self), with EventOpenError::IncompatibleNotifierCreatedEvent,
                 "{} since the notifier_created_event id is {:?} but the value {:?} is required.",
                 msg, existing_settings.notifier_created_event, required_settings.notifier_created_event);
         }
@@ -651,7 +658,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         if self.verify_notifier_dropped_event
             && existing_settings.notifier_dropped_event != required_settings.notifier_dropped_event
         {
-            fail!(from self, with EventOpenError::IncompatibleNotifierDroppedEvent,
+            fail!(from self.base.log_origin(self), with EventOpenError::IncompatibleNotifierDroppedEvent,
                 "{} since the notifier_dropped_event id is {:?} but the value {:?} is required.",
                 msg, existing_settings.notifier_dropped_event, required_settings.notifier_dropped_event);
         }
@@ -659,7 +666,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         if self.verify_notifier_dead_event
             && existing_settings.notifier_dead_event != required_settings.notifier_dead_event
         {
-            fail!(from self, with EventOpenError::IncompatibleNotifierDeadEvent,
+            fail!(from self.base.log_origin(self), with EventOpenError::IncompatibleNotifierDeadEvent,
                 "{} since the notifier_dead_event id is {:?} but the value {:?} is required.",
                 msg, existing_settings.notifier_dead_event, required_settings.notifier_dead_event);
         }
@@ -668,7 +675,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             && existing_settings.deadline.map(|v| v.value)
                 != required_settings.deadline.map(|v| v.value)
         {
-            fail!(from self, with EventOpenError::IncompatibleDeadline,
+            fail!(from self.base.log_origin(self), with EventOpenError::IncompatibleDeadline,
                 "{} since the deadline is {:?} but a deadline of {:?} is required.",
                 msg, existing_settings.deadline, required_settings.deadline);
         }
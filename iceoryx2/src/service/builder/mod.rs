@@ -20,6 +20,14 @@ pub mod event;
 /// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
 
+/// Builder for serialized services based on
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+pub mod serialized;
+
+/// Builder for payload free signal services based on
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+pub mod signal;
+
 /// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 pub mod request_response;
 
@@ -29,6 +37,7 @@ pub mod blackboard;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::marker::PhantomData;
+use core::time::Duration;
 
 use alloc::string::String;
 use alloc::sync::Arc;
@@ -52,13 +61,14 @@ use iceoryx2_log::fail;
 use iceoryx2_log::fatal_panic;
 use iceoryx2_log::warn;
 
-use crate::node::SharedNode;
+use crate::node::{NodeLogOrigin, SharedNode};
 use crate::service;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::dynamic_config::RegisterNodeResult;
 use crate::service::static_config::*;
 
 use super::Service;
+use super::config_scheme::creation_epoch_config;
 use super::config_scheme::dynamic_config_storage_config;
 use super::config_scheme::service_tag_config;
 use super::config_scheme::static_config_storage_config;
@@ -116,6 +126,7 @@ enum_gen! {
 pub struct Builder<S: Service> {
     name: ServiceName,
     shared_node: Arc<SharedNode<S>>,
+    is_persistent: bool,
     _phantom_s: PhantomData<S>,
 }
 
@@ -124,10 +135,25 @@ impl<S: Service> Builder<S> {
         Self {
             name: *name,
             shared_node,
+            is_persistent: false,
             _phantom_s: PhantomData,
         }
     }
 
+    /// If the [`Service`] is created, it defines if the [`Service`] persists when the last
+    /// [`Node`](crate::node::Node) detaches from it. By default, the [`Service`] is removed
+    /// together with the last [`Node`](crate::node::Node). When an existing [`Service`] is
+    /// opened, the setting of the creator is used.
+    pub fn persistent(mut self, value: bool) -> Self {
+        self.is_persistent = value;
+        self
+    }
+
+    fn with_service_type(self, mut service_config: StaticConfig) -> BuilderWithServiceType<S> {
+        service_config.is_persistent = self.is_persistent;
+        BuilderWithServiceType::new(service_config, self.shared_node)
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
     pub fn request_response<
@@ -136,39 +162,50 @@ impl<S: Service> Builder<S> {
     >(
         self,
     ) -> request_response::Builder<RequestPayload, (), ResponsePayload, (), S> {
-        BuilderWithServiceType::new(
-            StaticConfig::new_request_response::<S::ServiceNameHasher>(
-                &self.name,
-                self.shared_node.config(),
-            ),
-            self.shared_node,
-        )
-        .request_response::<RequestPayload, ResponsePayload>()
+        let service_config = StaticConfig::new_request_response::<S::ServiceNameHasher>(
+            &self.name,
+            self.shared_node.config(),
+        );
+        self.with_service_type(service_config)
+            .request_response::<RequestPayload, ResponsePayload>()
     }
 
     /// Create a new builder to create a
     /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
+    /// Fixed-size arrays like `[f32; 16]` are sized payloads and use the typed API, slices
+    /// like `[f32]` use the sliced API with a runtime length.
     pub fn publish_subscribe<PayloadType: Debug + ?Sized + ZeroCopySend>(
         self,
     ) -> publish_subscribe::Builder<PayloadType, (), S> {
-        BuilderWithServiceType::new(
-            StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
-                &self.name,
-                self.shared_node.config(),
-            ),
-            self.shared_node,
-        )
-        .publish_subscribe()
+        let service_config = StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
+            &self.name,
+            self.shared_node.config(),
+        );
+        self.with_service_type(service_config).publish_subscribe()
+    }
+
+    /// Create a new builder to create a payload free signal [`Service`] that is based on
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
+    pub fn signal(self) -> signal::Builder<S> {
+        signal::Builder::new(self.publish_subscribe::<()>())
+    }
+
+    /// Create a new builder to create a serialized [`Service`] that is based on
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
+    /// Values of type `T` are serialized into a `[u8]` payload, therefore `T` does not need to
+    /// be [`ZeroCopySend`].
+    pub fn serialized<T: Debug + serde::Serialize + serde::de::DeserializeOwned>(
+        self,
+    ) -> serialized::Builder<T, S> {
+        serialized::Builder::new(self.publish_subscribe::<[u8]>())
     }
 
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
-        BuilderWithServiceType::new(
-            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
-            self.shared_node,
-        )
-        .event()
+        let service_config =
+            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config());
+        self.with_service_type(service_config).event()
     }
 
     /// Create a new builder to create a
@@ -178,14 +215,11 @@ impl<S: Service> Builder<S> {
     >(
         self,
     ) -> blackboard::Creator<KeyType, S> {
-        BuilderWithServiceType::new(
-            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
-                &self.name,
-                self.shared_node.config(),
-            ),
-            self.shared_node,
-        )
-        .blackboard_creator()
+        let service_config = StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+            &self.name,
+            self.shared_node.config(),
+        );
+        self.with_service_type(service_config).blackboard_creator()
     }
 
     /// Create a new builder to open a
@@ -195,14 +229,11 @@ impl<S: Service> Builder<S> {
     >(
         self,
     ) -> blackboard::Opener<KeyType, S> {
-        BuilderWithServiceType::new(
-            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
-                &self.name,
-                self.shared_node.config(),
-            ),
-            self.shared_node,
-        )
-        .blackboard_opener()
+        let service_config = StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+            &self.name,
+            self.shared_node.config(),
+        );
+        self.with_service_type(service_config).blackboard_opener()
     }
 }
 
@@ -223,6 +254,13 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         }
     }
 
+    pub(crate) fn log_origin<'a, T: Debug + ?Sized>(
+        &'a self,
+        origin: &'a T,
+    ) -> NodeLogOrigin<'a, T> {
+        self.shared_node.log_origin(origin)
+    }
+
     fn request_response<
         RequestPayload: Debug + ZeroCopySend + ?Sized,
         ResponsePayload: Debug + ZeroCopySend + ?Sized,
@@ -282,13 +320,13 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         Ok(storage) => storage,
                         Err(StaticStorageOpenError::DoesNotExist) => return Ok(None),
                         Err(StaticStorageOpenError::InitializationNotYetFinalized) => {
-                            fail!(from self, with ServiceState::HangsInCreation,
+                            fail!(from self.log_origin(self), with ServiceState::HangsInCreation,
                                 "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded.",
                                 msg, creation_timeout);
                         },
                         Err(e) =>
                         {
-                            fail!(from self, with ServiceState::InsufficientPermissions,
+                            fail!(from self.log_origin(self), with ServiceState::InsufficientPermissions,
                                     "{} since it is not possible to open the services underlying static details ({:?}). Is the service accessible?",
                                     msg, e);
                         }
@@ -300,22 +338,22 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                     .read(unsafe { read_content.as_mut_vec() }.as_mut_slice())
                     .is_err()
                 {
-                    fail!(from self, with ServiceState::InsufficientPermissions,
+                    fail!(from self.log_origin(self), with ServiceState::InsufficientPermissions,
                             "{} since it is not possible to read the services underlying static details. Is the service accessible?", msg);
                 }
 
-                let service_config = fail!(from self, when ServiceType::ConfigSerializer::deserialize::<StaticConfig>(unsafe {
+                let service_config = fail!(from self.log_origin(self), when ServiceType::ConfigSerializer::deserialize::<StaticConfig>(unsafe {
                                             read_content.as_mut_vec() }),
                                      with ServiceState::Corrupted, "Unable to deserialize the service config. Is the service corrupted?");
 
                 if service_config.service_hash() != self.service_config.service_hash() {
-                    fail!(from self, with ServiceState::Corrupted,
+                    fail!(from self.log_origin(self), with ServiceState::Corrupted,
                         "{} a service with that name exist but different ServiceHash.", msg);
                 }
 
                 let msg = "Service exist but is not compatible";
                 if !service_config.has_same_messaging_pattern(&self.service_config) {
-                    fail!(from self, with ServiceState::IncompatibleMessagingPattern,
+                    fail!(from self.log_origin(self), with ServiceState::IncompatibleMessagingPattern,
                         "{} since the messaging pattern \"{:?}\" does not fit the requested pattern \"{:?}\".",
                         msg, service_config.messaging_pattern(), self.service_config.messaging_pattern());
                 }
@@ -323,7 +361,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                 Ok(Some((service_config, storage)))
             }
             Err(v) => {
-                fail!(from self, with ServiceState::Corrupted,
+                fail!(from self.log_origin(self), with ServiceState::Corrupted,
                     "{} since the service seems to be in a corrupted/inaccessible state ({:?}).", msg, v);
             }
         }
@@ -351,17 +389,17 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
             .supplementary_size(additional_size + required_memory_size)
             .has_ownership(false)
             .initializer(Self::config_init_call)
-            .create(DynamicConfig::new_uninit(super::dynamic_config::MessagingPattern::new(messaging_pattern_settings), max_number_of_nodes) ) {
+            .create(DynamicConfig::new_uninit(super::dynamic_config::MessagingPattern::new(messaging_pattern_settings), max_number_of_nodes, self.service_config.is_persistent()) ) {
                 Ok(dynamic_storage) => {
                     let node_id = self.shared_node.id();
-                    let node_handle = fatal_panic!(from self,
+                    let node_handle = fatal_panic!(from self.log_origin(self),
                             when dynamic_storage.get().register_node_id(*node_id),
                             "{} since event the first NodeId could not be registered.", msg);
                     self.shared_node.registered_services().add(self.service_config.service_hash(), node_handle);
                     Ok(dynamic_storage)
                 },
                 Err(e) => {
-                    fail!(from self, with e, "Failed to create dynamic storage for service.");
+                    fail!(from self.log_origin(self), with e, "Failed to create dynamic storage for service.");
                 }
             }
     }
@@ -380,7 +418,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         ) {
             Ok(storage) => Ok(storage),
             Err(DynamicStorageCreateError::AlreadyExists) => {
-                warn!(from self, "Old dynamic config from previous instance discovered - trying to remove it.");
+                warn!(from self.log_origin(self), "Old dynamic config from previous instance discovered - trying to remove it.");
                 // Safe since a service removes the resources always in the order of:
                 //   1. dynamic config
                 //   2. additional resources
@@ -395,11 +433,11 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                 } {
                     Ok(_) => (),
                     Err(NamedConceptRemoveError::InsufficientPermissions) => {
-                        fail!(from self, with DynamicStorageCreateError::InsufficientPermissions,
+                        fail!(from self.log_origin(self), with DynamicStorageCreateError::InsufficientPermissions,
                             "{msg} since the old instance still exists and cannot be removed due to a lack of permissions.");
                     }
                     Err(e) => {
-                        fail!(from self, with DynamicStorageCreateError::InternalError,
+                        fail!(from self.log_origin(self), with DynamicStorageCreateError::InternalError,
                             "{msg} since the old instance still exists and cannot be removed ({e:?}).");
                     }
                 }
@@ -418,7 +456,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         &self,
     ) -> Result<ServiceType::DynamicStorage, OpenDynamicStorageFailure> {
         let msg = "Failed to open dynamic service information";
-        let storage = fail!(from self, when
+        let storage = fail!(from self.log_origin(self), when
             <<ServiceType::DynamicStorage as DynamicStorage<
                     DynamicConfig,
                 >>::Builder<'_> as NamedConceptBuilder<
@@ -437,11 +475,11 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                 match storage.get().register_node_id(*node_id) {
                     Ok(handle) => Ok(handle),
                     Err(RegisterNodeResult::MarkedForDestruction) => {
-                        fail!(from self, with OpenDynamicStorageFailure::IsMarkedForDestruction,
+                        fail!(from self.log_origin(self), with OpenDynamicStorageFailure::IsMarkedForDestruction,
                             "{} since the dynamic storage is marked for destruction.", msg);
                     }
                     Err(RegisterNodeResult::ExceedsMaxNumberOfNodes) => {
-                        fail!(from self, with OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes,
+                        fail!(from self.log_origin(self), with OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes,
                             "{} since it would exceed the maxium supported number of nodes.", msg);
                     }
                 }
@@ -469,18 +507,82 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
             Ok(static_storage) => Ok(Some(static_storage)),
             Err(StaticStorageCreateError::AlreadyExists) => Ok(None),
             Err(e) => {
-                fail!(from self, with error_value,
+                fail!(from self.log_origin(self), with error_value,
                     "{} since the nodes service tag could not be created ({:?}).", error_msg, e);
             }
         }
     }
 
+    /// Increments the creation epoch of the previous instance of the service, persists it and
+    /// stores it in the service config. The creation epoch outlives the service so that a
+    /// recreated service continues counting where the previous instance stopped. Must only be
+    /// called by the creator while it holds the locked static config storage.
+    fn update_creation_epoch<ErrorType: Copy>(
+        &mut self,
+        error_msg: &str,
+        error_value: ErrorType,
+    ) -> Result<(), ErrorType> {
+        let epoch_config = creation_epoch_config::<ServiceType>(self.shared_node.config());
+        let epoch_name = self.service_config.service_hash().0.into();
+        let mut previous_epoch = [0u8; core::mem::size_of::<u64>()];
+
+        match <ServiceType::StaticStorage as NamedConceptMgmt>::does_exist_cfg(
+            &epoch_name,
+            &epoch_config,
+        ) {
+            Ok(false) => (),
+            Ok(true) => {
+                let storage = fail!(from self.log_origin(self),
+                    when <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+                            ServiceType::StaticStorage>>
+                            ::new(&epoch_name)
+                            .has_ownership(false)
+                            .config(&epoch_config)
+                            .open(Duration::ZERO),
+                    with error_value,
+                    "{} since the creation epoch of the previous service instance could not be opened.", error_msg);
+
+                if storage.len() as usize != previous_epoch.len()
+                    || storage.read(&mut previous_epoch).is_err()
+                {
+                    fail!(from self.log_origin(self), with error_value,
+                        "{} since the creation epoch of the previous service instance could not be read.", error_msg);
+                }
+                drop(storage);
+
+                fail!(from self.log_origin(self),
+                    when unsafe { <ServiceType::StaticStorage as NamedConceptMgmt>::remove_cfg(&epoch_name, &epoch_config) },
+                    with error_value,
+                    "{} since the creation epoch of the previous service instance could not be removed.", error_msg);
+            }
+            Err(e) => {
+                fail!(from self.log_origin(self), with error_value,
+                    "{} since the creation epoch of the previous service instance is in a corrupted/inaccessible state ({:?}).",
+                    error_msg, e);
+            }
+        }
+
+        let creation_epoch = u64::from_le_bytes(previous_epoch).saturating_add(1);
+        let storage = fail!(from self.log_origin(self),
+            when <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+                    ServiceType::StaticStorage>>
+                    ::new(&epoch_name)
+                    .config(&epoch_config)
+                    .create(&creation_epoch.to_le_bytes()),
+            with error_value,
+            "{} since the creation epoch of the service could not be stored.", error_msg);
+        storage.release_ownership();
+
+        self.service_config.creation_epoch = creation_epoch;
+        Ok(())
+    }
+
     fn create_static_config_storage(
         &self,
     ) -> Result<<ServiceType::StaticStorage as StaticStorage>::Locked, StaticStorageCreateError>
     {
         Ok(
-            fail!(from self, when <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+            fail!(from self.log_origin(self), when <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
                         ServiceType::StaticStorage,
                     >>::new(&self.service_config.service_hash().0.into())
                     .config(&static_config_storage_config::<ServiceType>(
@@ -15,16 +15,20 @@
 //! See [`crate::service`]
 //!
 use core::marker::PhantomData;
+use core::time::Duration;
 
 use alloc::format;
 
+use iceoryx2_bb_container::relocatable_option::RelocatableOption;
 use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::StaticStorageLocked;
 use iceoryx2_log::{fail, fatal_panic, warn};
 
+use crate::port::details::data_segment::DataSegment;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::publish_subscribe;
@@ -36,7 +40,7 @@ use super::{CustomHeaderMarker, CustomPayloadMarker, OpenDynamicStorageFailure,
 
 use self::{
     attribute::{AttributeSpecifier, AttributeVerifier},
-    message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant},
+    message_type_details::{MessageTypeDetails, TypeCompatibility, TypeDetail, TypeVariant},
 };
 use builder::RETRY_LIMIT;
 
@@ -49,6 +53,9 @@ pub enum PublishSubscribeOpenError {
     InternalFailure,
     /// The [`Service`] has the wrong payload type.
     IncompatibleTypes,
+    /// The [`Service`] payload type has the same layout but a different type name and
+    /// [`Builder::require_matching_type_name()`] was enabled.
+    IncompatibleTypeName,
     /// The [`Service`] has the wrong messaging pattern.
     IncompatibleMessagingPattern,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
@@ -80,6 +87,9 @@ pub enum PublishSubscribeOpenError {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] did not appear within the timeout provided to
+    /// [`Builder::open_with_timeout()`].
+    Timeout,
 }
 
 impl core::fmt::Display for PublishSubscribeOpenError {
@@ -96,6 +106,9 @@ impl From<ServiceAvailabilityState> for PublishSubscribeOpenError {
             ServiceAvailabilityState::IncompatibleTypes => {
                 PublishSubscribeOpenError::IncompatibleTypes
             }
+            ServiceAvailabilityState::IncompatibleTypeName => {
+                PublishSubscribeOpenError::IncompatibleTypeName
+            }
             ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 PublishSubscribeOpenError::IncompatibleMessagingPattern
             }
@@ -166,6 +179,7 @@ impl From<ServiceAvailabilityState> for PublishSubscribeCreateError {
 enum ServiceAvailabilityState {
     ServiceState(ServiceState),
     IncompatibleTypes,
+    IncompatibleTypeName,
 }
 
 /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
@@ -207,6 +221,31 @@ impl core::fmt::Display for PublishSubscribeOpenOrCreateError {
 
 impl core::error::Error for PublishSubscribeOpenOrCreateError {}
 
+/// The projected shared memory usage of a [`MessagingPattern::PublishSubscribe`] based
+/// [`Service`] computed from the current settings of the [`Builder`] without creating anything.
+/// Acquired via [`Builder::estimate_resource_usage()`]. It can be compared with the
+/// [`ShmFootprint`](crate::service::port_factory::publish_subscribe::ShmFootprint) of the
+/// created [`Service`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceEstimate {
+    /// The size of the serialized static configuration of the service.
+    pub static_config_bytes: usize,
+    /// The size of the dynamic configuration of the service, containing all ports and nodes.
+    pub dynamic_config_bytes: usize,
+    /// The size of the data segment of a single [`crate::port::publisher::Publisher`].
+    pub segment_bytes_per_publisher: usize,
+    /// The sum of the data segment sizes when the maximum number of
+    /// [`crate::port::publisher::Publisher`]s is connected.
+    pub total_segment_bytes: usize,
+}
+
+impl ResourceEstimate {
+    /// Returns the total number of bytes the service is projected to occupy in shared memory.
+    pub fn total_bytes(&self) -> usize {
+        self.static_config_bytes + self.dynamic_config_bytes + self.total_segment_bytes
+    }
+}
+
 /// Builder to create new [`MessagingPattern::PublishSubscribe`] based [`Service`]s
 ///
 /// # Example
@@ -229,6 +268,9 @@ pub struct Builder<
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
     verify_max_nodes: bool,
+    verify_type_name: bool,
+    verify_extended_user_header: bool,
+    user_header_compatibility: TypeCompatibility,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -252,6 +294,9 @@ impl<
             verify_publisher_history_size: self.verify_publisher_history_size,
             verify_enable_safe_overflow: self.verify_enable_safe_overflow,
             verify_max_nodes: self.verify_max_nodes,
+            verify_type_name: self.verify_type_name,
+            verify_extended_user_header: self.verify_extended_user_header,
+            user_header_compatibility: self.user_header_compatibility,
             _data: PhantomData,
             _user_header: PhantomData,
         }
@@ -274,6 +319,9 @@ impl<
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
             verify_max_nodes: false,
+            verify_type_name: false,
+            verify_extended_user_header: false,
+            user_header_compatibility: TypeCompatibility::MatchingTypeName,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
@@ -292,7 +340,7 @@ impl<
         match self.base.service_config.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref mut v) => v,
             _ => {
-                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in PublishSubscribe builder!");
+                fatal_panic!(from self.base.log_origin(self), "This should never happen! Accessing wrong messaging pattern in PublishSubscribe builder!");
             }
         }
     }
@@ -301,7 +349,7 @@ impl<
         match self.base.service_config.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
             _ => {
-                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in PublishSubscribe builder!");
+                fatal_panic!(from self.base.log_origin(self), "This should never happen! Accessing wrong messaging pattern in PublishSubscribe builder!");
             }
         }
     }
@@ -313,16 +361,41 @@ impl<
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
+                let requested_type = &self.config_details().message_type_details.payload;
+                let existing_type = &config.publish_subscribe().message_type_details.payload;
+                if self.verify_type_name
+                    && requested_type.type_name != existing_type.type_name
+                    && requested_type
+                        .is_compatible_to(existing_type, TypeCompatibility::MatchingLayout)
+                {
+                    fail!(from self.base.log_origin(self), with ServiceAvailabilityState::IncompatibleTypeName,
+                        "{} since the service offers the payload type name \"{}\" which differs from the requested type name \"{}\".",
+                        error_msg, existing_type.type_name, requested_type.type_name);
+                }
+
                 if !self
                     .config_details()
                     .message_type_details
-                    .is_compatible_to(&config.publish_subscribe().message_type_details)
+                    .is_compatible_to_with_user_header_policy(
+                        &config.publish_subscribe().message_type_details,
+                        self.user_header_compatibility,
+                    )
                 {
-                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                    fail!(from self.base.log_origin(self), with ServiceAvailabilityState::IncompatibleTypes,
                         "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
                         error_msg, &config.publish_subscribe().message_type_details , self.config_details().message_type_details);
                 }
 
+                let requested_extended_user_header = self.config_details().extended_user_header;
+                let existing_extended_user_header = config.publish_subscribe().extended_user_header;
+                if self.verify_extended_user_header
+                    && requested_extended_user_header != existing_extended_user_header
+                {
+                    fail!(from self.base.log_origin(self), with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service offers the extended user header \"{:?}\" which is not compatible to the requested extended user header \"{:?}\".",
+                        error_msg, existing_extended_user_header, requested_extended_user_header);
+                }
+
                 Ok(Some((config, storage)))
             }
             Ok(None) => Ok(None),
@@ -335,6 +408,19 @@ impl<
         unsafe { core::mem::transmute::<Self, Builder<Payload, M, ServiceType>>(self) }
     }
 
+    /// Defines an optional extended user header type that a [`crate::port::publisher::Publisher`]
+    /// can attach to individual [`crate::sample::Sample`]s with
+    /// [`Publisher::loan_with_extended_header()`](crate::port::publisher::Publisher::loan_with_extended_header()).
+    /// If an existing [`Service`] is opened it requires the service to define the same extended
+    /// user header type.
+    pub fn extended_user_header<ExtendedUserHeader: Debug + ZeroCopySend>(mut self) -> Self {
+        self.config_details_mut().extended_user_header = RelocatableOption::Some(
+            TypeDetail::new::<ExtendedUserHeader>(TypeVariant::FixedSize),
+        );
+        self.verify_extended_user_header = true;
+        self
+    }
+
     /// If the [`Service`] is created, it defines the [`Alignment`] of the payload for the service. If
     /// an existing [`Service`] is opened it requires the service to have at least the defined
     /// [`Alignment`]. If the Payload [`Alignment`] is greater than the provided [`Alignment`]
@@ -344,6 +430,22 @@ impl<
         self
     }
 
+    /// If enabled, opening an existing [`Service`] fails with
+    /// [`PublishSubscribeOpenError::IncompatibleTypeName`] when the stored payload type name
+    /// differs from the requested one, even if size and alignment match. Has no effect when
+    /// the [`Service`] is created.
+    pub fn require_matching_type_name(mut self, value: bool) -> Self {
+        self.verify_type_name = value;
+        self
+    }
+
+    /// Replaces the payload [`TypeDetail`] that is stored in the [`Service`] and verified when
+    /// it is opened.
+    pub(crate) fn set_payload_type_details(mut self, value: &TypeDetail) -> Self {
+        self.override_payload_type = Some(*value);
+        self
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
@@ -382,6 +484,15 @@ impl<
     /// If the [`Service`] is created it defines how many [`crate::port::subscriber::Subscriber`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::subscriber::Subscriber`] must be at least supported.
+    ///
+    /// # Important
+    ///
+    /// The limit is fixed for the lifetime of the [`Service`]. The subscriber list in the
+    /// dynamic config is a lock-free container with a fixed capacity and every
+    /// [`crate::port::publisher::Publisher`] sizes its connections and its data segment by
+    /// this value when it is created. To support more
+    /// [`crate::port::subscriber::Subscriber`]s, all ports have to be dropped and the
+    /// [`Service`] has to be recreated with a larger limit.
     pub fn max_subscribers(mut self, value: usize) -> Self {
         self.config_details_mut().max_subscribers = value;
         self.verify_number_of_subscribers = true;
@@ -408,7 +519,9 @@ impl<
 
     /// Validates configuration and overrides the invalid setting with meaningful values.
     fn adjust_configuration_to_meaningful_values(&mut self) {
+        let shared_node = self.base.shared_node.clone();
         let origin = format!("{self:?}");
+        let origin = shared_node.log_origin(&origin);
         let settings = self.base.service_config.publish_subscribe_mut();
 
         if settings.subscriber_max_borrowed_samples == 0 {
@@ -451,7 +564,7 @@ impl<
 
         let existing_attributes = existing_settings.attributes();
         if let Err(incompatible_key) = verifier.verify_requirements(existing_attributes) {
-            fail!(from self, with PublishSubscribeOpenError::IncompatibleAttributes,
+            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::IncompatibleAttributes,
                 "{} due to incompatible service attribute key \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
                 msg, incompatible_key, verifier, existing_attributes);
         }
@@ -460,7 +573,7 @@ impl<
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::PublishSubscribe(v) => v,
             p => {
-                fail!(from self, with PublishSubscribeOpenError::IncompatibleMessagingPattern,
+                fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::IncompatibleMessagingPattern,
                 "{} since a service with the messaging pattern {:?} exists but MessagingPattern::PublishSubscribe is required.", msg, p);
             }
         };
@@ -468,7 +581,7 @@ impl<
         if self.verify_number_of_publishers
             && existing_settings.max_publishers < required_settings.max_publishers
         {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers,
+            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers,
                                 "{} since the service supports only {} publishers but a support of {} publishers was requested.",
                                 msg, existing_settings.max_publishers, required_settings.max_publishers);
         }
@@ -476,7 +589,7 @@ impl<
         if self.verify_number_of_subscribers
             && existing_settings.max_subscribers < required_settings.max_subscribers
         {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers,
+            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers,
                                 "{} since the service supports only {} subscribers but a support of {} subscribers was requested.",
                                 msg, existing_settings.max_subscribers, required_settings.max_subscribers);
         }
@@ -485,7 +598,7 @@ impl<
             && existing_settings.subscriber_max_buffer_size
                 < required_settings.subscriber_max_buffer_size
         {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize,
+            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize,
                                 "{} since the service supports only a subscriber buffer size of {} but a buffer size of {} was requested.",
                                 msg, existing_settings.subscriber_max_buffer_size, required_settings.subscriber_max_buffer_size);
         }
@@ -493,7 +606,7 @@ impl<
         if self.verify_publisher_history_size
             && existing_settings.history_size < required_settings.history_size
         {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize,
+            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize,
                                 "{} since the service supports only a history size of {} but a history size of {} was requested.",
                                 msg, existing_settings.history_size, required_settings.history_size);
         }
@@ -502,7 +615,7 @@ impl<
             && existing_settings.subscriber_max_borrowed_samples
                 < required_settings.subscriber_max_borrowed_samples
         {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples,
+            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples,
                                 "{} since the service supports only {} borrowed subscriber samples but a {} borrowed subscriber samples were requested.",
                                 msg, existing_settings.subscriber_max_borrowed_samples, required_settings.subscriber_max_borrowed_samples);
         }
@@ -510,13 +623,13 @@ impl<
         if self.verify_enable_safe_overflow
             && existing_settings.enable_safe_overflow != required_settings.enable_safe_overflow
         {
-            fail!(from self, with PublishSubscribeOpenError::IncompatibleOverflowBehavior,
+            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::IncompatibleOverflowBehavior,
                                 "{} since the service has an incompatible safe overflow behavior.",
                                 msg);
         }
 
         if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
+            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
                                 "{} since the service supports only {} nodes but {} are required.",
                                 msg, existing_settings.max_nodes, required_settings.max_nodes);
         }
@@ -539,7 +652,7 @@ impl<
             && (self.config_details().subscriber_max_buffer_size
                 < self.config_details().history_size)
         {
-            fail!(from self, with PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize,
+            fail!(from self.base.log_origin(self), with PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize,
                 "{} since the history size is greater than the subscriber buffer size. The subscriber buffer size must be always greater or equal to the history size in the non-overflowing setup.", msg);
         }
 
@@ -553,23 +666,26 @@ impl<
                 let static_config = match self.base.create_static_config_storage() {
                     Ok(c) => c,
                     Err(StaticStorageCreateError::AlreadyExists) => {
-                        fail!(from self, with PublishSubscribeCreateError::AlreadyExists,
+                        fail!(from self.base.log_origin(self), with PublishSubscribeCreateError::AlreadyExists,
                            "{} since the service already exists.", msg);
                     }
                     Err(StaticStorageCreateError::Creation) => {
-                        fail!(from self, with PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance,
+                        fail!(from self.base.log_origin(self), with PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance,
                             "{} since the service is being created by another instance.", msg);
                     }
                     Err(StaticStorageCreateError::InsufficientPermissions) => {
-                        fail!(from self, with PublishSubscribeCreateError::InsufficientPermissions,
+                        fail!(from self.base.log_origin(self), with PublishSubscribeCreateError::InsufficientPermissions,
                             "{} since the static service information could not be created due to insufficient permissions.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with PublishSubscribeCreateError::InternalFailure,
+                        fail!(from self.base.log_origin(self), with PublishSubscribeCreateError::InternalFailure,
                             "{} since the static service information could not be created due to an internal failure ({:?}).", msg, e);
                     }
                 };
 
+                self.base
+                    .update_creation_epoch(msg, PublishSubscribeCreateError::InternalFailure)?;
+
                 let pubsub_config = self.base.service_config.publish_subscribe();
 
                 // create dynamic config
@@ -587,23 +703,23 @@ impl<
                 ) {
                     Ok(dynamic_config) => dynamic_config,
                     Err(DynamicStorageCreateError::AlreadyExists) => {
-                        fail!(from self, with PublishSubscribeCreateError::ServiceInCorruptedState,
+                        fail!(from self.base.log_origin(self), with PublishSubscribeCreateError::ServiceInCorruptedState,
                             "{} since the dynamic config of a previous instance of the service still exists.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with PublishSubscribeCreateError::InternalFailure,
+                        fail!(from self.base.log_origin(self), with PublishSubscribeCreateError::InternalFailure,
                             "{} since the dynamic service segment could not be created ({:?}).", msg, e);
                     }
                 };
 
                 self.base.service_config.attributes = attributes.0.clone();
-                let service_config = fail!(from self,
+                let service_config = fail!(from self.base.log_origin(self),
                             when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                             with PublishSubscribeCreateError::ServiceInCorruptedState,
                             "{} since the configuration could not be serialized.", msg);
 
                 // only unlock the static details when the service is successfully created
-                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                let unlocked_static_details = fail!(from self.base.log_origin(self), when static_config.unlock(service_config.as_slice()),
                             with PublishSubscribeCreateError::ServiceInCorruptedState,
                             "{} since the configuration could not be written to the static storage.", msg);
 
@@ -619,16 +735,47 @@ impl<
                         dynamic_config,
                         unlocked_static_details,
                         NoResource,
+                        true,
                     ),
                 ))
             }
             Some(_) => {
-                fail!(from self, with PublishSubscribeCreateError::AlreadyExists,
+                fail!(from self.base.log_origin(self), with PublishSubscribeCreateError::AlreadyExists,
                     "{} since the service already exists.", msg);
             }
         }
     }
 
+    fn open_with_timeout_impl(
+        &mut self,
+        attributes: &AttributeVerifier,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        let msg = "Unable to open publish subscribe service with timeout";
+        let mut adaptive_wait = fail!(from self.base.log_origin(self), when AdaptiveWaitBuilder::new().create(),
+                with PublishSubscribeOpenError::InternalFailure,
+                "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.open_impl(attributes) {
+                Err(PublishSubscribeOpenError::DoesNotExist) => (),
+                result => return result,
+            }
+
+            let elapsed_time = fail!(from self.base.log_origin(self), when adaptive_wait.wait(),
+                    with PublishSubscribeOpenError::InternalFailure,
+                    "{} since the adaptive wait call failed.", msg);
+
+            if elapsed_time >= timeout {
+                fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::Timeout,
+                    "{} since the service did not appear within {:?}.", msg, timeout);
+            }
+        }
+    }
+
     fn open_impl(
         &mut self,
         attributes: &AttributeVerifier,
@@ -642,7 +789,7 @@ impl<
         loop {
             match self.is_service_available(msg)? {
                 None => {
-                    fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
+                    fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::DoesNotExist,
                         "{} since the service does not exist.", msg);
                 }
                 Some((static_config, static_storage)) => {
@@ -656,29 +803,29 @@ impl<
                     let dynamic_config = match self.base.open_dynamic_config_storage() {
                         Ok(v) => v,
                         Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
-                            fail!(from self, with PublishSubscribeOpenError::IsMarkedForDestruction,
+                            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::IsMarkedForDestruction,
                                 "{} since the service is marked for destruction.", msg);
                         }
                         Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
-                            fail!(from self, with PublishSubscribeOpenError::ExceedsMaxNumberOfNodes,
+                            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::ExceedsMaxNumberOfNodes,
                                 "{} since it would exceed the maximum number of supported nodes.", msg);
                         }
                         Err(OpenDynamicStorageFailure::DynamicStorageOpenError(
                             DynamicStorageOpenError::DoesNotExist,
                         )) => {
-                            fail!(from self, with PublishSubscribeOpenError::ServiceInCorruptedState,
+                            fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::ServiceInCorruptedState,
                                 "{} since the dynamic segment of the service is missing.", msg);
                         }
                         Err(e) => {
                             if self.is_service_available(msg)?.is_none() {
-                                fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
+                                fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::DoesNotExist,
                                     "{} since the service does not exist.", msg);
                             }
 
                             service_open_retry_count += 1;
 
                             if RETRY_LIMIT < service_open_retry_count {
-                                fail!(from self, with PublishSubscribeOpenError::ServiceInCorruptedState,
+                                fail!(from self.base.log_origin(self), with PublishSubscribeOpenError::ServiceInCorruptedState,
                                 "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
                                 msg, e);
                             }
@@ -701,6 +848,7 @@ impl<
                             dynamic_config,
                             static_storage,
                             NoResource,
+                            false,
                         ),
                     ));
                 }
@@ -712,7 +860,10 @@ impl<
         mut self,
         verifier: &AttributeVerifier,
     ) -> Result<
-        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        (
+            publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+            bool,
+        ),
         PublishSubscribeOpenOrCreateError,
     > {
         let msg = "Unable to open or create publish subscribe service";
@@ -720,7 +871,7 @@ impl<
         let mut retry_count = 0;
         loop {
             if RETRY_LIMIT < retry_count {
-                fail!(from self,
+                fail!(from self.base.log_origin(self),
                       with PublishSubscribeOpenOrCreateError::SystemInFlux,
                       "{} since an instance is creating and removing the same service repeatedly.",
                       msg);
@@ -729,7 +880,7 @@ impl<
 
             match self.is_service_available(msg)? {
                 Some(_) => match self.open_impl(verifier) {
-                    Ok(factory) => return Ok(factory),
+                    Ok(factory) => return Ok((factory, false)),
                     Err(PublishSubscribeOpenError::DoesNotExist) => continue,
                     Err(e) => return Err(e.into()),
                 },
@@ -737,7 +888,7 @@ impl<
                     match self
                         .create_impl(&AttributeSpecifier(verifier.required_attributes().clone()))
                     {
-                        Ok(factory) => return Ok(factory),
+                        Ok(factory) => return Ok((factory, true)),
                         Err(PublishSubscribeCreateError::AlreadyExists)
                         | Err(PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance) => {
                             continue;
@@ -749,6 +900,48 @@ impl<
         }
     }
 
+    fn resource_estimate(&self, initial_max_slice_len: usize) -> ResourceEstimate {
+        let pubsub_config = self.config_details();
+        let dynamic_config_setting = DynamicConfigSettings {
+            number_of_publishers: pubsub_config.max_publishers,
+            number_of_subscribers: pubsub_config.max_subscribers,
+        };
+
+        let static_config_bytes = match ServiceType::ConfigSerializer::serialize(
+            &self.base.service_config,
+        ) {
+            Ok(serialized_config) => serialized_config.len(),
+            Err(e) => {
+                warn!(from self.base.log_origin(self),
+                        "Unable to serialize the configuration ({:?}), the static config is not part of the estimate.", e);
+                0
+            }
+        };
+
+        let max_loaned_samples = self
+            .base
+            .shared_node
+            .config()
+            .defaults
+            .publish_subscribe
+            .publisher_max_loaned_samples;
+        let segment_bytes_per_publisher = DataSegment::<ServiceType>::segment_size(
+            pubsub_config.sample_layout(initial_max_slice_len),
+            pubsub_config.required_amount_of_samples_per_data_segment(max_loaned_samples),
+        );
+
+        ResourceEstimate {
+            static_config_bytes,
+            dynamic_config_bytes: core::mem::size_of::<dynamic_config::DynamicConfig>()
+                + dynamic_config::DynamicConfig::memory_size(pubsub_config.max_nodes)
+                + dynamic_config::publish_subscribe::DynamicConfig::memory_size(
+                    &dynamic_config_setting,
+                ),
+            segment_bytes_per_publisher,
+            total_segment_bytes: segment_bytes_per_publisher * pubsub_config.max_publishers,
+        }
+    }
+
     fn adjust_payload_alignment(&mut self) {
         if let Some(alignment) = self.override_alignment {
             self.config_details_mut()
@@ -802,6 +995,37 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
         self.adjust_payload_alignment();
     }
 
+    /// Returns the [`ResourceEstimate`] of the [`Service`] with the current settings of the
+    /// [`Builder`] without creating anything. It assumes that the maximum number of
+    /// [`crate::port::publisher::Publisher`]s is connected and that every
+    /// [`crate::port::publisher::Publisher`] uses the default number of loaned samples from
+    /// the [`Config`](crate::config::Config). A dynamic data segment is estimated with the
+    /// size of its initial segment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let estimate = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .max_publishers(2)
+    ///     .estimate_resource_usage();
+    ///
+    /// println!("the service will occupy {} bytes", estimate.total_bytes());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn estimate_resource_usage(&self) -> ResourceEstimate {
+        let mut builder = self.clone();
+        builder.prepare_config_details();
+        builder.adjust_configuration_to_meaningful_values();
+        builder.resource_estimate(1)
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(
@@ -827,7 +1051,24 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
         PublishSubscribeOpenOrCreateError,
     > {
         self.prepare_config_details();
-        self.open_or_create_impl(verifier)
+        Ok(self.open_or_create_impl(verifier)?.0)
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. Additionally to the [`publish_subscribe::PortFactory`] it returns `true` when
+    /// this call created the [`Service`] and `false` when it was created by someone else,
+    /// for instance when another process won the race to create it.
+    pub fn create_or_attach(
+        mut self,
+    ) -> Result<
+        (
+            publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+            bool,
+        ),
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.prepare_config_details();
+        self.open_or_create_impl(&AttributeVerifier::new())
     }
 
     /// Opens an existing [`Service`].
@@ -840,6 +1081,23 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
         self.open_with_attributes(&AttributeVerifier::new())
     }
 
+    /// Opens an existing [`Service`] like [`Builder::open()`] but compares the requested
+    /// user header type with the one of the [`Service`] under the provided
+    /// [`TypeCompatibility`] policy. With [`TypeCompatibility::MatchingLayout`] a user header
+    /// type with a different name but the same size and a compatible alignment is accepted,
+    /// for instance a newer header version whose additional fields replaced former padding.
+    /// [`Builder::open()`] applies [`TypeCompatibility::MatchingTypeName`].
+    pub fn open_with_header_compatibility(
+        mut self,
+        policy: TypeCompatibility,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.user_header_compatibility = policy;
+        self.open()
+    }
+
     /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
     /// requirements are not satisfied the open process will fail.
     pub fn open_with_attributes(
@@ -853,6 +1111,53 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
         self.open_impl(verifier)
     }
 
+    /// Opens an existing [`Service`]. If the [`Service`] does not exist yet, it retries with an
+    /// increasing back-off until the [`Service`] appears or the provided `timeout` has passed.
+    /// In the latter case it returns [`PublishSubscribeOpenError::Timeout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let _service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .create()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_with_timeout(Duration::from_secs(1))?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn open_with_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.open_with_attributes_and_timeout(&AttributeVerifier::new(), timeout)
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the [`Service`] does not
+    /// exist yet, it retries with an increasing back-off until the [`Service`] appears or the
+    /// provided `timeout` has passed.
+    pub fn open_with_attributes_and_timeout(
+        mut self,
+        verifier: &AttributeVerifier,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.prepare_config_details();
+        self.open_with_timeout_impl(verifier, timeout)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(
         self,
@@ -894,6 +1199,21 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
         self.adjust_payload_alignment();
     }
 
+    /// Returns the [`ResourceEstimate`] of the [`Service`] with the current settings of the
+    /// [`Builder`] without creating anything, when every [`crate::port::publisher::Publisher`]
+    /// is created with the provided
+    /// [`PortFactoryPublisher::initial_max_slice_len()`](crate::service::port_factory::publisher::PortFactoryPublisher::initial_max_slice_len()).
+    /// It assumes that the maximum number of [`crate::port::publisher::Publisher`]s is
+    /// connected and that every [`crate::port::publisher::Publisher`] uses the default number
+    /// of loaned samples from the [`Config`](crate::config::Config). A dynamic data segment is
+    /// estimated with the size of its initial segment.
+    pub fn estimate_resource_usage(&self, initial_max_slice_len: usize) -> ResourceEstimate {
+        let mut builder = self.clone();
+        builder.prepare_config_details();
+        builder.adjust_configuration_to_meaningful_values();
+        builder.resource_estimate(initial_max_slice_len)
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(
@@ -917,7 +1237,24 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
         PublishSubscribeOpenOrCreateError,
     > {
         self.prepare_config_details();
-        self.open_or_create_impl(attributes)
+        Ok(self.open_or_create_impl(attributes)?.0)
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. Additionally to the [`publish_subscribe::PortFactory`] it returns `true` when
+    /// this call created the [`Service`] and `false` when it was created by someone else,
+    /// for instance when another process won the race to create it.
+    pub fn create_or_attach(
+        mut self,
+    ) -> Result<
+        (
+            publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+            bool,
+        ),
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.prepare_config_details();
+        self.open_or_create_impl(&AttributeVerifier::new())
     }
 
     /// Opens an existing [`Service`].
@@ -930,6 +1267,23 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
         self.open_with_attributes(&AttributeVerifier::new())
     }
 
+    /// Opens an existing [`Service`] like [`Builder::open()`] but compares the requested
+    /// user header type with the one of the [`Service`] under the provided
+    /// [`TypeCompatibility`] policy. With [`TypeCompatibility::MatchingLayout`] a user header
+    /// type with a different name but the same size and a compatible alignment is accepted,
+    /// for instance a newer header version whose additional fields replaced former padding.
+    /// [`Builder::open()`] applies [`TypeCompatibility::MatchingTypeName`].
+    pub fn open_with_header_compatibility(
+        mut self,
+        policy: TypeCompatibility,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.user_header_compatibility = policy;
+        self.open()
+    }
+
     /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
     /// requirements are not satisfied the open process will fail.
     pub fn open_with_attributes(
@@ -943,6 +1297,34 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
         self.open_impl(attributes)
     }
 
+    /// Opens an existing [`Service`]. If the [`Service`] does not exist yet, it retries with an
+    /// increasing back-off until the [`Service`] appears or the provided `timeout` has passed.
+    /// In the latter case it returns [`PublishSubscribeOpenError::Timeout`].
+    pub fn open_with_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.open_with_attributes_and_timeout(&AttributeVerifier::new(), timeout)
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the [`Service`] does not
+    /// exist yet, it retries with an increasing back-off until the [`Service`] appears or the
+    /// provided `timeout` has passed.
+    pub fn open_with_attributes_and_timeout(
+        mut self,
+        attributes: &AttributeVerifier,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.prepare_config_details();
+        self.open_with_timeout_impl(attributes, timeout)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(
         self,
@@ -325,7 +325,7 @@ impl<
         match self.base.service_config.messaging_pattern {
             static_config::messaging_pattern::MessagingPattern::RequestResponse(ref mut v) => v,
             _ => {
-                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+                fatal_panic!(from self.base.log_origin(self), "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
             }
         }
     }
@@ -334,7 +334,7 @@ impl<
         match self.base.service_config.messaging_pattern {
             static_config::messaging_pattern::MessagingPattern::RequestResponse(ref v) => v,
             _ => {
-                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+                fatal_panic!(from self.base.log_origin(self), "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
             }
         }
     }
@@ -471,7 +471,9 @@ impl<
     }
 
     fn adjust_configuration_to_meaningful_values(&mut self) {
+        let shared_node = self.base.shared_node.clone();
         let origin = format!("{self:?}");
+        let origin = shared_node.log_origin(&origin);
         let settings = self.base.service_config.request_response_mut();
 
         if settings.max_response_buffer_size == 0 {
@@ -526,7 +528,7 @@ impl<
 
         let existing_attributes = existing_settings.attributes();
         if let Err(incompatible_key) = verifier.verify_requirements(existing_attributes) {
-            fail!(from self, with RequestResponseOpenError::IncompatibleAttributes,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::IncompatibleAttributes,
                 "{} due to incompatible service attribute key \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
                 msg, incompatible_key, verifier, existing_attributes);
         }
@@ -535,7 +537,7 @@ impl<
         let existing_configuration = match &existing_settings.messaging_pattern {
             MessagingPattern::RequestResponse(v) => v,
             p => {
-                fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                fail!(from self.base.log_origin(self), with RequestResponseOpenError::IncompatibleMessagingPattern,
                     "{} since a service with the messaging pattern {:?} exists but MessagingPattern::RequestResponse is required.",
                     msg, p);
             }
@@ -545,7 +547,7 @@ impl<
             && existing_configuration.enable_safe_overflow_for_requests
                 != required_configuration.enable_safe_overflow_for_requests
         {
-            fail!(from self, with RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests,
                 "{} since the service has an incompatible safe overflow behavior for requests.",
                 msg);
         }
@@ -554,7 +556,7 @@ impl<
             && existing_configuration.enable_safe_overflow_for_responses
                 != required_configuration.enable_safe_overflow_for_responses
         {
-            fail!(from self, with RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses,
                 "{} since the service has an incompatible safe overflow behavior for responses.",
                 msg);
         }
@@ -563,7 +565,7 @@ impl<
             && existing_configuration.enable_fire_and_forget_requests
                 != required_configuration.enable_fire_and_forget_requests
         {
-            fail!(from self, with RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests,
                 "{} since the service has an incompatible behavior for fire and forget requests.",
                 msg);
         }
@@ -572,7 +574,7 @@ impl<
             && existing_configuration.max_active_requests_per_client
                 < required_configuration.max_active_requests_per_client
         {
-            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient,
                 "{} since the service supports only {} active requests per client but {} are required.",
                 msg, existing_configuration.max_active_requests_per_client, required_configuration.max_active_requests_per_client);
         }
@@ -581,7 +583,7 @@ impl<
             && existing_configuration.max_loaned_requests
                 < required_configuration.max_loaned_requests
         {
-            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClientRequestLoans,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClientRequestLoans,
                 "{} since the service supports only {} loaned requests per client but {} are required.",
                 msg, existing_configuration.max_loaned_requests, required_configuration.max_loaned_requests);
         }
@@ -590,7 +592,7 @@ impl<
             && existing_configuration.max_borrowed_responses_per_pending_response
                 < required_configuration.max_borrowed_responses_per_pending_response
         {
-            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedResponsesPerPendingResponse,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedResponsesPerPendingResponse,
                 "{} since the service supports only {} borrowed responses per pending response but {} are required.",
                 msg, existing_configuration.max_borrowed_responses_per_pending_response, required_configuration.max_borrowed_responses_per_pending_response);
         }
@@ -599,7 +601,7 @@ impl<
             && existing_configuration.max_response_buffer_size
                 < required_configuration.max_response_buffer_size
         {
-            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize,
                 "{} since the service supports a maximum response buffer size of {} but a size of {} is required.",
                 msg, existing_configuration.max_response_buffer_size, required_configuration.max_response_buffer_size);
         }
@@ -607,7 +609,7 @@ impl<
         if self.verify_max_servers
             && existing_configuration.max_servers < required_configuration.max_servers
         {
-            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers,
                 "{} since the service supports at most {} servers but {} are required.",
                 msg, existing_configuration.max_servers, required_configuration.max_servers);
         }
@@ -615,7 +617,7 @@ impl<
         if self.verify_max_clients
             && existing_configuration.max_clients < required_configuration.max_clients
         {
-            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients,
                 "{} since the service supports at most {} clients but {} are required.",
                 msg, existing_configuration.max_clients, required_configuration.max_clients);
         }
@@ -623,7 +625,7 @@ impl<
         if self.verify_max_nodes
             && existing_configuration.max_nodes < required_configuration.max_nodes
         {
-            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes,
+            fail!(from self.base.log_origin(self), with RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes,
                 "{} since the service supports at most {} nodes but {} are required.",
                 msg, existing_configuration.max_nodes, required_configuration.max_nodes);
         }
@@ -645,7 +647,7 @@ impl<
                     .request_message_type_details
                     .is_compatible_to(&config.request_response().request_message_type_details)
                 {
-                    fail!(from self, with ServiceAvailabilityState::IncompatibleRequestType,
+                    fail!(from self.base.log_origin(self), with ServiceAvailabilityState::IncompatibleRequestType,
                         "{} since the services uses the request type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
                         error_msg, &config.request_response().request_message_type_details,
                         self.config_details().request_message_type_details);
@@ -656,7 +658,7 @@ impl<
                     .response_message_type_details
                     .is_compatible_to(&config.request_response().response_message_type_details)
                 {
-                    fail!(from self, with ServiceAvailabilityState::IncompatibleResponseType,
+                    fail!(from self.base.log_origin(self), with ServiceAvailabilityState::IncompatibleResponseType,
                         "{} since the services uses the response type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
                         error_msg, &config.request_response().response_message_type_details,
                         self.config_details().response_message_type_details);
@@ -687,7 +689,7 @@ impl<
 
         match self.is_service_available(msg)? {
             Some(_) => {
-                fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                fail!(from self.base.log_origin(self), with RequestResponseCreateError::AlreadyExists,
                     "{} since the service already exists.",
                     msg);
             }
@@ -699,25 +701,28 @@ impl<
                 let static_config = match self.base.create_static_config_storage() {
                     Ok(static_config) => static_config,
                     Err(StaticStorageCreateError::AlreadyExists) => {
-                        fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                        fail!(from self.base.log_origin(self), with RequestResponseCreateError::AlreadyExists,
                             "{} since the service already exists.", msg);
                     }
                     Err(StaticStorageCreateError::Creation) => {
-                        fail!(from self, with RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
+                        fail!(from self.base.log_origin(self), with RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
                             "{} since the service is being created by another instance.", msg);
                     }
                     Err(StaticStorageCreateError::InsufficientPermissions) => {
-                        fail!(from self, with RequestResponseCreateError::InsufficientPermissions,
+                        fail!(from self.base.log_origin(self), with RequestResponseCreateError::InsufficientPermissions,
                             "{} since the static service information could not be created due to insufficient permissions.",
                             msg);
                     }
                     Err(e) => {
-                        fail!(from self, with RequestResponseCreateError::InternalFailure,
+                        fail!(from self.base.log_origin(self), with RequestResponseCreateError::InternalFailure,
                             "{} since the static service information could not be created due to an internal failure ({:?}).",
                             msg, e);
                     }
                 };
 
+                self.base
+                    .update_creation_epoch(msg, RequestResponseCreateError::InternalFailure)?;
+
                 let request_response_config = self.base.service_config.request_response();
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_servers: request_response_config.max_servers,
@@ -733,25 +738,25 @@ impl<
                 ) {
                     Ok(dynamic_config) => dynamic_config,
                     Err(DynamicStorageCreateError::AlreadyExists) => {
-                        fail!(from self, with RequestResponseCreateError::ServiceInCorruptedState,
+                        fail!(from self.base.log_origin(self), with RequestResponseCreateError::ServiceInCorruptedState,
                             "{} since the dynamic config of a previous instance of the service still exists.",
                             msg);
                     }
                     Err(e) => {
-                        fail!(from self, with RequestResponseCreateError::InternalFailure,
+                        fail!(from self.base.log_origin(self), with RequestResponseCreateError::InternalFailure,
                             "{} since the dynamic service segment could not be created ({:?}).",
                             msg, e);
                     }
                 };
 
                 self.base.service_config.attributes = attributes.0.clone();
-                let serialized_service_config = fail!(from self,
+                let serialized_service_config = fail!(from self.base.log_origin(self),
                           when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                           with RequestResponseCreateError::ServiceInCorruptedState,
                           "{} since the configuration could not be serialized.",
                           msg);
 
-                let unlocked_static_details = fail!(from self,
+                let unlocked_static_details = fail!(from self.base.log_origin(self),
                         when static_config.unlock(serialized_service_config.as_slice()),
                         with RequestResponseCreateError::ServiceInCorruptedState,
                         "{} since the configuration could not be written into the static storage.",
@@ -769,6 +774,7 @@ impl<
                         dynamic_config,
                         unlocked_static_details,
                         NoResource,
+                        true,
                     ),
                 ))
             }
@@ -795,7 +801,7 @@ impl<
         loop {
             match self.is_service_available(msg)? {
                 None => {
-                    fail!(from self, with RequestResponseOpenError::DoesNotExist,
+                    fail!(from self.base.log_origin(self), with RequestResponseOpenError::DoesNotExist,
                         "{} since the service does not exist.",
                         msg);
                 }
@@ -810,32 +816,32 @@ impl<
                     let dynamic_config = match self.base.open_dynamic_config_storage() {
                         Ok(v) => v,
                         Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
-                            fail!(from self, with RequestResponseOpenError::IsMarkedForDestruction,
+                            fail!(from self.base.log_origin(self), with RequestResponseOpenError::IsMarkedForDestruction,
                                 "{} since the service is marked for destruction.",
                                 msg);
                         }
                         Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
-                            fail!(from self, with RequestResponseOpenError::ExceedsMaxNumberOfNodes,
+                            fail!(from self.base.log_origin(self), with RequestResponseOpenError::ExceedsMaxNumberOfNodes,
                                 "{} since it would exceed the maximum number of supported nodes.",
                                 msg);
                         }
                         Err(OpenDynamicStorageFailure::DynamicStorageOpenError(
                             DynamicStorageOpenError::DoesNotExist,
                         )) => {
-                            fail!(from self, with RequestResponseOpenError::ServiceInCorruptedState,
+                            fail!(from self.base.log_origin(self), with RequestResponseOpenError::ServiceInCorruptedState,
                                 "{} since the dynamic segment of the service is missing.",
                                 msg);
                         }
                         Err(e) => {
                             if self.is_service_available(msg)?.is_none() {
-                                fail!(from self, with RequestResponseOpenError::DoesNotExist,
+                                fail!(from self.base.log_origin(self), with RequestResponseOpenError::DoesNotExist,
                                     "{} since the service does not exist.", msg);
                             }
 
                             service_open_retry_count += 1;
 
                             if OPEN_RETRY_LIMIT < service_open_retry_count {
-                                fail!(from self, with RequestResponseOpenError::ServiceInCorruptedState,
+                                fail!(from self.base.log_origin(self), with RequestResponseOpenError::ServiceInCorruptedState,
                                     "{} since the dynamic service information could not be opened ({:?}).",
                                     msg, e);
                             }
@@ -858,6 +864,7 @@ impl<
                             dynamic_config,
                             static_storage,
                             NoResource,
+                            false,
                         ),
                     ));
                 }
@@ -883,7 +890,7 @@ impl<
         let mut retry_count = 0;
         loop {
             if RETRY_LIMIT < retry_count {
-                fail!(from self,
+                fail!(from self.base.log_origin(self),
                       with RequestResponseOpenOrCreateError::SystemInFlux,
                       "{} since an instance is creating and removing the same service repeatedly.",
                       msg);
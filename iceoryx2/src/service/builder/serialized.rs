@@ -0,0 +1,190 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! struct Telemetry {
+//!     label: String,
+//!     values: Vec<f64>,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .serialized::<Telemetry>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher()?;
+//! let subscriber = service.subscriber()?;
+//!
+//! publisher.send(&Telemetry { label: "temperature".into(), values: vec![21.5, 22.0] })?;
+//!
+//! if let Some(telemetry) = subscriber.receive()? {
+//!     println!("received {:?}", telemetry);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See [`crate::service`]
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use iceoryx2_log::fatal_panic;
+
+use crate::service;
+use crate::service::attribute::{AttributeSpecifier, AttributeVerifier};
+use crate::service::port_factory::serialized;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeName, TypeVariant};
+
+use super::publish_subscribe::{
+    self, PublishSubscribeCreateError, PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+};
+
+/// Builder to create new serialized [`Service`](crate::service::Service)s. A serialized
+/// [`Service`](crate::service::Service) transmits types that are not
+/// [`ZeroCopySend`](iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend) by
+/// serializing them into a
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// [`Service`](crate::service::Service) with the payload `[u8]`. It is a convenience for
+/// prototyping and not zero-copy, every send and receive serializes and deserializes a copy
+/// of the value. The name of `T` is stored in the [`Service`](crate::service::Service), opening
+/// it with a different type fails with
+/// [`PublishSubscribeOpenError::IncompatibleTypeName`].
+#[derive(Debug)]
+pub struct Builder<T: Debug + Serialize + DeserializeOwned, ServiceType: service::Service> {
+    builder: publish_subscribe::Builder<[u8], (), ServiceType>,
+    _data: PhantomData<T>,
+}
+
+impl<T: Debug + Serialize + DeserializeOwned, ServiceType: service::Service>
+    Builder<T, ServiceType>
+{
+    pub(crate) fn new(builder: publish_subscribe::Builder<[u8], (), ServiceType>) -> Self {
+        // the payload is stored as `[u8]` but carries the name of `T` so that a service can
+        // only be opened with the same serialized type
+        let mut payload_type = TypeDetail::new::<u8>(TypeVariant::Dynamic);
+        payload_type.type_name = fatal_panic!(
+            from "serialized::Builder::new()",
+            when TypeName::try_from(core::any::type_name::<T>()),
+            "Name of type T does not fit into fixed-size TypeNameString"
+        );
+
+        Self {
+            builder: builder
+                .set_payload_type_details(&payload_type)
+                .require_matching_type_name(true),
+            _data: PhantomData,
+        }
+    }
+
+    /// If the [`Service`](crate::service::Service) is created it defines how many values a
+    /// [`SerializedSubscriber`](crate::port::serialized::SerializedSubscriber) can store until
+    /// they are received. If an existing [`Service`](crate::service::Service) is opened it
+    /// defines the minimum required.
+    pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
+        self.builder = self.builder.subscriber_max_buffer_size(value);
+        self
+    }
+
+    /// If the [`Service`](crate::service::Service) is created it defines how many
+    /// [`SerializedSubscriber`](crate::port::serialized::SerializedSubscriber)s shall be
+    /// supported at most. If an existing [`Service`](crate::service::Service) is opened it
+    /// defines how many [`SerializedSubscriber`](crate::port::serialized::SerializedSubscriber)s
+    /// must be at least supported.
+    pub fn max_subscribers(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_subscribers(value);
+        self
+    }
+
+    /// If the [`Service`](crate::service::Service) is created it defines how many
+    /// [`SerializedPublisher`](crate::port::serialized::SerializedPublisher)s shall be
+    /// supported at most. If an existing [`Service`](crate::service::Service) is opened it
+    /// defines how many [`SerializedPublisher`](crate::port::serialized::SerializedPublisher)s
+    /// must be at least supported.
+    pub fn max_publishers(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_publishers(value);
+        self
+    }
+
+    /// If the [`Service`](crate::service::Service) is created it defines how many
+    /// [`Node`](crate::node::Node)s shall be able to open it in parallel. If an existing
+    /// [`Service`](crate::service::Service) is opened it defines how many
+    /// [`Node`](crate::node::Node)s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_nodes(value);
+        self
+    }
+
+    /// If the [`Service`](crate::service::Service) exists, it will be opened otherwise a new
+    /// [`Service`](crate::service::Service) will be created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<serialized::PortFactory<ServiceType, T>, PublishSubscribeOpenOrCreateError> {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`](crate::service::Service) exists, it will be opened otherwise a new
+    /// [`Service`](crate::service::Service) will be created. It defines a set of attributes.
+    pub fn open_or_create_with_attributes(
+        self,
+        verifier: &AttributeVerifier,
+    ) -> Result<serialized::PortFactory<ServiceType, T>, PublishSubscribeOpenOrCreateError> {
+        Ok(serialized::PortFactory::new(
+            self.builder.open_or_create_with_attributes(verifier)?,
+        ))
+    }
+
+    /// Opens an existing [`Service`](crate::service::Service).
+    pub fn open(
+        self,
+    ) -> Result<serialized::PortFactory<ServiceType, T>, PublishSubscribeOpenError> {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`](crate::service::Service) with attribute requirements. If
+    /// the defined attribute requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        self,
+        verifier: &AttributeVerifier,
+    ) -> Result<serialized::PortFactory<ServiceType, T>, PublishSubscribeOpenError> {
+        Ok(serialized::PortFactory::new(
+            self.builder.open_with_attributes(verifier)?,
+        ))
+    }
+
+    /// Creates a new [`Service`](crate::service::Service).
+    pub fn create(
+        self,
+    ) -> Result<serialized::PortFactory<ServiceType, T>, PublishSubscribeCreateError> {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`](crate::service::Service) with a set of attributes.
+    pub fn create_with_attributes(
+        self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<serialized::PortFactory<ServiceType, T>, PublishSubscribeCreateError> {
+        Ok(serialized::PortFactory::new(
+            self.builder.create_with_attributes(attributes)?,
+        ))
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let signal = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .signal()
+//!     .subscriber_max_buffer_size(16)
+//!     .open_or_create()?;
+//!
+//! let publisher = signal.publisher()?;
+//! let subscriber = signal.subscriber()?;
+//!
+//! publisher.notify()?;
+//!
+//! if subscriber.receive_signal()?.is_some() {
+//!     println!("received {} signals", subscriber.coalesced_signals());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See [`crate::service`]
+
+use crate::service;
+use crate::service::attribute::{AttributeSpecifier, AttributeVerifier};
+use crate::service::port_factory::signal;
+
+use super::publish_subscribe::{
+    self, PublishSubscribeCreateError, PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+};
+
+/// Builder to create new signal based [`Service`](crate::service::Service)s. A signal
+/// [`Service`](crate::service::Service) notifies that something changed without transmitting
+/// any payload. It is a
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// [`Service`](crate::service::Service) with the zero-sized payload `()`, so that every
+/// notification consists only of the sample header.
+#[derive(Debug)]
+pub struct Builder<ServiceType: service::Service> {
+    builder: publish_subscribe::Builder<(), (), ServiceType>,
+}
+
+impl<ServiceType: service::Service> Builder<ServiceType> {
+    pub(crate) fn new(builder: publish_subscribe::Builder<(), (), ServiceType>) -> Self {
+        Self { builder }
+    }
+
+    /// If the [`Service`](crate::service::Service) is created it defines how many signals a
+    /// [`SignalSubscriber`](crate::port::signal::SignalSubscriber) can store until they are
+    /// received. Further signals are coalesced with the stored ones. If an existing
+    /// [`Service`](crate::service::Service) is opened it defines the minimum required.
+    pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
+        self.builder = self.builder.subscriber_max_buffer_size(value);
+        self
+    }
+
+    /// If the [`Service`](crate::service::Service) is created it defines how many
+    /// [`SignalSubscriber`](crate::port::signal::SignalSubscriber)s shall be supported at most.
+    /// If an existing [`Service`](crate::service::Service) is opened it defines how many
+    /// [`SignalSubscriber`](crate::port::signal::SignalSubscriber)s must be at least supported.
+    pub fn max_subscribers(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_subscribers(value);
+        self
+    }
+
+    /// If the [`Service`](crate::service::Service) is created it defines how many
+    /// [`SignalPublisher`](crate::port::signal::SignalPublisher)s shall be supported at most.
+    /// If an existing [`Service`](crate::service::Service) is opened it defines how many
+    /// [`SignalPublisher`](crate::port::signal::SignalPublisher)s must be at least supported.
+    pub fn max_publishers(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_publishers(value);
+        self
+    }
+
+    /// If the [`Service`](crate::service::Service) is created it defines how many
+    /// [`Node`](crate::node::Node)s shall be able to open it in parallel. If an existing
+    /// [`Service`](crate::service::Service) is opened it defines how many
+    /// [`Node`](crate::node::Node)s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_nodes(value);
+        self
+    }
+
+    /// If the [`Service`](crate::service::Service) exists, it will be opened otherwise a new
+    /// [`Service`](crate::service::Service) will be created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<signal::PortFactory<ServiceType>, PublishSubscribeOpenOrCreateError> {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`](crate::service::Service) exists, it will be opened otherwise a new
+    /// [`Service`](crate::service::Service) will be created. It defines a set of attributes.
+    pub fn open_or_create_with_attributes(
+        self,
+        verifier: &AttributeVerifier,
+    ) -> Result<signal::PortFactory<ServiceType>, PublishSubscribeOpenOrCreateError> {
+        Ok(signal::PortFactory::new(
+            self.builder.open_or_create_with_attributes(verifier)?,
+        ))
+    }
+
+    /// Opens an existing [`Service`](crate::service::Service).
+    pub fn open(self) -> Result<signal::PortFactory<ServiceType>, PublishSubscribeOpenError> {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`](crate::service::Service) with attribute requirements. If
+    /// the defined attribute requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        self,
+        verifier: &AttributeVerifier,
+    ) -> Result<signal::PortFactory<ServiceType>, PublishSubscribeOpenError> {
+        Ok(signal::PortFactory::new(
+            self.builder.open_with_attributes(verifier)?,
+        ))
+    }
+
+    /// Creates a new [`Service`](crate::service::Service).
+    pub fn create(self) -> Result<signal::PortFactory<ServiceType>, PublishSubscribeCreateError> {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`](crate::service::Service) with a set of attributes.
+    pub fn create_with_attributes(
+        self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<signal::PortFactory<ServiceType>, PublishSubscribeCreateError> {
+        Ok(signal::PortFactory::new(
+            self.builder.create_with_attributes(attributes)?,
+        ))
+    }
+}
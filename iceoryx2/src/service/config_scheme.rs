@@ -10,7 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::{config, identifiers::UniqueNodeId};
+use crate::{config, identifiers::UniqueNodeId, node::NodeHeartbeat};
 use core::fmt::Debug;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
@@ -41,6 +41,13 @@ pub(crate) fn static_config_storage_config<Service: crate::service::Service>(
         .path_hint(&path_hint)
 }
 
+pub(crate) fn creation_epoch_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
+    static_config_storage_config::<Service>(global_config)
+        .suffix(&global_config.global.service.creation_epoch_suffix)
+}
+
 pub(crate) fn connection_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Connection as NamedConceptMgmt>::Configuration {
@@ -107,6 +114,15 @@ pub(crate) fn node_details_config<Service: crate::service::Service>(
         .path_hint(&node_details_path(global_config, node_id))
 }
 
+pub(crate) fn node_heartbeat_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::BlackboardMgmt<NodeHeartbeat> as NamedConceptMgmt>::Configuration {
+    <<Service::BlackboardMgmt<NodeHeartbeat> as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.node.heartbeat_suffix)
+        .path_hint(&global_config.global.node_dir())
+}
+
 pub(crate) fn service_tag_config<Service: crate::service::Service>(
     global_config: &config::Config,
     node_id: &UniqueNodeId,
@@ -104,6 +104,7 @@ impl MessagingPattern {
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
     nodes: Container<UniqueNodeId>,
+    is_persistent: bool,
 }
 
 impl Display for DynamicConfig {
@@ -120,10 +121,12 @@ impl DynamicConfig {
     pub(crate) fn new_uninit(
         messaging_pattern: MessagingPattern,
         max_number_of_nodes: usize,
+        is_persistent: bool,
     ) -> Self {
         Self {
             messaging_pattern,
             nodes: unsafe { Container::new_uninit(max_number_of_nodes) },
+            is_persistent,
         }
     }
 
@@ -214,15 +217,30 @@ impl DynamicConfig {
     }
 
     pub(crate) fn deregister_node_id(&self, handle: ContainerHandle) -> DeregisterNodeState {
-        if unsafe { self.nodes.remove(handle, ReleaseMode::LockIfLastIndex) }
-            == ReleaseState::Locked
-        {
+        // a persistent service is never marked for destruction so that it can be reopened
+        // after the last node detached
+        let release_mode = if self.is_persistent {
+            ReleaseMode::Default
+        } else {
+            ReleaseMode::LockIfLastIndex
+        };
+
+        if unsafe { self.nodes.remove(handle, release_mode) } == ReleaseState::Locked {
             DeregisterNodeState::NoMoreOwners
         } else {
             DeregisterNodeState::HasOwners
         }
     }
 
+    pub(crate) fn number_of_publishers_and_subscribers(&self) -> (usize, usize) {
+        match &self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(v) => {
+                (v.number_of_publishers(), v.number_of_subscribers())
+            }
+            _ => (0, 0),
+        }
+    }
+
     pub(crate) fn request_response(&self) -> &request_response::DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::RequestResponse(v) => v,
@@ -23,9 +23,16 @@
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//!
+//! pubsub.dynamic_config().list_subscribers(|subscriber| {
+//!     println!("overflowed samples: {}", subscriber.overflowed_samples());
+//!     CallbackProgression::Continue
+//! });
 //! # Ok(())
 //! # }
 //! ```
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
+use iceoryx2_bb_container::vector::{Vector, relocatable_vec::RelocatableVec};
 use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
@@ -33,7 +40,9 @@ use iceoryx2_log::fatal_panic;
 
 use crate::{
     identifiers::{UniqueNodeId, UniquePortId, UniquePublisherId, UniqueSubscriberId},
-    port::details::data_segment::DataSegmentType,
+    port::{
+        details::data_segment::DataSegmentType, unable_to_deliver_strategy::UnableToDeliverStrategy,
+    },
 };
 
 use super::PortCleanupAction;
@@ -67,6 +76,17 @@ pub struct PublisherDetails {
     /// [`DataSegmentType::Dynamic`] it defines how many segment the
     /// [`Publisher`](crate::port::publisher::Publisher) can have at most.
     pub max_number_of_segments: u8,
+    pub(crate) unable_to_deliver_strategy: u8,
+}
+
+impl PublisherDetails {
+    /// Returns the [`UnableToDeliverStrategy`] the
+    /// [`Publisher`](crate::port::publisher::Publisher) was created with. A later change via
+    /// [`Publisher::set_unable_to_deliver_strategy()`](crate::port::publisher::Publisher::set_unable_to_deliver_strategy())
+    /// is not reflected.
+    pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
+        UnableToDeliverStrategy::from_raw(self.unable_to_deliver_strategy)
+    }
 }
 
 /// Contains the communication settings of the connected
@@ -81,6 +101,30 @@ pub struct SubscriberDetails {
     pub node_id: UniqueNodeId,
     /// The size of the receive buffer that stores [`Sample`](crate::sample::Sample).
     pub buffer_size: usize,
+    pub(crate) overflowed_samples: u64,
+    pub(crate) connect_only_to: Option<UniquePublisherId>,
+}
+
+impl SubscriberDetails {
+    /// Returns how many [`Sample`](crate::sample::Sample)s were evicted from the receive
+    /// buffer of the [`Subscriber`](crate::port::subscriber::Subscriber) before they were
+    /// consumed, since safe overflow is enabled and the buffer was full.
+    pub fn overflowed_samples(&self) -> u64 {
+        self.overflowed_samples
+    }
+
+    /// Returns the [`UniquePublisherId`] of the only
+    /// [`Publisher`](crate::port::publisher::Publisher) the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) connects to. If it connects to all
+    /// [`Publisher`](crate::port::publisher::Publisher)s, [`None`] is returned.
+    pub fn connect_only_to(&self) -> Option<UniquePublisherId> {
+        self.connect_only_to
+    }
+
+    pub(crate) fn accepts_publisher(&self, publisher_id: &UniquePublisherId) -> bool {
+        self.connect_only_to
+            .is_none_or(|connect_only_to| connect_only_to == *publisher_id)
+    }
 }
 
 /// The dynamic configuration of an
@@ -91,6 +135,7 @@ pub struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    overflowed_samples: RelocatableVec<AtomicU64>,
 }
 
 impl DynamicConfig {
@@ -98,6 +143,7 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            overflowed_samples: unsafe { RelocatableVec::new_uninit(config.number_of_subscribers) },
         }
     }
 
@@ -109,12 +155,20 @@ impl DynamicConfig {
             fatal_panic!(from self,
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+            fatal_panic!(from self,
+            when self.overflowed_samples.init(allocator),
+            "This should never happen! Unable to initialize subscriber overflow counters.");
+            let capacity = self.overflowed_samples.capacity();
+            fatal_panic!(from self,
+            when self.overflowed_samples.resize_with(capacity, || AtomicU64::new(0)),
+            "This should never happen! Unable to initialize subscriber overflow counters.");
         }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<AtomicU64>::const_memory_size(config.number_of_subscribers)
     }
 
     pub(crate) unsafe fn remove_dead_node_id<
@@ -173,7 +227,12 @@ impl DynamicConfig {
     ) {
         let state = unsafe { self.subscribers.get_state() };
 
-        state.for_each(|_, details| callback(details));
+        state.for_each(|handle, details| {
+            let mut details = *details;
+            details.overflowed_samples =
+                self.overflowed_samples[handle.index() as usize].load(Ordering::Relaxed);
+            callback(&details)
+        });
     }
 
     /// Iterates over all [`Publisher`](crate::port::publisher::Publisher)s and calls the
@@ -190,7 +249,15 @@ impl DynamicConfig {
     }
 
     pub(crate) fn add_subscriber_id(&self, details: SubscriberDetails) -> Option<ContainerHandle> {
-        unsafe { self.subscribers.add(details).ok() }
+        let handle = unsafe { self.subscribers.add(details).ok()? };
+        self.overflowed_samples[handle.index() as usize].store(0, Ordering::Relaxed);
+        Some(handle)
+    }
+
+    pub(crate) fn increment_overflowed_samples(&self, subscriber_index: usize) {
+        if let Some(counter) = self.overflowed_samples.get(subscriber_index) {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
     }
 
     pub(crate) fn release_subscriber_handle(&self, handle: ContainerHandle) {
@@ -31,6 +31,8 @@
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 
@@ -44,6 +46,23 @@ pub struct Header {
     node_id: UniqueNodeId,
     publisher_port_id: UniquePublisherId,
     number_of_elements: u64,
+    sequence_number: u64,
+    // monotonic clock time in nanoseconds, zero when the sample does not expire
+    expiry_timestamp: u64,
+    endianness: u8,
+    has_extended_user_header: bool,
+    is_pinned: bool,
+}
+
+const LITTLE_ENDIAN_TAG: u8 = 1;
+const BIG_ENDIAN_TAG: u8 = 2;
+
+const fn native_endianness_tag() -> u8 {
+    if cfg!(target_endian = "big") {
+        BIG_ENDIAN_TAG
+    } else {
+        LITTLE_ENDIAN_TAG
+    }
 }
 
 impl Header {
@@ -56,9 +75,34 @@ impl Header {
             node_id,
             publisher_port_id,
             number_of_elements,
+            sequence_number: 0,
+            expiry_timestamp: 0,
+            endianness: native_endianness_tag(),
+            has_extended_user_header: false,
+            is_pinned: false,
         }
     }
 
+    pub(crate) fn set_sequence_number(&mut self, value: u64) {
+        self.sequence_number = value;
+    }
+
+    pub(crate) fn set_expiry(&mut self, value: Duration) {
+        self.expiry_timestamp = (value.as_nanos() as u64).max(1);
+    }
+
+    pub(crate) fn set_number_of_elements(&mut self, value: u64) {
+        self.number_of_elements = value;
+    }
+
+    pub(crate) fn set_has_extended_user_header(&mut self, value: bool) {
+        self.has_extended_user_header = value;
+    }
+
+    pub(crate) fn set_is_pinned(&mut self, value: bool) {
+        self.is_pinned = value;
+    }
+
     /// Returns the [`UniqueNodeId`] of the source node that published the
     /// [`Sample`](crate::sample::Sample).
     pub fn node_id(&self) -> UniqueNodeId {
@@ -83,4 +127,65 @@ impl Header {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the sequence number the [`Publisher`](crate::port::publisher::Publisher) stamped
+    /// on the [`Sample`](crate::sample::Sample) when it was sent. It is monotonically
+    /// increasing for every sent [`Sample`](crate::sample::Sample) of a
+    /// [`Publisher`](crate::port::publisher::Publisher) and starts at zero.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the time of the monotonic clock after which the
+    /// [`Sample`](crate::sample::Sample) is expired when it was sent with
+    /// [`SampleMut::send_with_ttl()`](crate::sample_mut::SampleMut::send_with_ttl()),
+    /// otherwise [`None`].
+    pub fn expiry(&self) -> Option<Duration> {
+        match self.expiry_timestamp {
+            0 => None,
+            v => Some(Duration::from_nanos(v)),
+        }
+    }
+
+    /// Returns `true` when the [`Sample`](crate::sample::Sample) was published by a process
+    /// with the same byte order as the current process, otherwise `false`. Payloads from a
+    /// process with a foreign byte order must be converted before they can be interpreted.
+    pub fn is_native_endian(&self) -> bool {
+        self.endianness == native_endianness_tag()
+    }
+
+    /// Returns `true` when the [`Sample`](crate::sample::Sample) carries the extended user
+    /// header defined by the [`crate::service::Service`], otherwise `false`.
+    pub fn has_extended_user_header(&self) -> bool {
+        self.has_extended_user_header
+    }
+
+    /// Returns `true` when the [`Sample`](crate::sample::Sample) was sent with
+    /// [`SampleMut::send_pinned()`](crate::sample_mut::SampleMut::send_pinned()), otherwise
+    /// `false`.
+    pub fn is_pinned(&self) -> bool {
+        self.is_pinned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_is_tagged_with_native_endianness() {
+        let sut = Header::new(UniqueNodeId::new(), UniquePublisherId::new(), 1);
+        assert!(sut.is_native_endian());
+    }
+
+    #[test]
+    fn header_with_foreign_endianness_tag_is_not_native_endian() {
+        let mut sut = Header::new(UniqueNodeId::new(), UniquePublisherId::new(), 1);
+        sut.endianness = if native_endianness_tag() == LITTLE_ENDIAN_TAG {
+            BIG_ENDIAN_TAG
+        } else {
+            LITTLE_ENDIAN_TAG
+        };
+        assert!(!sut.is_native_endian());
+    }
 }
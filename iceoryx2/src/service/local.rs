@@ -10,6 +10,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+//! The `local` service variant is restricted to a single process. All of its concepts are
+//! process-local, like heap-backed storages and queues, therefore it does not create any
+//! shared memory or other file system objects.
+//!
 //! # Example
 //!
 //! ```
@@ -269,6 +269,7 @@ use crate::identifiers::UniqueNodeId;
 use crate::node::{NodeListFailure, NodeState, SharedNode};
 use crate::service::config_scheme::dynamic_config_storage_config;
 use crate::service::dynamic_config::DynamicConfig;
+use crate::service::static_config::message_type_details::{TypeCompatibility, TypeDetail};
 use crate::service::static_config::*;
 use config_scheme::service_tag_config;
 use iceoryx2_bb_container::semantic_string::SemanticString;
@@ -391,6 +392,7 @@ pub struct ServiceState<S: Service, R: ServiceResource> {
     pub(crate) additional_resource: R,
     pub(crate) static_config: StaticConfig,
     pub(crate) shared_node: Arc<SharedNode<S>>,
+    pub(crate) is_creator: bool,
     // must be destructed last, otherwise other processes might create a new service with the same
     // name and their resources are then removed by another process while they are creating them
     // which would end up in a completely corrupted service
@@ -404,15 +406,21 @@ impl<S: Service, R: ServiceResource> ServiceState<S, R> {
         dynamic_storage: S::DynamicStorage,
         static_storage: S::StaticStorage,
         additional_resource: R,
+        is_creator: bool,
     ) -> Self {
+        shared_node
+            .registered_services()
+            .set_static_config(&static_config);
+
         let new_self = Self {
             static_config,
             shared_node,
             dynamic_storage,
             static_storage,
             additional_resource,
+            is_creator,
         };
-        trace!(from "Service::open()", "open service: {} ({:?})",
+        trace!(from new_self.shared_node.log_origin("Service::open()"), "open service: {} ({:?})",
             new_self.static_config.name(), new_self.static_config.service_hash());
         new_self
     }
@@ -420,7 +428,7 @@ impl<S: Service, R: ServiceResource> ServiceState<S, R> {
 
 impl<S: Service, R: ServiceResource> Drop for ServiceState<S, R> {
     fn drop(&mut self) {
-        let origin = "ServiceState::drop()";
+        let origin = self.shared_node.log_origin("ServiceState::drop()");
         let hash = self.static_config.service_hash();
         self.shared_node.registered_services().remove(hash, |handle| {
             if let Err(e) = remove_service_tag::<S>(self.shared_node.id(), hash, self.shared_node.config())
@@ -963,6 +971,56 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> + Clone {
 
         Ok(())
     }
+
+    /// Returns a snapshot of all services created under a given [`config::Config`] together
+    /// with the number of publishers and subscribers that are currently connected to them.
+    /// Services without a dynamic config and services that are not of type
+    /// [`MessagingPattern::PublishSubscribe`] report zero publishers and subscribers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// ipc::Service::list_with_dynamic(
+    ///     Config::global_config(),
+    ///     |static_config, number_of_publishers, number_of_subscribers| {
+    ///         println!(
+    ///             "{}: {} publishers, {} subscribers",
+    ///             static_config.name(),
+    ///             number_of_publishers,
+    ///             number_of_subscribers
+    ///         );
+    ///         CallbackProgression::Continue
+    ///     },
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_with_dynamic<F: FnMut(StaticConfig, usize, usize) -> CallbackProgression>(
+        config: &config::Config,
+        mut callback: F,
+    ) -> Result<(), ServiceListError> {
+        Self::list(config, |service_details| {
+            let (number_of_publishers, number_of_subscribers) = match open_dynamic_config::<Self>(
+                config,
+                service_details.static_details.service_hash(),
+            ) {
+                Ok(Some(dynamic_config)) => {
+                    dynamic_config.get().number_of_publishers_and_subscribers()
+                }
+                Ok(None) | Err(_) => (0, 0),
+            };
+
+            callback(
+                service_details.static_details,
+                number_of_publishers,
+                number_of_subscribers,
+            )
+        })
+    }
 }
 
 pub(crate) unsafe fn remove_static_service_config<S: Service>(
@@ -983,6 +1041,33 @@ pub(crate) unsafe fn remove_static_service_config<S: Service>(
     }
 }
 
+/// Returns `true` when a port that expects the `requested` [`TypeDetail`] can connect to a
+/// [`Service`] that offers the `offered` [`TypeDetail`] under the provided
+/// [`TypeCompatibility`] policy. No [`Service`] or port is accessed, therefore it can be used
+/// to match up ports before a connection is attempted.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::service::are_types_compatible;
+/// use iceoryx2::service::static_config::message_type_details::{
+///     TypeCompatibility, TypeDetail, TypeVariant,
+/// };
+///
+/// let offered = TypeDetail::new::<u64>(TypeVariant::FixedSize);
+/// let requested = TypeDetail::new::<i64>(TypeVariant::FixedSize);
+///
+/// assert!(!are_types_compatible(&offered, &requested, TypeCompatibility::MatchingTypeName));
+/// assert!(are_types_compatible(&offered, &requested, TypeCompatibility::MatchingLayout));
+/// ```
+pub fn are_types_compatible(
+    offered: &TypeDetail,
+    requested: &TypeDetail,
+    policy: TypeCompatibility,
+) -> bool {
+    requested.is_compatible_to(offered, policy)
+}
+
 #[doc(hidden)]
 pub fn __internal_details<S: Service>(
     config: &config::Config,
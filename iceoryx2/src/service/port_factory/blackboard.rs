@@ -89,6 +89,10 @@ impl<
         self.service.static_config.unique_service_id()
     }
 
+    fn creation_epoch(&self) -> u64 {
+        self.service.static_config.creation_epoch()
+    }
+
     fn service_hash(&self) -> &ServiceHash {
         self.service.static_config.service_hash()
     }
@@ -97,6 +101,14 @@ impl<
         self.service.static_config.attributes()
     }
 
+    fn is_persistent(&self) -> bool {
+        self.service.static_config.is_persistent()
+    }
+
+    fn is_creator(&self) -> bool {
+        self.service.is_creator
+    }
+
     fn static_config(&self) -> &static_config::blackboard::StaticConfig {
         self.service.static_config.blackboard()
     }
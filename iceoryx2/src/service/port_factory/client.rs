@@ -272,7 +272,9 @@ impl<
         Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
         ClientCreateError,
     > {
+        let factory = self.factory;
         let origin = format!("{self:?}");
+        let origin = factory.service.shared_node.log_origin(&origin);
         Ok(fail!(from origin,
               when Client::new(self),
               "Failed to create new Client port."))
@@ -76,6 +76,10 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
         self.service.static_config.unique_service_id()
     }
 
+    fn creation_epoch(&self) -> u64 {
+        self.service.static_config.creation_epoch()
+    }
+
     fn service_hash(&self) -> &ServiceHash {
         self.service.static_config.service_hash()
     }
@@ -84,6 +88,14 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
         self.service.static_config.attributes()
     }
 
+    fn is_persistent(&self) -> bool {
+        self.service.static_config.is_persistent()
+    }
+
+    fn is_creator(&self) -> bool {
+        self.service.is_creator
+    }
+
     fn static_config(&self) -> &static_config::event::StaticConfig {
         self.service.static_config.event()
     }
@@ -48,7 +48,7 @@ impl<Service: service::Service> PortFactoryListener<'_, Service> {
     /// Creates the [`Listener`] port or returns a [`ListenerCreateError`] on failure.
     pub fn create(self) -> Result<Listener<Service>, ListenerCreateError> {
         Ok(
-            fail!(from self, when Listener::new(self.factory.service.clone()),
+            fail!(from self.factory.service.shared_node.log_origin(&self), when Listener::new(self.factory.service.clone()),
                     "Failed to create new Listener port."),
         )
     }
@@ -55,6 +55,14 @@ pub mod publish_subscribe;
 /// Factory to create a [`Publisher`](crate::port::publisher::Publisher)
 pub mod publisher;
 
+/// Factory to create the endpoints of serialized communication and to acquire static and
+/// dynamic service information
+pub mod serialized;
+
+/// Factory to create the endpoints of payload free signal based communication and to
+/// acquire static and dynamic service information
+pub mod signal;
+
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
 
@@ -78,12 +86,26 @@ pub trait PortFactory {
     /// Returns the unique [`UniqueServiceId`] of the [`crate::service::Service`]
     fn unique_service_id(&self) -> UniqueServiceId;
 
+    /// Returns the creation epoch of the [`crate::service::Service`], see
+    /// [`StaticConfig::creation_epoch()`](crate::service::static_config::StaticConfig::creation_epoch()).
+    fn creation_epoch(&self) -> u64;
+
     /// Returns the [`ServiceHash`] of the [`crate::service::Service`]
     fn service_hash(&self) -> &ServiceHash;
 
     /// Returns the attributes defined in the [`crate::service::Service`]
     fn attributes(&self) -> &AttributeSet;
 
+    /// Returns true if the [`crate::service::Service`] persists when the last
+    /// [`Node`](crate::node::Node) detaches from it, otherwise it is removed together with
+    /// the last [`Node`](crate::node::Node).
+    fn is_persistent(&self) -> bool;
+
+    /// Returns true if this handle created the [`crate::service::Service`] and false if it
+    /// opened an already existing one. When the [`crate::service::Service`] was acquired with
+    /// `open_or_create()`, it reflects which of both actually happened.
+    fn is_creator(&self) -> bool;
+
     /// Returns the StaticConfig of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     fn static_config(&self) -> &Self::StaticConfig;
@@ -46,10 +46,13 @@ use core::{fmt::Debug, marker::PhantomData};
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::static_storage::StaticStorage;
 
 use crate::identifiers::UniqueServiceId;
 use crate::node::NodeListFailure;
+use crate::port::details::data_segment::DataSegment;
 use crate::service::attribute::AttributeSet;
+use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::service_hash::ServiceHash;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, NoResource, ServiceState, dynamic_config, static_config};
@@ -58,6 +61,27 @@ use alloc::sync::Arc;
 use super::nodes;
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 
+/// Describes how many bytes a
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based service occupies in shared memory. Acquired via [`PortFactory::shm_footprint()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShmFootprint {
+    /// The size of the serialized static configuration of the service.
+    pub static_config_bytes: usize,
+    /// The size of the dynamic configuration of the service, containing all ports and nodes.
+    pub dynamic_config_bytes: usize,
+    /// The sum of the data segment sizes of all
+    /// [`Publisher`](crate::port::publisher::Publisher)s of the service.
+    pub total_segment_bytes: usize,
+}
+
+impl ShmFootprint {
+    /// Returns the total number of bytes the service occupies in shared memory.
+    pub fn total_bytes(&self) -> usize {
+        self.static_config_bytes + self.dynamic_config_bytes + self.total_segment_bytes
+    }
+}
+
 /// The factory for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
 /// It can acquire dynamic and static service informations and create
@@ -107,6 +131,10 @@ impl<
         self.service.static_config.unique_service_id()
     }
 
+    fn creation_epoch(&self) -> u64 {
+        self.service.static_config.creation_epoch()
+    }
+
     fn service_hash(&self) -> &ServiceHash {
         self.service.static_config.service_hash()
     }
@@ -115,6 +143,14 @@ impl<
         self.service.static_config.attributes()
     }
 
+    fn is_persistent(&self) -> bool {
+        self.service.static_config.is_persistent()
+    }
+
+    fn is_creator(&self) -> bool {
+        self.service.is_creator
+    }
+
     fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
         self.service.static_config.publish_subscribe()
     }
@@ -149,6 +185,58 @@ impl<
         }
     }
 
+    /// Returns the [`ShmFootprint`] of the service. The data segment sizes are derived from
+    /// the current settings of every connected
+    /// [`Publisher`](crate::port::publisher::Publisher). A dynamic data segment contributes
+    /// with the size of its current segment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let footprint = pubsub.shm_footprint();
+    /// println!("the service occupies {} bytes", footprint.total_bytes());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shm_footprint(&self) -> ShmFootprint {
+        let static_config = self.service.static_config.publish_subscribe();
+        let dynamic_config_settings = DynamicConfigSettings {
+            number_of_subscribers: static_config.max_subscribers,
+            number_of_publishers: static_config.max_publishers,
+        };
+
+        let mut total_segment_bytes = 0;
+        self.service
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .list_publishers(|details| {
+                let chunk_layout = static_config.sample_layout(details.max_slice_len);
+                total_segment_bytes +=
+                    DataSegment::<Service>::segment_size(chunk_layout, details.number_of_samples);
+                CallbackProgression::Continue
+            });
+
+        ShmFootprint {
+            static_config_bytes: self.service.static_storage.len() as usize,
+            dynamic_config_bytes: core::mem::size_of::<dynamic_config::DynamicConfig>()
+                + dynamic_config::DynamicConfig::memory_size(static_config.max_nodes)
+                + dynamic_config::publish_subscribe::DynamicConfig::memory_size(
+                    &dynamic_config_settings,
+                ),
+            total_segment_bytes,
+        }
+    }
+
     /// Returns a [`PortFactorySubscriber`] to create a new
     /// [`crate::port::subscriber::Subscriber`] port.
     ///
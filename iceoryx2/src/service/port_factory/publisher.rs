@@ -56,7 +56,7 @@
 
 use crate::{
     port::{
-        DegradationAction, DegradationCallback,
+        DegradationAction, DegradationCallback, SampleReclaimedCallback,
         publisher::{Publisher, PublisherCreateError},
         unable_to_deliver_strategy::UnableToDeliverStrategy,
     },
@@ -64,6 +64,8 @@ use crate::{
 };
 use alloc::format;
 use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::time::Duration;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::shm_allocator::AllocationStrategy;
 use iceoryx2_log::fail;
@@ -99,6 +101,29 @@ pub(crate) struct LocalPublisherConfig {
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) run_drop_on_discard: bool,
+    pub(crate) max_send_rate: u64,
+    pub(crate) validate_initialization: bool,
+    pub(crate) duplicate_comparator: Option<fn(*const u8, *const u8) -> bool>,
+    pub(crate) adaptive_drop_threshold: Option<u8>,
+    pub(crate) slow_subscriber_threshold: Option<Duration>,
+    pub(crate) numa_node: Option<u32>,
+}
+
+/// Contains the full builder configuration of a [`Publisher`] and is acquired with
+/// [`Publisher::builder_config()`]. It can be applied with
+/// [`PortFactoryPublisher::from_config()`] to create further [`Publisher`]s with the same
+/// settings on the same or on another [`Service`](crate::service::Service) with the same
+/// payload and user header types. Callbacks are not part of the configuration.
+#[derive(Debug)]
+pub struct PublisherConfigSnapshot<Payload: Debug + ?Sized, UserHeader: Debug> {
+    pub(crate) config: LocalPublisherConfig,
+    pub(crate) _payload: PhantomData<Payload>,
+    pub(crate) _user_header: PhantomData<UserHeader>,
+}
+
+fn is_payload_equal<Payload: PartialEq>(lhs: *const u8, rhs: *const u8) -> bool {
+    unsafe { *lhs.cast::<Payload>() == *rhs.cast::<Payload>() }
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -113,6 +138,7 @@ pub struct PortFactoryPublisher<
 > {
     pub(crate) config: LocalPublisherConfig,
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
+    pub(crate) sample_reclaimed_callback: Option<SampleReclaimedCallback<'static>>,
     pub(crate) preallocate_number_of_samples_override: PreallocatedSamplesOverride<'static>,
     pub(crate) factory: &'factory PortFactory<Service, Payload, UserHeader>,
 }
@@ -140,6 +166,7 @@ impl<
             config: self.config,
             factory: self.factory,
             degradation_callback: None,
+            sample_reclaimed_callback: None,
             preallocate_number_of_samples_override: PreallocatedSamplesOverride::new(|v| v),
         }
     }
@@ -156,6 +183,13 @@ impl<
         Self {
             config: LocalPublisherConfig {
                 allocation_strategy: AllocationStrategy::Static,
+                run_drop_on_discard: false,
+                max_send_rate: 0,
+                validate_initialization: false,
+                duplicate_comparator: None,
+                adaptive_drop_threshold: None,
+                slow_subscriber_threshold: None,
+                numa_node: None,
                 initial_max_slice_len: 1,
                 max_loaned_samples: factory
                     .service
@@ -173,6 +207,7 @@ impl<
                     .unable_to_deliver_strategy,
             },
             degradation_callback: None,
+            sample_reclaimed_callback: None,
             preallocate_number_of_samples_override: PreallocatedSamplesOverride::new(|v| v),
             factory,
         }
@@ -207,6 +242,88 @@ impl<
         self
     }
 
+    /// Defines if the payload of a [`SampleMut`](crate::sample_mut::SampleMut) that goes out
+    /// of scope without being sent is dropped. By default, the memory is released without
+    /// running [`Drop`] on the payload.
+    pub fn run_drop_on_discard(mut self, value: bool) -> Self {
+        self.config.run_drop_on_discard = value;
+        self
+    }
+
+    /// Limits how many samples the [`Publisher`] can send per second. A burst of up to
+    /// `messages_per_second` samples is possible, afterwards the rate is enforced with a token
+    /// bucket that is refilled based on a monotonic clock. A send that exceeds the rate blocks
+    /// until it is allowed when the [`UnableToDeliverStrategy::Block`] is used, otherwise it
+    /// fails with [`SendError::RateLimited`](crate::port::SendError::RateLimited).
+    /// A value of `0` disables the limit, which is the default.
+    pub fn max_send_rate(mut self, messages_per_second: u64) -> Self {
+        self.config.max_send_rate = messages_per_second;
+        self
+    }
+
+    /// Enables adaptive dropping: [`Publisher::send_copy()`] skips the delivery and returns
+    /// `Ok(0)` when the receive buffer of the slowest
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is filled more than
+    /// `threshold_percent` percent. In contrast to safe overflow, no sample is overwritten, the
+    /// new value is shed before it adds further pressure. A threshold of `100` or more never
+    /// drops. By default, adaptive dropping is disabled.
+    pub fn adaptive_drop(mut self, threshold_percent: u8) -> Self {
+        self.config.adaptive_drop_threshold = Some(threshold_percent);
+        self
+    }
+
+    /// Enables the detection of slow [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    /// Whenever the [`Publisher`] updates its connections, for instance on every send, it
+    /// checks the receive buffer of every connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber). When a buffer was not empty at
+    /// every check for longer than `threshold`, a warning with the id of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is logged, at most once per
+    /// `threshold`. It is a diagnostic aid and does not affect the delivery. By default, the
+    /// detection is disabled.
+    pub fn slow_subscriber_threshold(mut self, threshold: Duration) -> Self {
+        self.config.slow_subscriber_threshold = Some(threshold);
+        self
+    }
+
+    /// Binds the data segment of the [`Publisher`] to the NUMA node with the provided `id`, so
+    /// that the payload memory is allocated from the memory attached to this node. The memory
+    /// is bound right after the data segment is created, before the first sample is loaned.
+    /// Pages that were already touched during the creation of the data segment are moved to
+    /// the node.
+    ///
+    /// NUMA is only supported on Linux and only for the data segment of
+    /// [`AllocationStrategy::Static`]. The creation fails with
+    /// [`PublisherCreateError::UnableToBindToNumaNode`] when the node does not exist, the
+    /// system does not support NUMA, the process is not allowed to bind memory or when the
+    /// [`Publisher`] uses a dynamic data segment.
+    pub fn numa_node(mut self, id: u32) -> Self {
+        self.config.numa_node = Some(id);
+        self
+    }
+
+    /// Debug aid to detect samples that are sent without writing their whole payload. In
+    /// debug builds, every [`SampleMutUninit`](crate::sample_mut_uninit::SampleMutUninit)
+    /// tracks which of its payload elements were written and
+    /// [`SampleMut::send()`](crate::sample_mut::SampleMut::send()) fails with
+    /// [`SendError::UninitializedPayload`](crate::port::SendError::UninitializedPayload) when
+    /// an element was never written. In release builds it has no effect.
+    ///
+    /// # Important
+    ///
+    /// Only writes via
+    /// [`SampleMutUninit::write_element()`](crate::sample_mut_uninit::SampleMutUninit::write_element())
+    /// are tracked per element. The APIs that initialize the whole payload, like
+    /// [`SampleMutUninit::write_payload()`](crate::sample_mut_uninit::SampleMutUninit::write_payload())
+    /// or
+    /// [`SampleMutUninit::write_from_fn()`](crate::sample_mut_uninit::SampleMutUninit::write_from_fn()),
+    /// mark the whole payload as written. Writes via
+    /// [`SampleMutUninit::payload_mut()`](crate::sample_mut_uninit::SampleMutUninit::payload_mut())
+    /// are not tracked at all.
+    pub fn validate_initialization(mut self, value: bool) -> Self {
+        self.config.validate_initialization = value;
+        self
+    }
+
     /// Sets the [`UnableToDeliverStrategy`].
     pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
         self.config.unable_to_deliver_strategy = value;
@@ -230,14 +347,54 @@ impl<
         self
     }
 
+    /// Sets a callback that is called with the number of samples the [`Publisher`] reclaimed
+    /// from a [`crate::port::subscriber::Subscriber`] that disconnected without consuming or
+    /// releasing them. It is called from the connection update of the [`Publisher`] that
+    /// detects the disconnect, for instance during a send, and not from a background thread.
+    pub fn on_sample_reclaimed<F: Fn(usize) + 'static>(mut self, callback: F) -> Self {
+        self.sample_reclaimed_callback = Some(SampleReclaimedCallback::new(callback));
+        self
+    }
+
+    /// Replaces the whole configuration of the builder with the one of the provided
+    /// [`PublisherConfigSnapshot`], so that the [`Publisher`] is created with the same settings
+    /// as the [`Publisher`] the snapshot was taken from. Callbacks that were defined on the
+    /// builder are kept.
+    pub fn from_config(mut self, snapshot: &PublisherConfigSnapshot<Payload, UserHeader>) -> Self {
+        self.config = snapshot.config;
+        self
+    }
+
     /// Creates a new [`Publisher`] or returns a [`PublisherCreateError`] on failure.
     pub fn create(self) -> Result<Publisher<Service, Payload, UserHeader>, PublisherCreateError> {
+        let factory = self.factory;
         let origin = format!("{self:?}");
+        let origin = factory.service.shared_node.log_origin(&origin);
         Ok(fail!(from origin, when Publisher::new(self),
                 "Failed to create new Publisher port."))
     }
 }
 
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + PartialEq,
+    UserHeader: Debug + ZeroCopySend,
+> PortFactoryPublisher<'_, Service, Payload, UserHeader>
+{
+    /// Defines if [`Publisher::send_copy()`] skips the delivery of a value that is equal to
+    /// the last value that reached at least one [`crate::port::subscriber::Subscriber`] and
+    /// returns `Ok(0)` instead. Useful for services that
+    /// broadcast a state where resending an unchanged value only wastes bandwidth.
+    /// By default, every value is delivered.
+    pub fn coalesce_duplicates(mut self, value: bool) -> Self {
+        self.config.duplicate_comparator = match value {
+            true => Some(is_payload_equal::<Payload>),
+            false => None,
+        };
+        self
+    }
+}
+
 impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend>
     PortFactoryPublisher<'_, Service, [Payload], UserHeader>
 {
@@ -65,7 +65,9 @@ impl<
 
     /// Creates a new [`Reader`] or returns a [`ReaderCreateError`] on failure.
     pub fn create(self) -> Result<Reader<Service, KeyType>, ReaderCreateError> {
+        let factory = self.factory;
         let origin = format!("{self:?}");
+        let origin = factory.service.shared_node.log_origin(&origin);
         Ok(
             fail!(from origin, when Reader::new(self.factory.service.clone()),"Failed to create new Reader port."),
         )
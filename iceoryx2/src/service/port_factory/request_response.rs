@@ -139,6 +139,10 @@ impl<
         self.service.static_config.unique_service_id()
     }
 
+    fn creation_epoch(&self) -> u64 {
+        self.service.static_config.creation_epoch()
+    }
+
     fn service_hash(&self) -> &ServiceHash {
         self.service.static_config.service_hash()
     }
@@ -147,6 +151,14 @@ impl<
         self.service.static_config.attributes()
     }
 
+    fn is_persistent(&self) -> bool {
+        self.service.static_config.is_persistent()
+    }
+
+    fn is_creator(&self) -> bool {
+        self.service.is_creator
+    }
+
     fn static_config(&self) -> &Self::StaticConfig {
         self.service.static_config.request_response()
     }
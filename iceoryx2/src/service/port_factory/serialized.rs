@@ -0,0 +1,178 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .serialized::<String>()
+//!     .open_or_create()?;
+//!
+//! println!("name:                         {:?}", service.name());
+//! println!("service id:                   {:?}", service.service_hash());
+//! println!("max publishers:               {:?}", service.static_config().max_publishers());
+//! println!("max subscribers:              {:?}", service.static_config().max_subscribers());
+//!
+//! let publisher = service.publisher()?;
+//! let subscriber = service.subscriber()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_cal::shm_allocator::AllocationStrategy;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::identifiers::UniqueServiceId;
+use crate::node::NodeListFailure;
+use crate::port::publisher::PublisherCreateError;
+use crate::port::serialized::{SerializedPublisher, SerializedSubscriber};
+use crate::port::subscriber::SubscriberCreateError;
+use crate::service::attribute::AttributeSet;
+use crate::service::service_hash::ServiceHash;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::PortFactory as _;
+use super::publish_subscribe;
+
+/// The factory for serialized communication. It can acquire dynamic and static service
+/// informations and create [`SerializedPublisher`] or [`SerializedSubscriber`] ports.
+#[derive(Debug)]
+pub struct PortFactory<Service: service::Service, T: Debug + Serialize + DeserializeOwned> {
+    factory: publish_subscribe::PortFactory<Service, [u8], ()>,
+    _data: PhantomData<T>,
+}
+
+impl<Service: service::Service, T: Debug + Serialize + DeserializeOwned>
+    crate::service::port_factory::PortFactory for PortFactory<Service, T>
+{
+    type Service = Service;
+    type StaticConfig = static_config::publish_subscribe::StaticConfig;
+    type DynamicConfig = dynamic_config::publish_subscribe::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.factory.name()
+    }
+
+    fn unique_service_id(&self) -> UniqueServiceId {
+        self.factory.unique_service_id()
+    }
+
+    fn creation_epoch(&self) -> u64 {
+        self.factory.creation_epoch()
+    }
+
+    fn service_hash(&self) -> &ServiceHash {
+        self.factory.service_hash()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.factory.attributes()
+    }
+
+    fn is_persistent(&self) -> bool {
+        self.factory.is_persistent()
+    }
+
+    fn is_creator(&self) -> bool {
+        self.factory.is_creator()
+    }
+
+    fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
+        self.factory.static_config()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::publish_subscribe::DynamicConfig {
+        self.factory.dynamic_config()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        self.factory.nodes(callback)
+    }
+}
+
+impl<Service: service::Service, T: Debug + Serialize + DeserializeOwned> PortFactory<Service, T> {
+    pub(crate) fn new(factory: publish_subscribe::PortFactory<Service, [u8], ()>) -> Self {
+        Self {
+            factory,
+            _data: PhantomData,
+        }
+    }
+
+    /// Creates a new [`SerializedPublisher`] port. Its data segment grows with the size of
+    /// the serialized values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .serialized::<String>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher()?;
+    /// publisher.send(&"hello world".to_string())?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn publisher(&self) -> Result<SerializedPublisher<Service, T>, PublisherCreateError> {
+        Ok(SerializedPublisher::new(
+            self.factory
+                .publisher_builder()
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+                .create()?,
+        ))
+    }
+
+    /// Creates a new [`SerializedSubscriber`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .serialized::<String>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = service.subscriber()?;
+    /// if let Some(message) = subscriber.receive()? {
+    ///     println!("received {}", message);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscriber(&self) -> Result<SerializedSubscriber<Service, T>, SubscriberCreateError> {
+        Ok(SerializedSubscriber::new(
+            self.factory.subscriber_builder().create()?,
+        ))
+    }
+}
@@ -239,7 +239,7 @@ impl<
     /// [`ActiveRequest`](crate::active_request::ActiveRequest).
     pub fn max_loaned_responses_per_request(mut self, value: usize) -> Self {
         if value == 0 {
-            warn!(from self,
+            warn!(from self.factory.service.shared_node.log_origin(&self),
                 "A value of 0 is not allowed for max loaned responses per request. Adjusting it to 1.");
         }
         self.config.max_loaned_responses_per_request = value.max(1);
@@ -290,7 +290,9 @@ impl<
         Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
         ServerCreateError,
     > {
+        let factory = self.factory;
         let origin = format!("{self:?}");
+        let origin = factory.service.shared_node.log_origin(&origin);
         Ok(fail!(from origin,
               when Server::new(self),
               "Failed to create new Server port."))
@@ -0,0 +1,162 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let signal = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .signal()
+//!     .open_or_create()?;
+//!
+//! println!("name:                         {:?}", signal.name());
+//! println!("service id:                   {:?}", signal.service_hash());
+//! println!("max publishers:               {:?}", signal.static_config().max_publishers());
+//! println!("max subscribers:              {:?}", signal.static_config().max_subscribers());
+//!
+//! let publisher = signal.publisher()?;
+//! let subscriber = signal.subscriber()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_elementary::CallbackProgression;
+
+use crate::identifiers::UniqueServiceId;
+use crate::node::NodeListFailure;
+use crate::port::publisher::PublisherCreateError;
+use crate::port::signal::{SignalPublisher, SignalSubscriber};
+use crate::port::subscriber::SubscriberCreateError;
+use crate::service::attribute::AttributeSet;
+use crate::service::service_hash::ServiceHash;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::PortFactory as _;
+use super::publish_subscribe;
+
+/// The factory for payload free signal based communication. It can acquire dynamic and
+/// static service informations and create [`SignalPublisher`] or [`SignalSubscriber`] ports.
+#[derive(Debug)]
+pub struct PortFactory<Service: service::Service> {
+    factory: publish_subscribe::PortFactory<Service, (), ()>,
+}
+
+impl<Service: service::Service> crate::service::port_factory::PortFactory for PortFactory<Service> {
+    type Service = Service;
+    type StaticConfig = static_config::publish_subscribe::StaticConfig;
+    type DynamicConfig = dynamic_config::publish_subscribe::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.factory.name()
+    }
+
+    fn unique_service_id(&self) -> UniqueServiceId {
+        self.factory.unique_service_id()
+    }
+
+    fn creation_epoch(&self) -> u64 {
+        self.factory.creation_epoch()
+    }
+
+    fn service_hash(&self) -> &ServiceHash {
+        self.factory.service_hash()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.factory.attributes()
+    }
+
+    fn is_persistent(&self) -> bool {
+        self.factory.is_persistent()
+    }
+
+    fn is_creator(&self) -> bool {
+        self.factory.is_creator()
+    }
+
+    fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
+        self.factory.static_config()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::publish_subscribe::DynamicConfig {
+        self.factory.dynamic_config()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        self.factory.nodes(callback)
+    }
+}
+
+impl<Service: service::Service> PortFactory<Service> {
+    pub(crate) fn new(factory: publish_subscribe::PortFactory<Service, (), ()>) -> Self {
+        Self { factory }
+    }
+
+    /// Creates a new [`SignalPublisher`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let signal = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .signal()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = signal.publisher()?;
+    /// publisher.notify()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn publisher(&self) -> Result<SignalPublisher<Service>, PublisherCreateError> {
+        Ok(SignalPublisher::new(
+            self.factory.publisher_builder().create()?,
+        ))
+    }
+
+    /// Creates a new [`SignalSubscriber`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let signal = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .signal()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = signal.subscriber()?;
+    /// if subscriber.receive_signal()?.is_some() {
+    ///     println!("received {} signals", subscriber.coalesced_signals());
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscriber(&self) -> Result<SignalSubscriber<Service>, SubscriberCreateError> {
+        Ok(SignalSubscriber::new(
+            self.factory.subscriber_builder().create()?,
+        ))
+    }
+}
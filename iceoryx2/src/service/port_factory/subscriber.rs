@@ -32,10 +32,12 @@ use core::fmt::Debug;
 
 use alloc::format;
 
+use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_log::fail;
 
 use crate::{
+    identifiers::UniquePublisherId,
     port::{
         DegradationAction, DegradationCallback,
         subscriber::{Subscriber, SubscriberCreateError},
@@ -49,6 +51,14 @@ use super::publish_subscribe::PortFactory;
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
+    pub(crate) reject_foreign_endian: bool,
+    pub(crate) payload_alignment: Option<Alignment>,
+    pub(crate) connect_only_to: Option<UniquePublisherId>,
+    pub(crate) track_payload_size_stats: bool,
+    pub(crate) drop_expired: bool,
+    pub(crate) retain_window: usize,
+    pub(crate) manual_release: bool,
+    pub(crate) keep_latest_only: bool,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
@@ -89,6 +99,14 @@ impl<
             config: SubscriberConfig {
                 buffer_size: self.config.buffer_size,
                 degradation_callback: None,
+                reject_foreign_endian: self.config.reject_foreign_endian,
+                payload_alignment: self.config.payload_alignment,
+                connect_only_to: self.config.connect_only_to,
+                track_payload_size_stats: self.config.track_payload_size_stats,
+                drop_expired: self.config.drop_expired,
+                retain_window: self.config.retain_window,
+                manual_release: self.config.manual_release,
+                keep_latest_only: self.config.keep_latest_only,
             },
             factory: self.factory,
         }
@@ -99,6 +117,14 @@ impl<
             config: SubscriberConfig {
                 buffer_size: None,
                 degradation_callback: None,
+                reject_foreign_endian: false,
+                payload_alignment: None,
+                connect_only_to: None,
+                track_payload_size_stats: false,
+                drop_expired: false,
+                retain_window: 0,
+                manual_release: false,
+                keep_latest_only: false,
             },
             factory,
         }
@@ -110,6 +136,111 @@ impl<
         self
     }
 
+    /// Defines if the [`Subscriber`] rejects [`Sample`](crate::sample::Sample)s that were
+    /// published by a process with a different byte order. When enabled, receiving such a
+    /// [`Sample`](crate::sample::Sample) fails with
+    /// [`ReceiveError::ForeignEndianness`](crate::port::ReceiveError::ForeignEndianness).
+    pub fn reject_foreign_endian(mut self, value: bool) -> Self {
+        self.config.reject_foreign_endian = value;
+        self
+    }
+
+    /// Defines the [`Alignment`] the payload of every received
+    /// [`Sample`](crate::sample::Sample) must satisfy. When the payload is misaligned,
+    /// receiving fails with
+    /// [`ReceiveError::MisalignedPayload`](crate::port::ReceiveError::MisalignedPayload)
+    /// instead of handing out a misaligned pointer. Useful when the payload type details
+    /// are provided manually, for instance via the language bindings.
+    pub fn assert_payload_alignment(mut self, alignment: Alignment) -> Self {
+        self.config.payload_alignment = Some(alignment);
+        self
+    }
+
+    /// Defines if the [`Subscriber`] records the minimum, maximum and average payload size of
+    /// all received [`Sample`](crate::sample::Sample)s. They can be acquired with
+    /// [`Subscriber::payload_size_stats()`]. Useful to find a suitable
+    /// `initial_max_slice_len` for slice based services. When disabled, no statistics are
+    /// recorded.
+    pub fn track_payload_size_stats(mut self, value: bool) -> Self {
+        self.config.track_payload_size_stats = value;
+        self
+    }
+
+    /// Defines if the [`Subscriber`] discards [`Sample`](crate::sample::Sample)s that were sent
+    /// with [`SampleMut::send_with_ttl()`](crate::sample_mut::SampleMut::send_with_ttl()) and
+    /// whose expiry has passed when they are received. Discarded
+    /// [`Sample`](crate::sample::Sample)s are released right away and counted in
+    /// [`Subscriber::expired_samples()`].
+    pub fn drop_expired(mut self, value: bool) -> Self {
+        self.config.drop_expired = value;
+        self
+    }
+
+    /// Defines that the [`Subscriber`] retains the last `n` received
+    /// [`Sample`](crate::sample::Sample)s in a window that can be accessed with
+    /// [`Subscriber::window()`]. Whenever a new [`Sample`](crate::sample::Sample) is received
+    /// the window slides and the oldest [`Sample`](crate::sample::Sample) is released.
+    /// By default, no window is retained.
+    ///
+    /// # Important
+    ///
+    /// The retained [`Sample`](crate::sample::Sample)s count towards the borrowed samples of
+    /// the [`Subscriber`]. Therefore, the window is limited to one less than the
+    /// services `subscriber_max_borrowed_samples` so that a new
+    /// [`Sample`](crate::sample::Sample) can always be received.
+    pub fn retain_window(mut self, n: usize) -> Self {
+        self.config.retain_window = n;
+        self
+    }
+
+    /// Defines if the slots of received [`Sample`](crate::sample::Sample)s are returned to the
+    /// [`Publisher`](crate::port::publisher::Publisher) when they go out of scope. When
+    /// enabled, dropping a [`Sample`](crate::sample::Sample) does not release its slot. The
+    /// slots are released in bulk with [`Subscriber::release_all()`] or
+    /// [`Subscriber::release_up_to()`] instead. By default, every
+    /// [`Sample`](crate::sample::Sample) releases its slot on drop.
+    ///
+    /// # Important
+    ///
+    /// Dropped but unreleased [`Sample`](crate::sample::Sample)s still count towards the
+    /// borrowed samples of the [`Subscriber`] and occupy the data segment of the
+    /// [`Publisher`](crate::port::publisher::Publisher). When they are not released
+    /// regularly, receiving fails with
+    /// [`ReceiveError::ExceedsMaxBorrows`](crate::port::ReceiveError::ExceedsMaxBorrows) and
+    /// the [`Publisher`](crate::port::publisher::Publisher) runs out of samples to loan.
+    pub fn manual_release(mut self, value: bool) -> Self {
+        self.config.manual_release = value;
+        self
+    }
+
+    /// When enabled, the [`Subscriber`] keeps only the newest
+    /// [`Sample`](crate::sample::Sample) of every
+    /// [`Publisher`](crate::port::publisher::Publisher). Its buffer size is set to `1`
+    /// regardless of [`PortFactorySubscriber::buffer_size()`] and, with safe overflow enabled
+    /// in the [`Service`](crate::service::Service), every new
+    /// [`Sample`](crate::sample::Sample) replaces the queued one, which is released. Useful
+    /// for state or telemetry data where only the latest value matters.
+    ///
+    /// # Important
+    ///
+    /// When the [`Service`](crate::service::Service) has safe overflow disabled, a queued
+    /// [`Sample`](crate::sample::Sample) is not replaced and the
+    /// [`UnableToDeliverStrategy`](crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy) of the
+    /// [`Publisher`](crate::port::publisher::Publisher) applies to the new one.
+    pub fn keep_latest_only(mut self, value: bool) -> Self {
+        self.config.keep_latest_only = value;
+        self
+    }
+
+    /// Binds the [`Subscriber`] to the [`Publisher`](crate::port::publisher::Publisher) with
+    /// the provided [`UniquePublisherId`]. It does not connect to any other
+    /// [`Publisher`](crate::port::publisher::Publisher) of the service and therefore does not
+    /// receive any of their [`Sample`](crate::sample::Sample)s.
+    pub fn connect_only_to(mut self, publisher_id: UniquePublisherId) -> Self {
+        self.config.connect_only_to = Some(publisher_id);
+        self
+    }
+
     /// Sets the [`DegradationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegradationAction`] measures will be taken.
@@ -131,7 +262,9 @@ impl<
     pub fn create(
         self,
     ) -> Result<Subscriber<Service, PayloadType, UserHeader>, SubscriberCreateError> {
+        let factory = self.factory;
         let origin = format!("{self:?}");
+        let origin = factory.service.shared_node.log_origin(&origin);
         Ok(
             fail!(from origin, when Subscriber::new(self.factory.service.clone(), self.factory.service.static_config.publish_subscribe(), self.config),
                 "Failed to create new Subscriber port."),
@@ -65,7 +65,9 @@ impl<
 
     /// Creates a new [`Writer`] or returns a [`WriterCreateError`] on failure.
     pub fn create(self) -> Result<Writer<Service, KeyType>, WriterCreateError> {
+        let factory = self.factory;
         let origin = format!("{self:?}");
+        let origin = factory.service.shared_node.log_origin(&origin);
         Ok(
             fail!(from origin, when Writer::new(self.factory.service.clone()),"Failed to create new Writer port."),
         )
@@ -50,6 +50,21 @@ pub enum TypeVariant {
     Dynamic,
 }
 
+/// Defines how strictly two [`TypeDetail`]s are compared in
+/// [`are_types_compatible()`](crate::service::are_types_compatible).
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TypeCompatibility {
+    /// Type name, [`TypeVariant`], size and alignment must be identical.
+    Exact,
+    /// Type name, [`TypeVariant`] and size must be identical and the requested alignment must
+    /// not exceed the offered alignment. This is the policy that is applied when a
+    /// [`Service`](crate::service::Service) is opened.
+    MatchingTypeName,
+    /// Like [`TypeCompatibility::MatchingTypeName`] but the type name is ignored, so that
+    /// types with the same memory layout but a different name are compatible.
+    MatchingLayout,
+}
+
 /// A fixed-size string type used to store type names.
 pub type TypeName = StaticString<MAX_TYPE_NAME_LENGTH>;
 
@@ -103,6 +118,23 @@ impl TypeDetail {
     pub fn alignment(&self) -> usize {
         self.alignment
     }
+
+    /// Returns `true` when the requested `self` can be used with the `offered` [`TypeDetail`]
+    /// under the provided [`TypeCompatibility`] policy.
+    pub(crate) fn is_compatible_to(&self, offered: &Self, policy: TypeCompatibility) -> bool {
+        match policy {
+            TypeCompatibility::Exact => self == offered,
+            TypeCompatibility::MatchingTypeName => {
+                self.type_name == offered.type_name
+                    && self.is_compatible_to(offered, TypeCompatibility::MatchingLayout)
+            }
+            TypeCompatibility::MatchingLayout => {
+                self.variant == offered.variant
+                    && self.size == offered.size
+                    && self.alignment <= offered.alignment
+            }
+        }
+    }
 }
 
 /// Contains all type information to the header and payload type.
@@ -160,15 +192,23 @@ impl MessageTypeDetails {
     }
 
     pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
+        self.is_compatible_to_with_user_header_policy(rhs, TypeCompatibility::MatchingTypeName)
+    }
+
+    /// Like [`MessageTypeDetails::is_compatible_to()`] but compares the user header under the
+    /// provided [`TypeCompatibility`] policy.
+    pub(crate) fn is_compatible_to_with_user_header_policy(
+        &self,
+        rhs: &Self,
+        user_header_policy: TypeCompatibility,
+    ) -> bool {
         self.header == rhs.header
-            && self.user_header.type_name == rhs.user_header.type_name
-            && self.user_header.variant == rhs.user_header.variant
-            && self.user_header.size == rhs.user_header.size
-            && self.user_header.alignment <= rhs.user_header.alignment
-            && self.payload.type_name == rhs.payload.type_name
-            && self.payload.variant == rhs.payload.variant
-            && self.payload.size == rhs.payload.size
-            && self.payload.alignment <= rhs.payload.alignment
+            && self
+                .user_header
+                .is_compatible_to(&rhs.user_header, user_header_policy)
+            && self
+                .payload
+                .is_compatible_to(&rhs.payload, TypeCompatibility::MatchingTypeName)
     }
 }
 
@@ -434,4 +474,58 @@ mod tests {
         let sut = right.is_compatible_to(&left);
         assert_that!(sut, eq false);
     }
+
+    #[test]
+    fn are_types_compatible_accepts_identical_types_under_every_policy() {
+        let offered = TypeDetail::new::<u64>(TypeVariant::FixedSize);
+        let requested = TypeDetail::new::<u64>(TypeVariant::FixedSize);
+
+        for policy in [
+            TypeCompatibility::Exact,
+            TypeCompatibility::MatchingTypeName,
+            TypeCompatibility::MatchingLayout,
+        ] {
+            assert_that!(crate::service::are_types_compatible(&offered, &requested, policy), eq true);
+        }
+    }
+
+    #[test]
+    fn are_types_compatible_accepts_same_size_and_alignment_only_when_layout_matches() {
+        let offered = TypeDetail::new::<u64>(TypeVariant::FixedSize);
+        let requested = TypeDetail::new::<i64>(TypeVariant::FixedSize);
+
+        assert_that!(crate::service::are_types_compatible(&offered, &requested, TypeCompatibility::Exact), eq false);
+        assert_that!(crate::service::are_types_compatible(&offered, &requested, TypeCompatibility::MatchingTypeName), eq false);
+        assert_that!(crate::service::are_types_compatible(&offered, &requested, TypeCompatibility::MatchingLayout), eq true);
+    }
+
+    #[test]
+    fn are_types_compatible_accepts_smaller_requested_alignment_unless_exact() {
+        let offered = TypeDetail::new::<u64>(TypeVariant::FixedSize);
+        let mut requested = offered;
+        requested.alignment = 1;
+
+        assert_that!(crate::service::are_types_compatible(&offered, &requested, TypeCompatibility::Exact), eq false);
+        assert_that!(crate::service::are_types_compatible(&offered, &requested, TypeCompatibility::MatchingTypeName), eq true);
+        assert_that!(crate::service::are_types_compatible(&offered, &requested, TypeCompatibility::MatchingLayout), eq true);
+        assert_that!(crate::service::are_types_compatible(&requested, &offered, TypeCompatibility::MatchingLayout), eq false);
+    }
+
+    #[test]
+    fn are_types_compatible_rejects_incompatible_types_under_every_policy() {
+        let offered = TypeDetail::new::<u64>(TypeVariant::FixedSize);
+
+        for requested in [
+            TypeDetail::new::<u32>(TypeVariant::FixedSize),
+            TypeDetail::new::<u64>(TypeVariant::Dynamic),
+        ] {
+            for policy in [
+                TypeCompatibility::Exact,
+                TypeCompatibility::MatchingTypeName,
+                TypeCompatibility::MatchingLayout,
+            ] {
+                assert_that!(crate::service::are_types_compatible(&offered, &requested, policy), eq false);
+            }
+        }
+    }
 }
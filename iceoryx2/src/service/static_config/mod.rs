@@ -54,6 +54,8 @@ pub struct StaticConfig {
     unique_service_id: UniqueServiceId,
     pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
+    pub(crate) is_persistent: bool,
+    pub(crate) creation_epoch: u64,
 }
 
 impl StaticConfig {
@@ -72,6 +74,8 @@ impl StaticConfig {
             service_name: *service_name,
             messaging_pattern,
             attributes: AttributeSet::new(),
+            is_persistent: false,
+            creation_epoch: 0,
         }
     }
 
@@ -89,6 +93,8 @@ impl StaticConfig {
             service_name: *service_name,
             messaging_pattern,
             attributes: AttributeSet::new(),
+            is_persistent: false,
+            creation_epoch: 0,
         }
     }
 
@@ -107,6 +113,8 @@ impl StaticConfig {
             service_name: *service_name,
             messaging_pattern,
             attributes: AttributeSet::new(),
+            is_persistent: false,
+            creation_epoch: 0,
         }
     }
 
@@ -124,6 +132,8 @@ impl StaticConfig {
             service_name: *service_name,
             messaging_pattern,
             attributes: AttributeSet::new(),
+            is_persistent: false,
+            creation_epoch: 0,
         }
     }
 
@@ -147,6 +157,22 @@ impl StaticConfig {
         &self.service_name
     }
 
+    /// Returns true if the [`crate::service::Service`] persists when the last
+    /// [`Node`](crate::node::Node) detaches from it.
+    pub fn is_persistent(&self) -> bool {
+        self.is_persistent
+    }
+
+    /// Returns the creation epoch of the [`crate::service::Service`]. It is a counter that is
+    /// persisted next to the static service configuration and that outlives the
+    /// [`crate::service::Service`]. It starts at 1 and is incremented every time a
+    /// [`crate::service::Service`] with the same name is recreated, as long as the underlying
+    /// storage of the service directory is not cleared, e.g. by a reboot. Ports can compare it
+    /// across reconnects to detect that the [`crate::service::Service`] was restarted.
+    pub fn creation_epoch(&self) -> u64 {
+        self.creation_epoch
+    }
+
     /// Returns the [`MessagingPattern`] of the [`crate::service::Service`]
     pub fn messaging_pattern(&self) -> &MessagingPattern {
         &self.messaging_pattern
@@ -28,17 +28,26 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("fingerprint:                      {:?}", pubsub.static_config().fingerprint());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
-use super::message_type_details::MessageTypeDetails;
+use super::message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant};
 use crate::config;
+use core::alloc::Layout;
+use iceoryx2_bb_container::relocatable_option::RelocatableOption;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::math::align;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::serialize::{Serialize as _, postcard::Postcard};
+use iceoryx2_log::fatal_panic;
 use serde::{Deserialize, Serialize};
 
+const FINGERPRINT_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FINGERPRINT_PRIME: u64 = 0x100000001b3;
+
 /// The static configuration of an
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 /// based service. Contains all parameters that do not change during the lifetime of a
@@ -54,6 +63,7 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
     pub(crate) message_type_details: MessageTypeDetails,
+    pub(crate) extended_user_header: RelocatableOption<TypeDetail>,
 }
 
 impl StaticConfig {
@@ -73,9 +83,50 @@ impl StaticConfig {
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
             message_type_details: MessageTypeDetails::default(),
+            extended_user_header: RelocatableOption::None,
+        }
+    }
+
+    /// Returns the layout of a sample with `number_of_elements` payload elements, including
+    /// the space for the extended user header when the service defines one.
+    pub(crate) fn sample_layout(&self, number_of_elements: usize) -> Layout {
+        let layout = self.message_type_details.sample_layout(number_of_elements);
+        match self.extended_user_header.as_option_ref() {
+            None => layout,
+            Some(extended_user_header) => unsafe {
+                Layout::from_size_align_unchecked(
+                    align(
+                        layout.size() + extended_user_header.size + extended_user_header.alignment
+                            - 1,
+                        layout.align(),
+                    ),
+                    layout.align(),
+                )
+            },
         }
     }
 
+    /// Returns the pointer to the extended user header, which is located directly after the
+    /// payload, or [`None`] when the service does not define one.
+    pub(crate) fn extended_user_header_ptr_from_header(
+        &self,
+        header: *const u8,
+        number_of_elements: usize,
+    ) -> Option<*const u8> {
+        let extended_user_header = self.extended_user_header.as_option_ref()?;
+        let payload_end = self.message_type_details.payload_ptr_from_header(header) as usize
+            + self.message_type_details.payload.size * number_of_elements;
+        Some(align(payload_end, extended_user_header.alignment) as *const u8)
+    }
+
+    pub(crate) fn has_extended_user_header_type<ExtendedUserHeader: ZeroCopySend>(&self) -> bool {
+        self.extended_user_header
+            .as_option_ref()
+            .is_some_and(|details| {
+                *details == TypeDetail::new::<ExtendedUserHeader>(TypeVariant::FixedSize)
+            })
+    }
+
     pub(crate) fn required_amount_of_samples_per_data_segment(
         &self,
         publisher_max_loaned_data: usize,
@@ -130,4 +181,32 @@ impl StaticConfig {
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
     }
+
+    /// Returns the [`TypeDetail`] of the optional extended user header that can be attached to
+    /// individual samples or [`None`] when the [`crate::service::Service`] does not define one.
+    pub fn extended_user_header(&self) -> Option<&TypeDetail> {
+        self.extended_user_header.as_option_ref()
+    }
+
+    /// Returns a fingerprint that summarizes the [`StaticConfig`]. It is stable across runs and
+    /// processes, therefore two processes that opened the same
+    /// [`crate::service::Service`] compute the same value while incompatible configurations
+    /// result in different values.
+    pub fn fingerprint(&self) -> u64 {
+        // postcard is used explicitly, the recommended serializer depends on the feature flags
+        let serialized = match Postcard::serialize(self) {
+            Ok(v) => v,
+            Err(e) => {
+                fatal_panic!(from self,
+                    "This should never happen! Unable to serialize the static config ({e:?}).")
+            }
+        };
+
+        // FNV-1a
+        serialized
+            .iter()
+            .fold(FINGERPRINT_OFFSET_BASIS, |hash, byte| {
+                (hash ^ *byte as u64).wrapping_mul(FINGERPRINT_PRIME)
+            })
+    }
 }
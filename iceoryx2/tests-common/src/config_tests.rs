@@ -0,0 +1,55 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::config::{Config, ConfigValidationError};
+use iceoryx2::prelude::Path;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+#[test]
+fn default_config_is_valid() {
+    let sut = Config::default();
+
+    assert_that!(sut.validate(), is_ok);
+}
+
+#[test]
+fn config_with_empty_service_directory_is_invalid() {
+    let mut sut = Config::default();
+    sut.global.service.directory = Path::new_empty();
+
+    assert_that!(sut.validate(), eq Err(ConfigValidationError::EmptyServiceDirectory));
+}
+
+#[test]
+fn config_with_zero_publish_subscribe_limit_is_invalid() {
+    let mut sut = Config::default();
+    sut.defaults.publish_subscribe.subscriber_max_buffer_size = 0;
+
+    assert_that!(sut.validate(), eq Err(ConfigValidationError::ZeroPublishSubscribeLimit));
+}
+
+#[test]
+fn config_with_zero_request_response_limit_is_invalid() {
+    let mut sut = Config::default();
+    sut.defaults.request_response.max_servers = 0;
+
+    assert_that!(sut.validate(), eq Err(ConfigValidationError::ZeroRequestResponseLimit));
+}
+
+#[test]
+fn config_with_event_id_above_max_value_is_invalid() {
+    let mut sut = Config::default();
+    sut.defaults.event.notifier_created_event = Some(sut.defaults.event.event_id_max_value + 1);
+
+    assert_that!(sut.validate(), eq Err(ConfigValidationError::EventIdExceedsMaxValue));
+}
@@ -16,7 +16,13 @@ extern crate alloc;
 extern crate iceoryx2_bb_loggers;
 
 pub mod attribute_tests;
+pub mod config_tests;
+pub mod local_service_tests;
+#[cfg(feature = "metrics")]
+pub mod metrics_tests;
 pub mod node_name_tests;
+#[cfg(target_os = "linux")]
+pub mod publisher_numa_tests;
 pub mod service_event_thread_safety_tests;
 pub mod service_publish_subscribe_thread_safety_tests;
 pub mod service_request_response_thread_safety_tests;
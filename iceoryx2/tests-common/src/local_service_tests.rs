@@ -0,0 +1,50 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+use iceoryx2::testing::*;
+use iceoryx2_bb_posix::shared_memory::SharedMemory;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+#[test]
+fn local_service_communicates_without_creating_shared_memory() {
+    const NUMBER_OF_SAMPLES: u64 = 8;
+    let service_name = generate_service_name();
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<local::Service>()
+        .unwrap();
+    let service = node
+        .service_builder(&service_name)
+        .publish_subscribe::<u64>()
+        .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+        .create()
+        .unwrap();
+    let publisher = service.publisher_builder().create().unwrap();
+    let subscriber = service.subscriber_builder().create().unwrap();
+
+    for n in 0..NUMBER_OF_SAMPLES {
+        assert_that!(publisher.send_copy(n), eq Ok(1));
+    }
+    for n in 0..NUMBER_OF_SAMPLES {
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq n);
+    }
+
+    let number_of_shared_memories = SharedMemory::list()
+        .iter()
+        .filter(|name| name.starts_with(&config.global.prefix))
+        .count();
+    assert_that!(number_of_shared_memories, eq 0);
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::metrics::render_prometheus;
+use iceoryx2::prelude::*;
+use iceoryx2::testing::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+fn counter_value(rendered: &str, name: &str) -> u64 {
+    rendered
+        .lines()
+        .filter_map(|line| line.strip_prefix(name))
+        .filter_map(|value| value.strip_prefix(' '))
+        .find_map(|value| value.parse().ok())
+        .unwrap()
+}
+
+#[test]
+fn render_prometheus_contains_counters_of_all_ports() {
+    const NUMBER_OF_SAMPLES: u64 = 3;
+    let service_name = generate_service_name();
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ipc::Service>()
+        .unwrap();
+    let service = node
+        .service_builder(&service_name)
+        .publish_subscribe::<u64>()
+        .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+        .create()
+        .unwrap();
+    let publisher = service.publisher_builder().create().unwrap();
+    let subscriber = service.subscriber_builder().create().unwrap();
+
+    let before = render_prometheus();
+
+    for n in 0..NUMBER_OF_SAMPLES {
+        publisher.send_copy(n).unwrap();
+    }
+    for _ in 0..NUMBER_OF_SAMPLES {
+        assert_that!(subscriber.receive().unwrap(), is_some);
+    }
+
+    let after = render_prometheus();
+
+    for name in [
+        "iceoryx2_sent_samples_total",
+        "iceoryx2_received_samples_total",
+        "iceoryx2_loaned_samples_total",
+    ] {
+        assert_that!(after.contains(&alloc::format!("# TYPE {name} counter")), eq true);
+        assert_that!(
+            counter_value(&after, name) - counter_value(&before, name),
+            ge NUMBER_OF_SAMPLES
+        );
+    }
+    assert_that!(
+        after.contains("# TYPE iceoryx2_dropped_samples_total counter"),
+        eq true
+    );
+}
@@ -0,0 +1,89 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::port::publisher::PublisherCreateError;
+use iceoryx2::prelude::*;
+use iceoryx2::testing::*;
+use iceoryx2_bb_linux::numa::NumaNode;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+#[test]
+fn publisher_with_valid_numa_node_can_be_created() {
+    let service_name = generate_service_name();
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ipc::Service>()
+        .unwrap();
+    let service = node
+        .service_builder(&service_name)
+        .publish_subscribe::<u64>()
+        .create()
+        .unwrap();
+
+    let sut = service.publisher_builder().numa_node(0).create();
+
+    // the explicitly requested NUMA node must be honored, otherwise the creation fails
+    if NumaNode::new(0).is_err() {
+        assert_that!(sut.err(), eq Some(PublisherCreateError::UnableToBindToNumaNode));
+        return;
+    }
+
+    let publisher = sut.unwrap();
+    let subscriber = service.subscriber_builder().create().unwrap();
+
+    assert_that!(publisher.send_copy(1234), eq Ok(1));
+    assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+}
+
+#[test]
+fn publisher_with_invalid_numa_node_cannot_be_created() {
+    let service_name = generate_service_name();
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ipc::Service>()
+        .unwrap();
+    let service = node
+        .service_builder(&service_name)
+        .publish_subscribe::<u64>()
+        .create()
+        .unwrap();
+
+    let sut = service.publisher_builder().numa_node(u32::MAX).create();
+
+    assert_that!(sut.err(), eq Some(PublisherCreateError::UnableToBindToNumaNode));
+}
+
+#[test]
+fn publisher_with_dynamic_data_segment_cannot_be_bound_to_numa_node() {
+    let service_name = generate_service_name();
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ipc::Service>()
+        .unwrap();
+    let service = node
+        .service_builder(&service_name)
+        .publish_subscribe::<[u64]>()
+        .create()
+        .unwrap();
+
+    let sut = service
+        .publisher_builder()
+        .allocation_strategy(AllocationStrategy::PowerOfTwo)
+        .numa_node(0)
+        .create();
+
+    assert_that!(sut.err(), eq Some(PublisherCreateError::UnableToBindToNumaNode));
+}
@@ -0,0 +1,151 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The logger can be set only once per process, therefore the log prefix tests run in a
+// dedicated test binary that installs a capturing logger before anything is logged.
+
+mod log_prefix {
+    use std::sync::{Mutex, Once};
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing_macros::test;
+    use iceoryx2_log::Log;
+
+    struct Entry {
+        origin: String,
+        message: String,
+    }
+
+    struct CapturingLogger {
+        entries: Mutex<Vec<Entry>>,
+    }
+
+    impl Log for CapturingLogger {
+        fn log(
+            &self,
+            _log_level: LogLevel,
+            origin: core::fmt::Arguments,
+            formatted_message: core::fmt::Arguments,
+        ) {
+            self.entries.lock().unwrap().push(Entry {
+                origin: origin.to_string(),
+                message: formatted_message.to_string(),
+            });
+        }
+    }
+
+    impl CapturingLogger {
+        fn contains(&self, origin_prefix: &str, origin: &str, message: &str) -> bool {
+            self.entries.lock().unwrap().iter().any(|entry| {
+                entry.origin.starts_with(origin_prefix)
+                    && entry.origin.contains(origin)
+                    && entry.message.contains(message)
+            })
+        }
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger {
+        entries: Mutex::new(Vec::new()),
+    };
+
+    fn capturing_logger() -> &'static CapturingLogger {
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            assert_that!(set_logger(&LOGGER), eq true);
+            set_log_level(LogLevel::Trace);
+        });
+
+        &LOGGER
+    }
+
+    fn log_prefix_is_prepended_to_the_log_origin_of_node_owned_entities<S: Service>(
+        log_prefix: &str,
+    ) {
+        let logger = capturing_logger();
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new()
+            .log_prefix(log_prefix)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_err);
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(1)
+            .subscriber_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let _publisher = service.publisher_builder().create().unwrap();
+        let sut = service.publisher_builder().create();
+        assert_that!(sut, is_err);
+
+        let sut = service.subscriber_builder().buffer_size(2).create();
+        assert_that!(sut, is_err);
+
+        assert_that!(logger.contains(log_prefix, "Builder", "since the service does not exist"), eq true);
+        assert_that!(logger.contains(log_prefix, "Publisher::new()", "maximum supported amount of publishers"), eq true);
+        assert_that!(logger.contains(log_prefix, "PortFactoryPublisher", "Failed to create new Publisher port"), eq true);
+        assert_that!(logger.contains(log_prefix, "Subscriber::new()", "exceeds the maximum supported buffer size"), eq true);
+    }
+
+    fn log_origin_is_unchanged_without_log_prefix<S: Service>() {
+        let logger = capturing_logger();
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().buffer_size(2).create();
+        assert_that!(sut, is_err);
+
+        assert_that!(logger.contains("\"Subscriber::new()\"", "", "exceeds the maximum supported buffer size"), eq true);
+    }
+
+    #[test]
+    fn log_prefix_is_prepended_to_the_log_origin_of_ipc_node_owned_entities() {
+        log_prefix_is_prepended_to_the_log_origin_of_node_owned_entities::<ipc::Service>(
+            "[ipc-node-with-prefix] ",
+        );
+    }
+
+    #[test]
+    fn log_prefix_is_prepended_to_the_log_origin_of_local_node_owned_entities() {
+        log_prefix_is_prepended_to_the_log_origin_of_node_owned_entities::<local::Service>(
+            "[local-node-with-prefix] ",
+        );
+    }
+
+    #[test]
+    fn log_origin_of_ipc_node_without_log_prefix_is_unchanged() {
+        log_origin_is_unchanged_without_log_prefix::<ipc::Service>();
+    }
+}
+
+iceoryx2_bb_testing::test_harness!();